@@ -0,0 +1,80 @@
+/// Spanish cardinals from 0 to 29. `1` and `21` are written out of the
+/// table, since they agree in gender and are apocopated before nouns.
+pub const UNITS: [&str; 30] = [
+    "",
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+
+
+/// Spanish tens, by first digit.
+pub const TENS: [&str; 10] = [
+    "",
+    "diez",
+    "veinte",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+
+
+/// Stems of the Spanish hundreds, by first digit. From `2` onwards, the
+/// suffix `-os` or `-as` agrees in gender with the noun.
+pub const HUNDREDS: [&str; 10] = [
+    "",
+    "ciento",
+    "doscient",
+    "trescient",
+    "cuatrocient",
+    "quinient",
+    "seiscient",
+    "setecient",
+    "ochocient",
+    "novecient",
+];
+
+
+/// Spanish (long scale) names of the powers of one million, in singular
+/// and plural.
+pub const SCALES: [(&str, &str); 4] = [
+    ("", ""),
+    ("millón", "millones"),
+    ("billón", "billones"),
+    ("trillón", "trillones"),
+];
+
+
+/// Maximum number of decimals of an amount in UF. Contracts usually agree
+/// on 2 to 4 decimals.
+pub const MAX_UF_DECIMALS: usize = 4;
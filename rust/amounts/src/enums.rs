@@ -0,0 +1,166 @@
+use rust_decimal::RoundingStrategy;
+
+use crate::errors::LocaleError;
+
+
+/// Grammatical gender of the noun a number refers to.
+///
+/// In Spanish, `uno` and the hundreds agree in gender with the noun
+/// (e.g. "doscientos pesos", "doscientas Unidades de Fomento").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Gender {
+    #[default]
+    Masculine,
+    Feminine,
+}
+
+
+/// Rounding mode used when formatting or rounding amounts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Rounds half away from zero, as usual in invoices and accounting.
+    #[default]
+    HalfUp,
+    /// Rounds half to the nearest even digit (banker's rounding).
+    HalfEven,
+    /// Truncates towards zero.
+    Down,
+    /// Rounds away from zero.
+    Up,
+}
+
+
+impl Rounding {
+    pub const ALL: &'static [Self] = &[
+        Self::HalfUp,
+        Self::HalfEven,
+        Self::Down,
+        Self::Up,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Rounding::HalfUp => "half_up",
+            Rounding::HalfEven => "half_even",
+            Rounding::Down => "down",
+            Rounding::Up => "up",
+        }
+    }
+
+    /// Returns string with enum values separated by comma.
+    pub fn values() -> String {
+        Self::ALL
+            .iter()
+            .map(|v| format!("'{}'", v.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Returns the equivalent strategy of `rust_decimal`.
+    pub fn strategy(&self) -> RoundingStrategy {
+        match self {
+            Rounding::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            Rounding::HalfEven => RoundingStrategy::MidpointNearestEven,
+            Rounding::Down => RoundingStrategy::ToZero,
+            Rounding::Up => RoundingStrategy::AwayFromZero,
+        }
+    }
+}
+
+
+impl TryFrom<&str> for Rounding {
+    type Error = LocaleError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "half_up" => Ok(Rounding::HalfUp),
+            "half_even" => Ok(Rounding::HalfEven),
+            "down" => Ok(Rounding::Down),
+            "up" => Ok(Rounding::Up),
+            _ => Err(LocaleError::UnknownRounding {
+                expected: Self::values(),
+                actual: s.to_string(),
+            }),
+        }
+    }
+}
+
+
+/// Currency or unit of account of an amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Currency {
+    /// Chilean peso.
+    Clp,
+    /// Unidad de Fomento.
+    Uf,
+    /// Unidad Tributaria Mensual.
+    Utm,
+    /// US dollar.
+    Usd,
+}
+
+
+impl Currency {
+    pub const ALL: &'static [Self] = &[
+        Self::Clp,
+        Self::Uf,
+        Self::Utm,
+        Self::Usd,
+    ];
+
+    /// Returns the code of the currency.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Currency::Clp => "CLP",
+            Currency::Uf => "UF",
+            Currency::Utm => "UTM",
+            Currency::Usd => "USD",
+        }
+    }
+
+    /// Returns the symbol written before amounts in the currency.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::Clp => "$",
+            Currency::Uf => "UF",
+            Currency::Utm => "UTM",
+            Currency::Usd => "US$",
+        }
+    }
+
+    /// Returns the number of decimals amounts in the currency are usually
+    /// written with.
+    pub fn decimals(&self) -> u32 {
+        match self {
+            Currency::Clp | Currency::Utm => 0,
+            Currency::Uf | Currency::Usd => 2,
+        }
+    }
+
+    /// Returns string with enum values separated by comma.
+    pub fn values() -> String {
+        Self::ALL
+            .iter()
+            .map(|v| format!("'{}'", v.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+
+impl TryFrom<&str> for Currency {
+    type Error = LocaleError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "CLP" | "$" => Ok(Currency::Clp),
+            "UF" => Ok(Currency::Uf),
+            "UTM" => Ok(Currency::Utm),
+            "USD" | "US$" => Ok(Currency::Usd),
+            _ => Err(LocaleError::UnknownCurrency {
+                expected: Self::values(),
+                actual: s.to_string(),
+            }),
+        }
+    }
+}
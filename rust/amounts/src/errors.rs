@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum WordsError {
+    #[error("Invalid amount: '{amount}'.")]
+    InvalidAmount { amount: String },
+
+    #[error("Amount '{amount}' has {actual} decimals, but at most {max} are allowed.")]
+    TooManyDecimals { amount: String, actual: usize, max: usize },
+}
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum LocaleError {
+    #[error("Number is empty.")]
+    EmptyNumber,
+
+    #[error("Invalid character '{character}' at position {position} of '{number}'.")]
+    InvalidCharacter { number: String, character: char, position: usize },
+
+    #[error("Invalid thousands grouping in '{number}'. Groups after the first must have 3 digits.")]
+    InvalidGrouping { number: String },
+
+    #[error("Number '{number}' is out of range or has too many digits.")]
+    OutOfRange { number: String },
+
+    #[error("Unknown currency. Expected one of: {expected}. Got: '{actual}'.")]
+    UnknownCurrency { expected: String, actual: String },
+
+    #[error("Unknown rounding mode. Expected one of: {expected}. Got: '{actual}'.")]
+    UnknownRounding { expected: String, actual: String },
+}
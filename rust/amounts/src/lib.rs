@@ -0,0 +1,193 @@
+pub mod enums;
+pub mod constants;
+pub mod errors;
+pub mod words;
+pub mod locale;
+
+use pyo3::prelude::*;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+
+use rust_decimal::Decimal;
+
+use crate::enums::Gender;
+use crate::enums::Currency;
+use crate::enums::Rounding;
+use crate::errors::WordsError;
+use crate::errors::LocaleError;
+
+
+create_exception!(
+    rs_amounts, AmountException, PyException,
+    "Base class for all exceptions raised when writing amounts."
+);
+create_exception!(
+    rs_amounts, InvalidAmount, AmountException,
+    "Raised when the given amount is not a valid number."
+);
+create_exception!(
+    rs_amounts, TooManyDecimals, AmountException,
+    "Raised when the given amount has more decimals than allowed."
+);
+
+create_exception!(
+    rs_amounts, LocaleException, PyException,
+    "Base class for all exceptions raised when parsing or formatting numbers."
+);
+create_exception!(
+    rs_amounts, EmptyNumber, LocaleException,
+    "Raised when the given number is empty."
+);
+create_exception!(
+    rs_amounts, InvalidNumberCharacter, LocaleException,
+    "Raised when the given number has an invalid character."
+);
+create_exception!(
+    rs_amounts, InvalidGrouping, LocaleException,
+    "Raised when the thousands separators of the given number are misplaced."
+);
+create_exception!(
+    rs_amounts, NumberOutOfRange, LocaleException,
+    "Raised when the given number has too many digits."
+);
+create_exception!(
+    rs_amounts, UnknownCurrency, LocaleException,
+    "Raised when the given currency is unknown."
+);
+create_exception!(
+    rs_amounts, UnknownRounding, LocaleException,
+    "Raised when the given rounding mode is unknown."
+);
+
+
+impl From<WordsError> for PyErr {
+    fn from(err: WordsError) -> PyErr {
+        match err {
+            WordsError::InvalidAmount { .. } => InvalidAmount::new_err(err.to_string()),
+            WordsError::TooManyDecimals { .. } => TooManyDecimals::new_err(err.to_string()),
+        }
+    }
+}
+
+impl From<LocaleError> for PyErr {
+    fn from(err: LocaleError) -> PyErr {
+        match err {
+            LocaleError::EmptyNumber => EmptyNumber::new_err(err.to_string()),
+            LocaleError::InvalidCharacter { .. } => InvalidNumberCharacter::new_err(err.to_string()),
+            LocaleError::InvalidGrouping { .. } => InvalidGrouping::new_err(err.to_string()),
+            LocaleError::OutOfRange { .. } => NumberOutOfRange::new_err(err.to_string()),
+            LocaleError::UnknownCurrency { .. } => UnknownCurrency::new_err(err.to_string()),
+            LocaleError::UnknownRounding { .. } => UnknownRounding::new_err(err.to_string()),
+        }
+    }
+}
+
+
+fn gender(feminine: bool) -> Gender {
+    if feminine { Gender::Feminine } else { Gender::Masculine }
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (n, feminine=false))]
+fn number_to_words(n: u64, feminine: bool) -> String {
+    words::number_to_words(n, gender(feminine))
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (n, singular, plural, feminine=false))]
+fn amount_to_words(n: u64, singular: &str, plural: &str, feminine: bool) -> String {
+    words::amount_to_words(n, singular, plural, gender(feminine))
+}
+
+
+#[pyfunction]
+fn pesos_to_words(n: u64) -> String {
+    words::pesos_to_words(n)
+}
+
+
+#[pyfunction]
+fn uf_to_words(amount: &str) -> PyResult<String> {
+    match words::uf_to_words(amount) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn parse_number(text: &str) -> PyResult<Decimal> {
+    match locale::parse_number(text) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn parse_currency(text: &str) -> PyResult<(Decimal, &'static str)> {
+    match locale::parse_currency(text) {
+        Ok((value, currency)) => Ok((value, currency.as_str())),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (value, decimals, rounding="half_up"))]
+fn round_decimal(value: Decimal, decimals: u32, rounding: &str) -> PyResult<Decimal> {
+    let rounding = Rounding::try_from(rounding)?;
+    Ok(locale::round_decimal(value, decimals, rounding))
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (value, decimals=0, rounding="half_up"))]
+fn format_number(value: Decimal, decimals: u32, rounding: &str) -> PyResult<String> {
+    let rounding = Rounding::try_from(rounding)?;
+    Ok(locale::format_number(value, decimals, rounding))
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (value, currency="CLP", decimals=None, rounding="half_up"))]
+fn format_currency(
+    value: Decimal,
+    currency: &str,
+    decimals: Option<u32>,
+    rounding: &str,
+) -> PyResult<String> {
+    let currency = Currency::try_from(currency)?;
+    let rounding = Rounding::try_from(rounding)?;
+    Ok(locale::format_currency(value, currency, decimals, rounding))
+}
+
+
+#[pymodule]
+pub fn rs_amounts(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(number_to_words, m)?)?;
+    m.add_function(wrap_pyfunction!(amount_to_words, m)?)?;
+    m.add_function(wrap_pyfunction!(pesos_to_words, m)?)?;
+    m.add_function(wrap_pyfunction!(uf_to_words, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_number, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_currency, m)?)?;
+    m.add_function(wrap_pyfunction!(round_decimal, m)?)?;
+    m.add_function(wrap_pyfunction!(format_number, m)?)?;
+    m.add_function(wrap_pyfunction!(format_currency, m)?)?;
+
+    m.add("AmountException", m.py().get_type::<AmountException>())?;
+    m.add("InvalidAmount", m.py().get_type::<InvalidAmount>())?;
+    m.add("TooManyDecimals", m.py().get_type::<TooManyDecimals>())?;
+
+    m.add("LocaleException", m.py().get_type::<LocaleException>())?;
+    m.add("EmptyNumber", m.py().get_type::<EmptyNumber>())?;
+    m.add("InvalidNumberCharacter", m.py().get_type::<InvalidNumberCharacter>())?;
+    m.add("InvalidGrouping", m.py().get_type::<InvalidGrouping>())?;
+    m.add("NumberOutOfRange", m.py().get_type::<NumberOutOfRange>())?;
+    m.add("UnknownCurrency", m.py().get_type::<UnknownCurrency>())?;
+    m.add("UnknownRounding", m.py().get_type::<UnknownRounding>())?;
+
+    Ok(())
+}
@@ -0,0 +1,291 @@
+use rust_decimal::Decimal;
+
+use crate::enums::Currency;
+use crate::enums::Rounding;
+use crate::errors::LocaleError;
+
+
+/// Splits a leading `+` or `-` sign from a number, along with the
+/// whitespace after it (e.g. `"- 1.234"`).
+fn split_sign(text: &str) -> (bool, &str) {
+    match text.strip_prefix(['-', '+']) {
+        Some(rest) => (text.starts_with('-'), rest.trim_start()),
+        None => (false, text),
+    }
+}
+
+
+/// Checks that the integer part of a number is either plain digits or
+/// digits grouped by thousands with `.`.
+fn validate_grouping(integer: &str) -> bool {
+    let mut groups = integer.split('.');
+
+    // `split` always yields at least one item.
+    let first = groups.next().unwrap();
+
+    !first.is_empty()
+        && (integer.len() == first.len() || first.len() <= 3)
+        && groups.all(|g| g.len() == 3)
+}
+
+
+/// Parses a number written in the Chilean locale.
+///
+/// # Arguments
+/// * `text` - A reference to a string slice containing the number, with
+///   `.` as thousands separator and `,` as decimal separator, e.g.
+///   `"38.416,69"`.
+///
+/// # Returns
+/// * `Ok(Decimal)` - The exact value of the number.
+/// * `Err(LocaleError)`:
+///   - [`LocaleError::EmptyNumber`] - If the number has no digits.
+///   - [`LocaleError::InvalidCharacter`] - If the number has characters
+///     other than digits, separators and a leading sign.
+///   - [`LocaleError::InvalidGrouping`] - If the thousands separators are
+///     misplaced.
+///   - [`LocaleError::OutOfRange`] - If the number has more than 28
+///     significant digits.
+///
+/// # Behavior
+/// Surrounding whitespace, and whitespace between the sign and the digits,
+/// is ignored. Thousands separators are optional, but must separate groups
+/// of 3 digits if present.
+///
+/// # Examples
+/// ```
+/// use rust_decimal::Decimal;
+/// use amounts::locale::parse_number;
+///
+/// assert_eq!(parse_number("38.416,69").unwrap(), Decimal::new(3841669, 2));
+/// assert_eq!(parse_number("1234,5678").unwrap(), Decimal::new(12345678, 4));
+/// assert_eq!(parse_number(" - 0,3 ").unwrap(), Decimal::new(-3, 1));
+/// assert_eq!(parse_number("1.000.000").unwrap(), Decimal::new(1000000, 0));
+/// assert!(parse_number("1.23,4").is_err());
+/// assert!(parse_number("1,234.5").is_err());
+/// assert!(parse_number("").is_err());
+/// ```
+pub fn parse_number(text: &str) -> Result<Decimal, LocaleError> {
+    let number = text.trim();
+    let (negative, body) = split_sign(number);
+
+    if body.is_empty() {
+        return Err(LocaleError::EmptyNumber);
+    }
+
+    let offset = number.chars().count() - body.chars().count();
+    let mut comma = None;
+
+    for (i, c) in body.chars().enumerate() {
+        match c {
+            '0'..='9' | '.' => {}
+            ',' if comma.is_none() => comma = Some(i),
+            _ => return Err(LocaleError::InvalidCharacter {
+                number: number.to_string(),
+                character: c,
+                position: offset + i + 1,
+            }),
+        }
+    }
+
+    let (integer, decimals) = body.split_once(',').unwrap_or((body, ""));
+
+    if !validate_grouping(integer) || decimals.contains('.') || (comma.is_some() && decimals.is_empty()) {
+        return Err(LocaleError::InvalidGrouping { number: number.to_string() });
+    }
+
+    let sign = if negative { "-" } else { "" };
+    let digits = format!("{}{}.{}", sign, integer.replace('.', ""), decimals);
+
+    Decimal::from_str_exact(digits.trim_end_matches('.'))
+        .map_err(|_| LocaleError::OutOfRange { number: number.to_string() })
+}
+
+
+/// Parses an amount of money written in the Chilean locale.
+///
+/// # Arguments
+/// * `text` - A reference to a string slice containing the amount, with
+///   the symbol or code of the currency before the number, e.g.
+///   `"$1.234.567"` or `"UF 1.234,5678"`.
+///
+/// # Returns
+/// * `Ok((Decimal, Currency))` - The exact value of the amount and its
+///   currency, in the order of the arguments of [`format_currency`].
+/// * `Err(LocaleError)`:
+///   - [`LocaleError::UnknownCurrency`] - If the amount does not start
+///     with a known symbol or code, see [`Currency`].
+///   - See [`parse_number`].
+///
+/// # Behavior
+/// Symbols and codes are case-insensitive. The sign can be written either
+/// before or after the symbol (`"-$1.234"` or `"$ -1.234"`).
+///
+/// # Examples
+/// ```
+/// use rust_decimal::Decimal;
+/// use amounts::enums::Currency;
+/// use amounts::locale::parse_currency;
+///
+/// assert_eq!(
+///     parse_currency("$1.234.567").unwrap(),
+///     (Decimal::new(1234567, 0), Currency::Clp),
+/// );
+/// assert_eq!(
+///     parse_currency("UF 1.234,5678").unwrap(),
+///     (Decimal::new(12345678, 4), Currency::Uf),
+/// );
+/// assert_eq!(
+///     parse_currency("-US$ 10,5").unwrap(),
+///     (Decimal::new(-105, 1), Currency::Usd),
+/// );
+/// assert!(parse_currency("€ 10").is_err());
+/// assert!(parse_currency("-$-10").is_err());
+/// ```
+pub fn parse_currency(text: &str) -> Result<(Decimal, Currency), LocaleError> {
+    let amount = text.trim();
+    let (negative, rest) = split_sign(amount);
+
+    // Longer symbols first, so that "US$" is not taken for "$".
+    let mut symbols: Vec<(&str, Currency)> = Currency::ALL
+        .iter()
+        .flat_map(|c| [(c.symbol(), *c), (c.as_str(), *c)])
+        .collect();
+    symbols.sort_by_key(|(s, _)| std::cmp::Reverse(s.len()));
+
+    let (symbol, currency) = symbols
+        .into_iter()
+        .find(|(s, _)| rest.get(..s.len()).is_some_and(|p| p.eq_ignore_ascii_case(s)))
+        .ok_or_else(|| LocaleError::UnknownCurrency {
+            expected: Currency::values(),
+            actual: amount.to_string(),
+        })?;
+
+    let number = rest[symbol.len()..].trim_start();
+
+    if negative && number.starts_with(['-', '+']) {
+        return Err(LocaleError::InvalidCharacter {
+            number: amount.to_string(),
+            character: number.chars().next().unwrap(),
+            position: amount.chars().count() - number.chars().count() + 1,
+        });
+    }
+
+    let value = parse_number(number)?;
+    Ok((if negative { -value } else { value }, currency))
+}
+
+
+/// Rounds a value to a number of decimals.
+///
+/// # Examples
+/// ```
+/// use rust_decimal::Decimal;
+/// use amounts::enums::Rounding;
+/// use amounts::locale::round_decimal;
+///
+/// let value = Decimal::new(125, 2);
+/// assert_eq!(round_decimal(value, 1, Rounding::HalfUp), Decimal::new(13, 1));
+/// assert_eq!(round_decimal(value, 1, Rounding::HalfEven), Decimal::new(12, 1));
+/// assert_eq!(round_decimal(-value, 0, Rounding::Down), Decimal::new(-1, 0));
+/// assert_eq!(round_decimal(-value, 0, Rounding::Up), Decimal::new(-2, 0));
+/// ```
+pub fn round_decimal(value: Decimal, decimals: u32, rounding: Rounding) -> Decimal {
+    value.round_dp_with_strategy(decimals, rounding.strategy())
+}
+
+
+/// Formats a number in the Chilean locale.
+///
+/// # Arguments
+/// * `value` - The value to format.
+/// * `decimals` - The number of decimals to write.
+/// * `rounding` - The rounding mode, see [`round_decimal`].
+///
+/// # Returns
+/// The number with `.` as thousands separator and `,` as decimal
+/// separator, with exactly `decimals` decimals.
+///
+/// # Examples
+/// ```
+/// use rust_decimal::Decimal;
+/// use amounts::enums::Rounding;
+/// use amounts::locale::format_number;
+///
+/// let value = Decimal::new(-12345675, 3);
+/// assert_eq!(format_number(value, 2, Rounding::HalfUp), "-12.345,68");
+/// assert_eq!(format_number(value, 0, Rounding::HalfUp), "-12.346");
+/// assert_eq!(format_number(Decimal::new(5, 1), 3, Rounding::HalfUp), "0,500");
+/// assert_eq!(format_number(Decimal::new(-4, 1), 0, Rounding::HalfUp), "0");
+/// ```
+pub fn format_number(value: Decimal, decimals: u32, rounding: Rounding) -> String {
+    let rounded = round_decimal(value, decimals, rounding);
+    let digits = format!("{:.*}", decimals as usize, rounded.abs());
+    let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+
+    let mut grouped = String::with_capacity(digits.len() + integer.len() / 3 + 1);
+
+    if rounded.is_sign_negative() && !rounded.is_zero() {
+        grouped.push('-');
+    }
+
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push('.');
+        }
+        grouped.push(c);
+    }
+
+    if !fraction.is_empty() {
+        grouped.push(',');
+        grouped.push_str(fraction);
+    }
+
+    grouped
+}
+
+
+/// Formats an amount of money in the Chilean locale.
+///
+/// # Arguments
+/// * `value` - The amount to format.
+/// * `currency` - The currency of the amount.
+/// * `decimals` - The number of decimals to write, or `None` for the usual
+///   decimals of the currency, see [`Currency::decimals`].
+/// * `rounding` - The rounding mode, see [`round_decimal`].
+///
+/// # Returns
+/// The amount preceded by the symbol of the currency, see
+/// [`Currency::symbol`]. Symbols made of letters are separated from the
+/// number by a space, and the sign goes before the symbol.
+///
+/// # Examples
+/// ```
+/// use rust_decimal::Decimal;
+/// use amounts::enums::Currency;
+/// use amounts::enums::Rounding;
+/// use amounts::locale::format_currency;
+///
+/// let value = Decimal::new(12345678, 4);
+/// assert_eq!(format_currency(value, Currency::Clp, None, Rounding::HalfUp), "$1.235");
+/// assert_eq!(format_currency(value, Currency::Uf, None, Rounding::HalfUp), "UF 1.234,57");
+/// assert_eq!(format_currency(value, Currency::Uf, Some(4), Rounding::HalfUp), "UF 1.234,5678");
+/// assert_eq!(format_currency(-value, Currency::Usd, None, Rounding::Down), "-US$1.234,56");
+/// ```
+pub fn format_currency(
+    value: Decimal,
+    currency: Currency,
+    decimals: Option<u32>,
+    rounding: Rounding,
+) -> String {
+    let number = format_number(value, decimals.unwrap_or(currency.decimals()), rounding);
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number.as_str()),
+    };
+
+    let symbol = currency.symbol();
+    let separator = if symbol.ends_with(|c: char| c.is_ascii_alphabetic()) { " " } else { "" };
+
+    format!("{}{}{}{}", sign, symbol, separator, number)
+}
@@ -0,0 +1,285 @@
+use crate::enums::Gender;
+use crate::errors::WordsError;
+use crate::constants::TENS;
+use crate::constants::UNITS;
+use crate::constants::SCALES;
+use crate::constants::HUNDREDS;
+use crate::constants::MAX_UF_DECIMALS;
+
+
+/// Writes `1` or `21`, which agree in gender and, in masculine, are
+/// apocopated before nouns ("un peso", "veintiún pesos").
+fn one(n: u64, gender: Gender, apocope: bool) -> String {
+    let stem = if n == 21 { "veinti" } else { "" };
+
+    let suffix = match (gender, apocope) {
+        (Gender::Feminine, _) => "una",
+        (Gender::Masculine, true) if n == 21 => "ún",
+        (Gender::Masculine, true) => "un",
+        (Gender::Masculine, false) => "uno",
+    };
+
+    format!("{}{}", stem, suffix)
+}
+
+
+/// Writes a number from 1 to 99. Returns an empty string for 0.
+fn under_hundred(n: u64, gender: Gender, apocope: bool) -> String {
+    match n {
+        0 => String::new(),
+        1 | 21 => one(n, gender, apocope),
+        2..=29 => UNITS[n as usize].to_string(),
+        _ => match n % 10 {
+            0 => TENS[(n / 10) as usize].to_string(),
+            u => format!("{} y {}", TENS[(n / 10) as usize], under_hundred(u, gender, apocope)),
+        },
+    }
+}
+
+
+/// Writes a number from 1 to 999. Returns an empty string for 0.
+fn under_thousand(n: u64, gender: Gender, apocope: bool) -> String {
+    if n == 100 {
+        return "cien".to_string();
+    }
+
+    let hundreds = match n / 100 {
+        0 => String::new(),
+        1 => HUNDREDS[1].to_string(),
+        h => {
+            let suffix = if gender == Gender::Feminine { "as" } else { "os" };
+            format!("{}{}", HUNDREDS[h as usize], suffix)
+        }
+    };
+
+    join(&[hundreds, under_hundred(n % 100, gender, apocope)])
+}
+
+
+/// Writes a number from 1 to 999,999. Returns an empty string for 0.
+fn under_million(n: u64, gender: Gender, apocope: bool) -> String {
+    let thousands = match n / 1000 {
+        0 => String::new(),
+        1 => "mil".to_string(),
+        // Numbers before "mil" are always apocopated ("veintiún mil").
+        t => format!("{} mil", under_thousand(t, gender, true)),
+    };
+
+    join(&[thousands, under_thousand(n % 1000, gender, apocope)])
+}
+
+
+/// Joins the non-empty parts of a number with spaces.
+fn join(parts: &[String]) -> String {
+    parts
+        .iter()
+        .filter(|p| !p.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+
+/// Writes any number, apocopating `uno` if it comes before a noun.
+fn cardinal(n: u64, gender: Gender, apocope: bool) -> String {
+    if n == 0 {
+        return "cero".to_string();
+    }
+
+    let parts: Vec<String> = SCALES
+        .iter()
+        .enumerate()
+        .rev()
+        .filter_map(|(k, (singular, plural))| {
+            let chunk = (n / 1_000_000u64.pow(k as u32)) % 1_000_000;
+
+            match (chunk, k) {
+                (0, _) => None,
+                (_, 0) => Some(under_million(chunk, gender, apocope)),
+                // "millón" is a masculine noun, so the number before it
+                // is always masculine and apocopated.
+                (1, _) => Some(format!("un {}", singular)),
+                _ => Some(format!("{} {}", under_million(chunk, Gender::Masculine, true), plural)),
+            }
+        })
+        .collect();
+
+    join(&parts)
+}
+
+
+/// Writes a number in Spanish words.
+///
+/// # Arguments
+/// * `n` - The number to write.
+/// * `gender` - The gender of the noun the number refers to.
+///
+/// # Returns
+/// The number in lowercase Spanish words, as read on its own (`"uno"`,
+/// not `"un"`).
+///
+/// # Behavior
+/// Numbers follow the long scale used in Chile: a "billón" is a million
+/// millions (10^12), and 10^9 is "mil millones".
+///
+/// # Examples
+/// ```
+/// use amounts::enums::Gender;
+/// use amounts::words::number_to_words;
+///
+/// assert_eq!(number_to_words(0, Gender::Masculine), "cero");
+/// assert_eq!(number_to_words(21, Gender::Masculine), "veintiuno");
+/// assert_eq!(number_to_words(21, Gender::Feminine), "veintiuna");
+/// assert_eq!(number_to_words(100, Gender::Masculine), "cien");
+/// assert_eq!(number_to_words(115, Gender::Masculine), "ciento quince");
+/// assert_eq!(number_to_words(251, Gender::Feminine), "doscientas cincuenta y una");
+/// assert_eq!(number_to_words(21_000, Gender::Masculine), "veintiún mil");
+/// assert_eq!(number_to_words(1_200_000, Gender::Masculine), "un millón doscientos mil");
+/// assert_eq!(number_to_words(2_500_000_000, Gender::Masculine), "dos mil quinientos millones");
+/// assert_eq!(number_to_words(1_000_000_000_000, Gender::Masculine), "un billón");
+/// ```
+pub fn number_to_words(n: u64, gender: Gender) -> String {
+    cardinal(n, gender, false)
+}
+
+
+/// Writes an amount of a noun in Spanish words.
+///
+/// # Arguments
+/// * `n` - The amount.
+/// * `singular` - The noun in singular, e.g. `"peso"`.
+/// * `plural` - The noun in plural, e.g. `"pesos"`.
+/// * `gender` - The gender of the noun.
+///
+/// # Returns
+/// The amount in lowercase Spanish words, followed by the noun.
+///
+/// # Behavior
+/// - `uno` is apocopated before masculine nouns ("un peso", "veintiún
+///   pesos").
+/// - Round millions take `de` before the noun ("un millón de pesos"), but
+///   not other amounts ("un millón doscientos mil pesos").
+///
+/// # Examples
+/// ```
+/// use amounts::enums::Gender;
+/// use amounts::words::amount_to_words;
+///
+/// assert_eq!(amount_to_words(1, "peso", "pesos", Gender::Masculine), "un peso");
+/// assert_eq!(
+///     amount_to_words(201, "persona", "personas", Gender::Feminine),
+///     "doscientas una personas",
+/// );
+/// assert_eq!(
+///     amount_to_words(3_000_000, "dólar", "dólares", Gender::Masculine),
+///     "tres millones de dólares",
+/// );
+/// ```
+pub fn amount_to_words(n: u64, singular: &str, plural: &str, gender: Gender) -> String {
+    let words = cardinal(n, gender, true);
+
+    if n == 1 {
+        return format!("{} {}", words, singular);
+    }
+
+    if n >= 1_000_000 && n.is_multiple_of(1_000_000) {
+        return format!("{} de {}", words, plural);
+    }
+
+    format!("{} {}", words, plural)
+}
+
+
+/// Writes an amount of Chilean pesos in Spanish words, as required on
+/// cheques and legal documents.
+///
+/// # Examples
+/// ```
+/// use amounts::words::pesos_to_words;
+///
+/// assert_eq!(pesos_to_words(1), "un peso");
+/// assert_eq!(pesos_to_words(21), "veintiún pesos");
+/// assert_eq!(pesos_to_words(1_000_000), "un millón de pesos");
+/// assert_eq!(pesos_to_words(1_200_000), "un millón doscientos mil pesos");
+/// assert_eq!(pesos_to_words(0), "cero pesos");
+/// ```
+pub fn pesos_to_words(n: u64) -> String {
+    amount_to_words(n, "peso", "pesos", Gender::Masculine)
+}
+
+
+/// Writes an amount of UF (Unidades de Fomento) in Spanish words.
+///
+/// # Arguments
+/// * `amount` - A reference to a string slice containing the amount, with
+///   `,` or `.` as decimal separator and no thousands separators, e.g.
+///   `"1234,5678"`.
+///
+/// # Returns
+/// * `Ok(String)` - The amount in Spanish words, in feminine since
+///   "Unidad de Fomento" is a feminine noun.
+/// * `Err(WordsError)`:
+///   - [`WordsError::InvalidAmount`] - If the amount is not a
+///     non-negative decimal number.
+///   - [`WordsError::TooManyDecimals`] - If the amount has more than
+///     [`MAX_UF_DECIMALS`] decimals.
+///
+/// # Behavior
+/// Decimals are read after "coma" as a number, preceded by a "cero" for
+/// each leading zero. Trailing zeros are ignored.
+///
+/// # Examples
+/// ```
+/// use amounts::words::uf_to_words;
+///
+/// assert_eq!(uf_to_words("1").unwrap(), "una Unidad de Fomento");
+/// assert_eq!(uf_to_words("200,00").unwrap(), "doscientas Unidades de Fomento");
+/// assert_eq!(
+///     uf_to_words("1234,56").unwrap(),
+///     "mil doscientas treinta y cuatro coma cincuenta y seis Unidades de Fomento",
+/// );
+/// assert_eq!(uf_to_words("21.05").unwrap(), "veintiuna coma cero cinco Unidades de Fomento");
+/// assert!(uf_to_words("1.234,56").is_err());
+/// assert!(uf_to_words("1,23456").is_err());
+/// ```
+pub fn uf_to_words(amount: &str) -> Result<String, WordsError> {
+    let amount = amount.trim();
+    let invalid = || WordsError::InvalidAmount { amount: amount.to_string() };
+
+    let (integer, decimals) = match amount.split_once([',', '.']) {
+        Some((integer, decimals)) => (integer, decimals),
+        None => (amount, ""),
+    };
+
+    if integer.is_empty() || !integer.bytes().chain(decimals.bytes()).all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    if decimals.len() > MAX_UF_DECIMALS {
+        return Err(WordsError::TooManyDecimals {
+            amount: amount.to_string(),
+            actual: decimals.len(),
+            max: MAX_UF_DECIMALS,
+        });
+    }
+
+    let integer: u64 = integer.parse().map_err(|_| invalid())?;
+    let decimals = decimals.trim_end_matches('0');
+
+    if decimals.is_empty() {
+        return Ok(amount_to_words(integer, "Unidad de Fomento", "Unidades de Fomento", Gender::Feminine));
+    }
+
+    let significant = decimals.trim_start_matches('0');
+    let mut words = vec!["cero"; decimals.len() - significant.len()].join(" ");
+
+    // Decimals were validated as digits, and have at most 4 of them.
+    let significant = number_to_words(significant.parse().unwrap(), Gender::Masculine);
+    words = join(&[words, significant]);
+
+    Ok(format!(
+        "{} coma {} Unidades de Fomento",
+        cardinal(integer, Gender::Feminine, false),
+        words,
+    ))
+}
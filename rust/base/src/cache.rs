@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::errors::ClientError;
+use crate::utils::fnv1a;
+use crate::utils::temporary_path;
+
+
+/// Extension of the files of the disk cache.
+const ENTRY_EXTENSION: &str = "cache";
+
+
+/// Where a [`ResponseCache`] stores responses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheBackend {
+    /// In memory, for the life of the process, keeping at most
+    /// `max_entries` responses.
+    Memory { max_entries: usize },
+    /// In a directory, one file per response, shared by all the processes
+    /// using it.
+    Disk { path: PathBuf },
+}
+
+
+/// Configuration of a [`ResponseCache`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// Where responses are stored.
+    pub backend: CacheBackend,
+    /// How long responses are kept, unless a path has its own TTL.
+    pub ttl: Duration,
+    /// TTLs of the paths starting with a prefix, e.g. `("/ipc", ...)`. The
+    /// longest matching prefix wins. A zero TTL disables caching.
+    pub ttls: Vec<(String, Duration)>,
+}
+
+
+/// Builds the cache key of a request, leaving out the query parameters
+/// whose value is `secret`, so that keys are not stored.
+///
+/// # Behavior
+/// The key starts with a hash of `secret`, so that clients sharing a cache
+/// with different keys do not get each other's responses, which would hide
+/// invalid or revoked keys.
+///
+/// # Examples
+/// ```
+/// use base::cache::cache_key;
+///
+/// let query = [("apikey", "secret"), ("formato", "json")];
+/// let key = cache_key("https://api.example.com", "/uf", &query, "secret");
+/// assert!(key.ends_with(" https://api.example.com/uf?formato=json"));
+/// assert!(!key.contains("secret"));
+///
+/// let query = [("apikey", "other"), ("formato", "json")];
+/// assert_ne!(key, cache_key("https://api.example.com", "/uf", &query, "other"));
+/// ```
+pub fn cache_key(base_url: &str, path: &str, query: &[(&str, &str)], secret: &str) -> String {
+    let params: Vec<String> = query
+        .iter()
+        .filter(|(_, v)| *v != secret)
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+    let secret = fnv1a(secret);
+
+    if params.is_empty() {
+        format!("{:016x} {}{}", secret, base_url, path)
+    } else {
+        format!("{:016x} {}{}?{}", secret, base_url, path, params.join("&"))
+    }
+}
+
+
+#[derive(Debug, Clone)]
+struct Entry {
+    expires: SystemTime,
+    body: String,
+}
+
+
+/// Cache of successful responses, with a TTL per path.
+///
+/// # Behavior
+/// Cache failures never fail a request: entries that cannot be read are
+/// treated as missing, and responses that cannot be written are not
+/// cached.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use base::cache::CacheBackend;
+/// use base::cache::CacheConfig;
+/// use base::cache::ResponseCache;
+///
+/// let cache = ResponseCache::new(CacheConfig {
+///     backend: CacheBackend::Memory { max_entries: 16 },
+///     ttl: Duration::from_secs(60),
+///     ttls: vec![("/ipc".to_string(), Duration::ZERO)],
+/// }).unwrap();
+///
+/// cache.put("key", "/uf", "body");
+/// assert_eq!(cache.get("key").as_deref(), Some("body"));
+///
+/// // Paths with a zero TTL are not cached.
+/// cache.put("other", "/ipc/2024", "body");
+/// assert_eq!(cache.get("other"), None);
+///
+/// cache.clear().unwrap();
+/// assert_eq!(cache.get("key"), None);
+/// ```
+#[derive(Debug)]
+pub struct ResponseCache {
+    pub config: CacheConfig,
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+/// Implements methods for the [`ResponseCache`] struct.
+impl ResponseCache {
+    /// Creates a cache, creating the directory of the disk backend.
+    ///
+    /// # Errors
+    /// * [`ClientError::InvalidCache`] - If the memory backend has no room
+    ///   for entries.
+    /// * [`ClientError::CacheStorage`] - If the directory cannot be
+    ///   created.
+    pub fn new(config: CacheConfig) -> Result<Self, ClientError> {
+        match &config.backend {
+            CacheBackend::Memory { max_entries: 0 } => {
+                return Err(ClientError::InvalidCache { reason: "`max_entries` must be at least 1".to_string() });
+            }
+            CacheBackend::Disk { path } => {
+                fs::create_dir_all(path).map_err(|e| ClientError::CacheStorage {
+                    path: path.display().to_string(),
+                    reason: e.to_string(),
+                })?;
+            }
+            CacheBackend::Memory { .. } => {}
+        }
+
+        Ok(Self { config, entries: Mutex::new(HashMap::new()) })
+    }
+
+    /// Gets the TTL of a path.
+    pub fn ttl(&self, path: &str) -> Duration {
+        self.config.ttls
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.config.ttl, |(_, ttl)| *ttl)
+    }
+
+    fn entry_path(dir: &std::path::Path, key: &str) -> PathBuf {
+        dir.join(format!("{:016x}.{}", fnv1a(key), ENTRY_EXTENSION))
+    }
+
+    /// Gets a response that has not expired.
+    pub fn get(&self, key: &str) -> Option<String> {
+        let now = SystemTime::now();
+
+        match &self.config.backend {
+            CacheBackend::Memory { .. } => {
+                let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+                entries.get(key)
+                    .filter(|e| e.expires > now)
+                    .map(|e| e.body.clone())
+            }
+            CacheBackend::Disk { path } => {
+                // Files hold the key, the expiry in seconds since the epoch
+                // and the body, separated by new lines.
+                let content = fs::read_to_string(Self::entry_path(path, key)).ok()?;
+                let mut parts = content.splitn(3, '\n');
+                let (stored, expires, body) = (parts.next()?, parts.next()?, parts.next()?);
+                let expires = UNIX_EPOCH + Duration::from_secs(expires.parse().ok()?);
+
+                (stored == key && expires > now).then(|| body.to_string())
+            }
+        }
+    }
+
+    /// Stores a response of a path, unless its TTL is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use base::cache::CacheBackend;
+    /// use base::cache::CacheConfig;
+    /// use base::cache::ResponseCache;
+    ///
+    /// let path = std::env::temp_dir().join("cl_forge_cache_put_doctest");
+    /// let cache = ResponseCache::new(CacheConfig {
+    ///     backend: CacheBackend::Disk { path: path.clone() },
+    ///     ttl: Duration::from_secs(60),
+    ///     ttls: vec![],
+    /// }).unwrap();
+    ///
+    /// // Threads writing the same key never share a temporary file.
+    /// thread::scope(|s| {
+    ///     for _ in 0..8 {
+    ///         s.spawn(|| cache.put("key", "/uf", "body"));
+    ///     }
+    /// });
+    /// assert_eq!(cache.get("key").as_deref(), Some("body"));
+    ///
+    /// cache.clear().unwrap();
+    /// let _ = std::fs::remove_dir_all(&path);
+    /// ```
+    pub fn put(&self, key: &str, path: &str, body: &str) {
+        let ttl = self.ttl(path);
+        if ttl.is_zero() {
+            return;
+        }
+
+        let now = SystemTime::now();
+        let expires = now + ttl;
+
+        match &self.config.backend {
+            CacheBackend::Memory { max_entries } => {
+                let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+                if entries.len() >= *max_entries && !entries.contains_key(key) {
+                    entries.retain(|_, e| e.expires > now);
+                }
+                if entries.len() >= *max_entries && !entries.contains_key(key) {
+                    let soonest = entries.iter()
+                        .min_by_key(|(_, e)| e.expires)
+                        .map(|(k, _)| k.clone());
+                    if let Some(soonest) = soonest {
+                        entries.remove(&soonest);
+                    }
+                }
+
+                entries.insert(key.to_string(), Entry { expires, body: body.to_string() });
+            }
+            CacheBackend::Disk { path } => {
+                let seconds = expires.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+                let target = Self::entry_path(path, key);
+                let temporary = temporary_path(&target);
+
+                // Written to a temporary file and renamed, so that other
+                // threads and processes never read a partial entry.
+                let written = fs::write(&temporary, format!("{}\n{}\n{}", key, seconds, body))
+                    .and_then(|_| fs::rename(&temporary, &target));
+                if written.is_err() {
+                    let _ = fs::remove_file(&temporary);
+                }
+            }
+        }
+    }
+
+    /// Removes all the responses.
+    ///
+    /// # Errors
+    /// * [`ClientError::CacheStorage`] - If the files of the disk backend
+    ///   cannot be removed.
+    pub fn clear(&self) -> Result<(), ClientError> {
+        match &self.config.backend {
+            CacheBackend::Memory { .. } => {
+                self.entries.lock().unwrap_or_else(|e| e.into_inner()).clear();
+                Ok(())
+            }
+            CacheBackend::Disk { path } => {
+                let error = |e: std::io::Error| ClientError::CacheStorage {
+                    path: path.display().to_string(),
+                    reason: e.to_string(),
+                };
+
+                for entry in fs::read_dir(path).map_err(error)? {
+                    let file = entry.map_err(error)?.path();
+                    if file.extension().is_some_and(|e| e == ENTRY_EXTENSION) {
+                        fs::remove_file(file).map_err(error)?;
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::enums::CassetteMode;
+use crate::errors::ClientError;
+use crate::utils::temporary_path;
+
+
+/// Value that replaces API keys in the recorded query strings.
+pub const SCRUBBED: &str = "REDACTED";
+
+
+/// Builds the query string of a request as it is recorded, replacing the
+/// values equal to `secret` with [`SCRUBBED`].
+///
+/// # Examples
+/// ```
+/// use base::cassette::scrubbed_query;
+///
+/// let query = scrubbed_query(&[("apikey", "secret"), ("formato", "json")], "secret");
+/// assert_eq!(query, "apikey=REDACTED&formato=json");
+/// ```
+pub fn scrubbed_query(query: &[(&str, &str)], secret: &str) -> String {
+    query
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, if *v == secret { SCRUBBED } else { v }))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+
+/// A recorded request and its response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The HTTP method of the request.
+    pub method: String,
+    /// The URL of the request, without its query string.
+    pub url: String,
+    /// The query string of the request, scrubbed of API keys.
+    pub query: String,
+    /// The HTTP status of the response.
+    pub status: u16,
+    /// The body of the response.
+    pub body: String,
+}
+
+/// Implements methods for the [`Interaction`] struct.
+impl Interaction {
+    fn matches(&self, url: &str, query: &str) -> bool {
+        self.method == "GET" && self.url == url && self.query == query
+    }
+
+    /// Converts the recorded response to the result of a request.
+    fn response(&self) -> Result<String, ClientError> {
+        if (200..300).contains(&self.status) {
+            Ok(self.body.clone())
+        } else {
+            Err(ClientError::BadStatus { status: self.status, body: self.body.clone() })
+        }
+    }
+}
+
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+
+/// A file of recorded responses, which a client replays instead of
+/// sending requests.
+///
+/// # Behavior
+/// Requests match a recorded interaction when their URL and scrubbed
+/// query string are equal, so a cassette recorded with one API key can be
+/// replayed with any other. Error statuses are recorded and replayed as
+/// [`ClientError::BadStatus`]; connection errors are not recorded.
+///
+/// The file is a JSON object with an `interactions` list, and is saved
+/// after each recorded response. Saving it locks a `<path>.lock` file next
+/// to it, so that processes can record to the same cassette.
+///
+/// # Examples
+/// ```
+/// use base::cassette::Cassette;
+/// use base::enums::CassetteMode;
+///
+/// let path = std::env::temp_dir().join("cl_forge_cassette_doctest.json");
+/// let _ = std::fs::remove_file(&path);
+///
+/// let cassette = Cassette::open(path.clone(), CassetteMode::Auto).unwrap();
+/// let query = [("apikey", "secret"), ("formato", "json")];
+///
+/// // Missing responses are sent and recorded...
+/// let body = cassette.respond("https://api.example.com/uf", &query, "secret", || Ok("{}".to_string()));
+/// assert_eq!(body.unwrap(), "{}");
+/// assert!(!std::fs::read_to_string(&path).unwrap().contains("secret"));
+///
+/// // ...and replayed afterwards, with any key.
+/// let replay = Cassette::open(path.clone(), CassetteMode::Replay).unwrap();
+/// let query = [("apikey", "other"), ("formato", "json")];
+/// let body = replay.respond("https://api.example.com/uf", &query, "other", || unreachable!());
+/// assert_eq!(body.unwrap(), "{}");
+///
+/// let _ = std::fs::remove_file(&path);
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    pub path: PathBuf,
+    pub mode: CassetteMode,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+/// Implements methods for the [`Cassette`] struct.
+impl Cassette {
+    /// Opens a cassette, loading its recorded interactions.
+    ///
+    /// # Errors
+    /// * [`ClientError::CassetteStorage`] - If the file cannot be read or
+    ///   parsed, or if it does not exist in [`CassetteMode::Replay`].
+    pub fn open(path: PathBuf, mode: CassetteMode) -> Result<Self, ClientError> {
+        let file = if path.exists() || mode == CassetteMode::Replay {
+            let content = fs::read_to_string(&path).map_err(|e| storage_error(&path, e))?;
+            serde_json::from_str(&content).map_err(|e| storage_error(&path, e))?
+        } else {
+            CassetteFile::default()
+        };
+
+        Ok(Self { path, mode, interactions: Mutex::new(file.interactions) })
+    }
+
+    /// Gets the number of recorded interactions.
+    pub fn len(&self) -> usize {
+        self.interactions.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Checks whether no interaction has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the response of a GET request, replaying or recording it as
+    /// the mode of the cassette says.
+    ///
+    /// # Arguments
+    /// * `url` - The URL of the request, without its query string.
+    /// * `query` - The query parameters of the request.
+    /// * `secret` - The API key, scrubbed from the recorded query string.
+    /// * `send` - Sends the request, when it is not replayed.
+    ///
+    /// # Errors
+    /// * [`ClientError::CassetteMiss`] - If no response is recorded for the
+    ///   request in [`CassetteMode::Replay`].
+    /// * [`ClientError::CassetteStorage`] - If the cassette cannot be saved.
+    /// * The recorded [`ClientError::BadStatus`], or the error of `send`.
+    pub fn respond(
+        &self,
+        url: &str,
+        query: &[(&str, &str)],
+        secret: &str,
+        send: impl FnOnce() -> Result<String, ClientError>
+    ) -> Result<String, ClientError> {
+        match self.replay(url, query, secret) {
+            Some(result) => result,
+            None => self.save(url, query, secret, send()),
+        }
+    }
+
+    /// Replays the recorded response of a GET request.
+    ///
+    /// # Returns
+    /// * `Some` - The recorded response, or [`ClientError::CassetteMiss`]
+    ///   if there is none in [`CassetteMode::Replay`].
+    /// * `None` - If the request must be sent, and its result saved with
+    ///   [`Cassette::save`].
+    pub fn replay(&self, url: &str, query: &[(&str, &str)], secret: &str) -> Option<Result<String, ClientError>> {
+        let query = scrubbed_query(query, secret);
+
+        if self.mode != CassetteMode::Record {
+            let interactions = self.interactions.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(interaction) = interactions.iter().find(|i| i.matches(url, &query)) {
+                return Some(interaction.response());
+            }
+        }
+
+        if self.mode == CassetteMode::Replay {
+            return Some(Err(ClientError::CassetteMiss {
+                request: format!("GET {}?{}", url, query),
+                path: self.path.display().to_string(),
+            }));
+        }
+
+        None
+    }
+
+    /// Records the result of a GET request that was sent, unless no
+    /// response was received, and returns it.
+    ///
+    /// # Errors
+    /// * [`ClientError::CassetteStorage`] - If the cassette cannot be saved.
+    /// * The error of `result`.
+    ///
+    /// # Examples
+    /// ```
+    /// use base::cassette::Cassette;
+    /// use base::enums::CassetteMode;
+    ///
+    /// let path = std::env::temp_dir().join("cl_forge_cassette_save_doctest.json");
+    /// let _ = std::fs::remove_file(&path);
+    ///
+    /// // Cassettes opened on the same file keep what the other recorded.
+    /// let first = Cassette::open(path.clone(), CassetteMode::Record).unwrap();
+    /// let second = Cassette::open(path.clone(), CassetteMode::Record).unwrap();
+    /// first.save("https://api.example.com/uf", &[], "", Ok("1".to_string())).unwrap();
+    /// second.save("https://api.example.com/dolar", &[], "", Ok("2".to_string())).unwrap();
+    ///
+    /// let replay = Cassette::open(path.clone(), CassetteMode::Replay).unwrap();
+    /// assert_eq!(replay.len(), 2);
+    ///
+    /// let _ = std::fs::remove_file(&path);
+    /// let _ = std::fs::remove_file(path.with_extension("json.lock"));
+    /// ```
+    pub fn save(
+        &self,
+        url: &str,
+        query: &[(&str, &str)],
+        secret: &str,
+        result: Result<String, ClientError>
+    ) -> Result<String, ClientError> {
+        let (status, body) = match &result {
+            Ok(body) => (200, body.clone()),
+            Err(ClientError::BadStatus { status, body }) => (*status, body.clone()),
+            Err(_) => return result,
+        };
+
+        let query = scrubbed_query(query, secret);
+        self.record(Interaction { method: "GET".to_string(), url: url.to_string(), query, status, body })?;
+        result
+    }
+
+    /// Records an interaction, replacing the one of the same request, and
+    /// saves the cassette.
+    ///
+    /// # Behavior
+    /// Processes recording to the same cassette take turns through a
+    /// `<path>.lock` file, and the interactions saved by the others since
+    /// the cassette was opened are kept.
+    fn record(&self, interaction: Interaction) -> Result<(), ClientError> {
+        let mut interactions = self.interactions.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| storage_error(&self.path, e))?;
+        }
+
+        // Unlocked when dropped, after the cassette is renamed.
+        let _lock = self.lock()?;
+
+        if self.path.exists() {
+            let content = fs::read_to_string(&self.path).map_err(|e| storage_error(&self.path, e))?;
+            let saved: CassetteFile = serde_json::from_str(&content).map_err(|e| storage_error(&self.path, e))?;
+
+            for i in saved.interactions {
+                if !interactions.iter().any(|j| j.matches(&i.url, &i.query)) {
+                    interactions.push(i);
+                }
+            }
+        }
+
+        interactions.retain(|i| !i.matches(&interaction.url, &interaction.query));
+        interactions.push(interaction);
+
+        let file = CassetteFile { interactions: interactions.clone() };
+        let content = serde_json::to_string_pretty(&file).map_err(|e| storage_error(&self.path, e))?;
+
+        // Written to a temporary file and renamed, so that the cassette is
+        // never left half written.
+        let temporary = temporary_path(&self.path);
+        let written = fs::write(&temporary, content + "\n")
+            .and_then(|_| fs::rename(&temporary, &self.path));
+        if written.is_err() {
+            let _ = fs::remove_file(&temporary);
+        }
+
+        written.map_err(|e| storage_error(&self.path, e))
+    }
+
+    /// Opens and locks the lock file of the cassette, creating it if
+    /// needed.
+    ///
+    /// # Notes
+    /// The cassette itself is not locked, since it is replaced when saved.
+    fn lock(&self) -> Result<File, ClientError> {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(".lock");
+
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(self.path.with_file_name(name))
+            .map_err(|e| storage_error(&self.path, e))?;
+
+        file.lock().map_err(|e| storage_error(&self.path, e))?;
+        Ok(file)
+    }
+}
+
+
+fn storage_error(path: &std::path::Path, reason: impl ToString) -> ClientError {
+    ClientError::CassetteStorage { path: path.display().to_string(), reason: reason.to_string() }
+}
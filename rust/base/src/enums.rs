@@ -1,8 +1,9 @@
 use crate::errors::ClientError;
 
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
 pub enum ResponseFormat {
+    #[default]
     Json,
     Xml,
 }
@@ -33,12 +34,6 @@ impl ResponseFormat {
     }
 }
 
-impl Default for ResponseFormat {
-    fn default() -> Self {
-        ResponseFormat::Json
-    }
-}
-
 impl TryFrom<&str> for ResponseFormat {
     type Error = ClientError;
 
@@ -66,4 +61,170 @@ impl TryFrom<Option<&str>> for ResponseFormat {
             })
         }
     }
+}
+
+
+/// Period after which a request quota is reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuotaPeriod {
+    /// Reset every day, at 00:00 UTC.
+    Daily,
+    /// Reset every month, on the 1st at 00:00 UTC.
+    #[default]
+    Monthly,
+}
+
+impl QuotaPeriod {
+    pub const ALL: &'static [Self] = &[
+        Self::Daily,
+        Self::Monthly,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Monthly => "monthly",
+        }
+    }
+
+    pub fn iter() -> impl Iterator<Item = &'static Self> {
+        Self::ALL.iter()
+    }
+
+    /// Returns string with enum values separated by comma.
+    pub fn values() -> String {
+        Self::iter()
+            .map(|v| format!("'{}'", v.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl TryFrom<&str> for QuotaPeriod {
+    type Error = ClientError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.to_lowercase().trim() {
+            "daily" => Ok(QuotaPeriod::Daily),
+            "monthly" => Ok(QuotaPeriod::Monthly),
+            _ => Err(ClientError::UnknownQuotaPeriod {
+                expected: Self::values(),
+                actual: s.to_string()
+            }),
+        }
+    }
+}
+
+
+/// How a request uses the response cache of a client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheMode {
+    /// Returns a cached response if there is one, and caches new ones.
+    #[default]
+    Use,
+    /// Neither reads nor writes the cache.
+    Bypass,
+    /// Ignores the cached response, and caches the new one.
+    Refresh,
+}
+
+impl CacheMode {
+    pub const ALL: &'static [Self] = &[
+        Self::Use,
+        Self::Bypass,
+        Self::Refresh,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Use => "use",
+            Self::Bypass => "bypass",
+            Self::Refresh => "refresh",
+        }
+    }
+
+    pub fn iter() -> impl Iterator<Item = &'static Self> {
+        Self::ALL.iter()
+    }
+
+    /// Returns string with enum values separated by comma.
+    pub fn values() -> String {
+        Self::iter()
+            .map(|v| format!("'{}'", v.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl TryFrom<&str> for CacheMode {
+    type Error = ClientError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.to_lowercase().trim() {
+            "use" => Ok(CacheMode::Use),
+            "bypass" => Ok(CacheMode::Bypass),
+            "refresh" => Ok(CacheMode::Refresh),
+            _ => Err(ClientError::UnsupportedCacheMode {
+                expected: Self::values(),
+                actual: s.to_string()
+            }),
+        }
+    }
+}
+
+
+/// How a client uses its cassette of recorded responses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CassetteMode {
+    /// Replays recorded responses, and records the missing ones.
+    #[default]
+    Auto,
+    /// Sends every request, and records its response.
+    Record,
+    /// Only replays recorded responses, failing on the missing ones.
+    Replay,
+}
+
+impl CassetteMode {
+    pub const ALL: &'static [Self] = &[
+        Self::Auto,
+        Self::Record,
+        Self::Replay,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Record => "record",
+            Self::Replay => "replay",
+        }
+    }
+
+    pub fn iter() -> impl Iterator<Item = &'static Self> {
+        Self::ALL.iter()
+    }
+
+    /// Returns string with enum values separated by comma.
+    pub fn values() -> String {
+        Self::iter()
+            .map(|v| format!("'{}'", v.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl TryFrom<&str> for CassetteMode {
+    type Error = ClientError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.to_lowercase().trim() {
+            "auto" => Ok(CassetteMode::Auto),
+            "record" => Ok(CassetteMode::Record),
+            "replay" => Ok(CassetteMode::Replay),
+            _ => Err(ClientError::UnsupportedCassetteMode {
+                expected: Self::values(),
+                actual: s.to_string()
+            }),
+        }
+    }
 }
\ No newline at end of file
@@ -13,10 +13,125 @@ pub enum ClientError {
 
     #[error("HTTP error: {0}")]
     HttpError(#[from] reqwest::Error),
+
+    #[error("Transport error: {reason}")]
+    Transport {reason: String},
     
     #[error("Unexpected status {status}: {body}")]
     BadStatus {status: u16, body: String},
     
     #[error("Unsupported format. Expected {expected}, but '{actual}' was given.")]
-    UnsupportedFormat {expected: String, actual: String}
+    UnsupportedFormat {expected: String, actual: String},
+
+    #[error("Invalid retry policy: {reason}.")]
+    InvalidRetryPolicy {reason: String},
+
+    #[error("Invalid rate limit: {reason}.")]
+    InvalidRateLimit {reason: String},
+
+    #[error("Invalid quota: {reason}.")]
+    InvalidQuota {reason: String},
+
+    #[error("Unknown quota period. Expected {expected}, but '{actual}' was given.")]
+    UnknownQuotaPeriod {expected: String, actual: String},
+
+    #[error("Quota exceeded: {used} of {limit} requests already sent in {period}.")]
+    QuotaExceeded {used: u64, limit: u64, period: String},
+
+    #[error("Cannot use quota file '{path}': {reason}")]
+    QuotaStorage {path: String, reason: String},
+
+    #[error("Invalid cache: {reason}.")]
+    InvalidCache {reason: String},
+
+    #[error("Unsupported cache mode. Expected {expected}, but '{actual}' was given.")]
+    UnsupportedCacheMode {expected: String, actual: String},
+
+    #[error("Cannot use cache directory '{path}': {reason}")]
+    CacheStorage {path: String, reason: String},
+
+    #[error("Unsupported cassette mode. Expected {expected}, but '{actual}' was given.")]
+    UnsupportedCassetteMode {expected: String, actual: String},
+
+    #[error("No response recorded for '{request}' in cassette '{path}'.")]
+    CassetteMiss {request: String, path: String},
+
+    #[error("Cannot use cassette '{path}': {reason}")]
+    CassetteStorage {path: String, reason: String},
+
+    #[error("Invalid client settings: {reason}.")]
+    InvalidSettings {reason: String}
+}
+
+/// Implements methods for the [`ClientError`] enum.
+impl ClientError {
+    /// Checks whether the error may not happen again, i.e. a timeout, a
+    /// connection error or reset, or a failed read of the response body,
+    /// so that the request is worth retrying.
+    ///
+    /// # Notes
+    /// `reqwest` reports a body cut short as a decode error when reading
+    /// it as text, so decode errors are transient too.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Read;
+    /// use std::io::Write;
+    /// use std::net::TcpListener;
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use base::enums::CacheMode;
+    /// use base::native::BaseClient;
+    /// use base::native::ClientOptions;
+    /// use base::retry::RetryPolicy;
+    /// use base::transport::HttpConfig;
+    /// use base::transport::MockTransport;
+    /// use base::transport::ReqwestTransport;
+    /// use base::transport::Response;
+    /// use base::transport::Transport;
+    ///
+    /// // Gets the error of a request to a server answering with `reply`.
+    /// let error = |reply: &'static [u8]| {
+    ///     let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    ///     let url = format!("http://{}/uf", listener.local_addr().unwrap());
+    ///     std::thread::spawn(move || {
+    ///         let (mut stream, _) = listener.accept().unwrap();
+    ///         let _ = stream.read(&mut [0; 1024]);
+    ///         let _ = stream.write_all(reply);
+    ///     });
+    ///     let config = HttpConfig::new(Duration::from_secs(5), "agent", None, &[]).unwrap();
+    ///     ReqwestTransport::new(config).get(&url, &[]).unwrap_err()
+    /// };
+    /// let reset = error(b"");
+    /// let short_body = error(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n{}");
+    /// assert!(reset.is_transient());
+    /// assert!(short_body.is_transient());
+    ///
+    /// let mock = Arc::new(MockTransport::new());
+    /// mock.push("/uf", Err(reset));
+    /// mock.push("/uf", Err(short_body));
+    /// mock.push("/uf", Ok(Response::new(200, "{}")));
+    ///
+    /// let options = ClientOptions {
+    ///     retry: RetryPolicy { jitter: false, initial_backoff: Duration::ZERO, ..RetryPolicy::default() },
+    ///     transport: Some(mock.clone()),
+    ///     ..ClientOptions::default()
+    /// };
+    /// let client = BaseClient::new("secret", "https://api.example.com", "agent")
+    ///     .unwrap()
+    ///     .with_options(options)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(client.get("/uf", &[], CacheMode::Bypass).unwrap(), "{}");
+    /// assert_eq!(mock.requests().len(), 3);
+    /// ```
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::HttpError(e) => {
+                e.is_timeout() || e.is_connect() || e.is_request() || e.is_body() || e.is_decode()
+            }
+            Self::Transport { .. } => true,
+            _ => false,
+        }
+    }
 }
\ No newline at end of file
@@ -2,6 +2,17 @@ pub mod settings;
 pub mod errors;
 pub mod native;
 pub mod enums;
+pub mod retry;
+pub mod limits;
+pub mod cache;
+pub mod cassette;
+pub mod transport;
+pub mod runtime;
+mod utils;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
 use pyo3::prelude::*;
 use pyo3::create_exception;
@@ -38,6 +49,54 @@ create_exception!(
     rs_base, UnsupportedFormat, ClientException,
     "Raised when an unsupported format is requested."
 );
+create_exception!(
+    rs_base, InvalidRetryPolicy, ClientException,
+    "Raised when a retry policy is inconsistent."
+);
+create_exception!(
+    rs_base, InvalidRateLimit, ClientException,
+    "Raised when a rate limit is inconsistent."
+);
+create_exception!(
+    rs_base, InvalidQuota, ClientException,
+    "Raised when a quota is inconsistent or its period is unknown."
+);
+create_exception!(
+    rs_base, QuotaExceeded, ClientException,
+    "Raised before sending a request when the quota of the API key is exhausted."
+);
+create_exception!(
+    rs_base, QuotaStorageError, ClientException,
+    "Raised when the quota file cannot be read or written."
+);
+create_exception!(
+    rs_base, InvalidCache, ClientException,
+    "Raised when a response cache is inconsistent."
+);
+create_exception!(
+    rs_base, UnsupportedCacheMode, ClientException,
+    "Raised when an unsupported cache mode is requested."
+);
+create_exception!(
+    rs_base, CacheStorageError, ClientException,
+    "Raised when the cache directory cannot be created or cleared."
+);
+create_exception!(
+    rs_base, UnsupportedCassetteMode, ClientException,
+    "Raised when an unsupported cassette mode is requested."
+);
+create_exception!(
+    rs_base, CassetteMiss, ClientException,
+    "Raised when a replayed cassette has no response recorded for a request."
+);
+create_exception!(
+    rs_base, CassetteStorageError, ClientException,
+    "Raised when a cassette cannot be read or written."
+);
+create_exception!(
+    rs_base, InvalidSettings, ClientException,
+    "Raised when the base URL, timeout, proxy or CA bundles of a client are invalid."
+);
 
 
 impl From<ClientError> for PyErr {
@@ -47,8 +106,22 @@ impl From<ClientError> for PyErr {
             ClientError::EmptyPath => EmptyPath::new_err(err.to_string()),
             ClientError::InvalidPath => InvalidPath::new_err(err.to_string()),
             ClientError::HttpError(_) => HttpError::new_err(err.to_string()),
+            ClientError::Transport { .. } => HttpError::new_err(err.to_string()),
             ClientError::BadStatus { .. } => BadStatus::new_err(err.to_string()),
             ClientError::UnsupportedFormat { .. } => UnsupportedFormat::new_err(err.to_string()),
+            ClientError::InvalidRetryPolicy { .. } => InvalidRetryPolicy::new_err(err.to_string()),
+            ClientError::InvalidRateLimit { .. } => InvalidRateLimit::new_err(err.to_string()),
+            ClientError::InvalidQuota { .. } => InvalidQuota::new_err(err.to_string()),
+            ClientError::UnknownQuotaPeriod { .. } => InvalidQuota::new_err(err.to_string()),
+            ClientError::QuotaExceeded { .. } => QuotaExceeded::new_err(err.to_string()),
+            ClientError::QuotaStorage { .. } => QuotaStorageError::new_err(err.to_string()),
+            ClientError::InvalidCache { .. } => InvalidCache::new_err(err.to_string()),
+            ClientError::UnsupportedCacheMode { .. } => UnsupportedCacheMode::new_err(err.to_string()),
+            ClientError::CacheStorage { .. } => CacheStorageError::new_err(err.to_string()),
+            ClientError::UnsupportedCassetteMode { .. } => UnsupportedCassetteMode::new_err(err.to_string()),
+            ClientError::CassetteMiss { .. } => CassetteMiss::new_err(err.to_string()),
+            ClientError::CassetteStorage { .. } => CassetteStorageError::new_err(err.to_string()),
+            ClientError::InvalidSettings { .. } => InvalidSettings::new_err(err.to_string()),
         }
     }
 }
@@ -66,12 +139,12 @@ impl From<ClientError> for PyErr {
 ///     - [`PyValueError`] - If fail parsing the JSON string.
 ///
 /// # Examples
-/// ```
+/// ```no_run
 /// use base::json_to_dict;
-/// use pyo3::Python;
+/// use pyo3::prelude::*;
 ///
 /// let json = r#"{"key": "value"}"#;
-/// Python::with_gil(|py| {
+/// Python::attach(|py| {
 ///     let dict = json_to_dict(py, json).unwrap();
 ///     assert_eq!(
 ///         dict.get_item("key").unwrap().extract::<&str>().unwrap(),
@@ -85,13 +158,9 @@ pub fn json_to_dict<'py>(
         body: &str
 ) -> PyResult<Bound<'py, PyAny>> {
     let orjson = py.import("orjson")
-        .map_err(
-            |e| PyImportError::new_err(e)
-    )?;
+        .map_err(PyImportError::new_err)?;
     let dict = orjson.call_method1("loads", (body,))
-        .map_err(
-            |e| PyValueError::new_err(e)
-        )?;
+        .map_err(PyValueError::new_err)?;
 
     Ok(dict)
 }
@@ -122,6 +191,57 @@ impl Token {
 }
 
 
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct ClientSettings {
+    pub inner: settings::ClientSettings
+}
+
+#[pymethods]
+impl ClientSettings {
+    #[getter]
+    fn base_url(&self) -> Option<String> {
+        self.inner.base_url.clone()
+    }
+
+    #[getter]
+    fn timeout(&self) -> Option<f64> {
+        self.inner.timeout.map(|t| t.as_secs_f64())
+    }
+
+    #[getter]
+    fn user_agent(&self) -> Option<String> {
+        self.inner.user_agent.clone()
+    }
+
+    #[getter]
+    fn proxy(&self) -> Option<String> {
+        self.inner.proxy.as_ref().map(|p| p.url.clone())
+    }
+
+    #[getter]
+    fn no_proxy(&self) -> Option<String> {
+        self.inner.proxy.as_ref().and_then(|p| p.no_proxy.clone())
+    }
+
+    #[getter]
+    fn ca_bundles(&self) -> Vec<PathBuf> {
+        self.inner.ca_bundles.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        let repr = |value: Option<String>| value.map_or("None".to_string(), |v| format!("'{}'", v));
+
+        format!(
+            "ClientSettings(base_url={}, timeout={}, user_agent={})",
+            repr(self.base_url()),
+            self.timeout().map_or("None".to_string(), |t| t.to_string()),
+            repr(self.user_agent()),
+        )
+    }
+}
+
+
 #[pyclass]
 pub struct Config {
     pub inner: settings::Config
@@ -139,6 +259,380 @@ impl Config {
     fn tokens(&self) -> Token {
         Token { inner: self.inner.tokens.clone() }
     }
+
+    #[getter]
+    fn cmf(&self) -> ClientSettings {
+        ClientSettings { inner: self.inner.cmf.clone() }
+    }
+
+    #[getter]
+    fn market(&self) -> ClientSettings {
+        ClientSettings { inner: self.inner.market.clone() }
+    }
+}
+
+
+/// Converts seconds to a duration, or the reason they cannot be.
+fn seconds(name: &str, value: f64) -> Result<Duration, String> {
+    Duration::try_from_secs_f64(value)
+        .map_err(|_| format!("`{}` must be a non-negative number of seconds", name))
+}
+
+
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct RetryPolicy {
+    pub inner: retry::RetryPolicy
+}
+
+#[pymethods]
+impl RetryPolicy {
+    #[new]
+    #[pyo3(signature = (
+        max_attempts = 3,
+        initial_backoff = 0.5,
+        max_backoff = 30.0,
+        multiplier = 2.0,
+        jitter = true,
+        retry_statuses = None,
+        respect_retry_after = true,
+    ))]
+    pub fn new(
+        max_attempts: u32,
+        initial_backoff: f64,
+        max_backoff: f64,
+        multiplier: f64,
+        jitter: bool,
+        retry_statuses: Option<Vec<u16>>,
+        respect_retry_after: bool,
+    ) -> PyResult<Self> {
+        let inner = retry::RetryPolicy {
+            max_attempts,
+            initial_backoff: seconds("initial_backoff", initial_backoff)
+                .map_err(|reason| ClientError::InvalidRetryPolicy { reason })?,
+            max_backoff: seconds("max_backoff", max_backoff)
+                .map_err(|reason| ClientError::InvalidRetryPolicy { reason })?,
+            multiplier,
+            jitter,
+            retry_statuses: retry_statuses
+                .unwrap_or_else(|| retry::DEFAULT_RETRY_STATUSES.to_vec()),
+            respect_retry_after,
+        };
+        inner.validate()?;
+
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn max_attempts(&self) -> u32 {
+        self.inner.max_attempts
+    }
+
+    #[getter]
+    fn initial_backoff(&self) -> f64 {
+        self.inner.initial_backoff.as_secs_f64()
+    }
+
+    #[getter]
+    fn max_backoff(&self) -> f64 {
+        self.inner.max_backoff.as_secs_f64()
+    }
+
+    #[getter]
+    fn multiplier(&self) -> f64 {
+        self.inner.multiplier
+    }
+
+    #[getter]
+    fn jitter(&self) -> bool {
+        self.inner.jitter
+    }
+
+    #[getter]
+    fn retry_statuses(&self) -> Vec<u16> {
+        self.inner.retry_statuses.clone()
+    }
+
+    #[getter]
+    fn respect_retry_after(&self) -> bool {
+        self.inner.respect_retry_after
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RetryPolicy(max_attempts={}, initial_backoff={}, max_backoff={})",
+            self.inner.max_attempts,
+            self.initial_backoff(),
+            self.max_backoff(),
+        )
+    }
+}
+
+
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct RateLimit {
+    pub inner: limits::RateLimit
+}
+
+#[pymethods]
+impl RateLimit {
+    #[new]
+    #[pyo3(signature = (max_requests, period = 1.0, max_concurrent = None))]
+    pub fn new(max_requests: u32, period: f64, max_concurrent: Option<u32>) -> PyResult<Self> {
+        let inner = limits::RateLimit {
+            max_requests,
+            period: seconds("period", period)
+                .map_err(|reason| ClientError::InvalidRateLimit { reason })?,
+            max_concurrent,
+        };
+        inner.validate()?;
+
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn max_requests(&self) -> u32 {
+        self.inner.max_requests
+    }
+
+    #[getter]
+    fn period(&self) -> f64 {
+        self.inner.period.as_secs_f64()
+    }
+
+    #[getter]
+    fn max_concurrent(&self) -> Option<u32> {
+        self.inner.max_concurrent
+    }
+
+    fn __repr__(&self) -> String {
+        let max_concurrent = self.inner.max_concurrent.map_or("None".to_string(), |n| n.to_string());
+        format!(
+            "RateLimit(max_requests={}, period={}, max_concurrent={})",
+            self.inner.max_requests,
+            self.period(),
+            max_concurrent,
+        )
+    }
+}
+
+
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct Quota {
+    pub inner: limits::Quota
+}
+
+#[pymethods]
+impl Quota {
+    #[new]
+    #[pyo3(signature = (limit, period = "monthly", path = None))]
+    pub fn new(limit: u64, period: &str, path: Option<PathBuf>) -> PyResult<Self> {
+        let inner = limits::Quota {
+            limit,
+            period: enums::QuotaPeriod::try_from(period)?,
+            path: path.unwrap_or_else(limits::default_quota_path),
+        };
+        inner.validate()?;
+
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn limit(&self) -> u64 {
+        self.inner.limit
+    }
+
+    #[getter]
+    fn period(&self) -> &'static str {
+        self.inner.period.as_str()
+    }
+
+    #[getter]
+    fn path(&self) -> PathBuf {
+        self.inner.path.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Quota(limit={}, period='{}', path='{}')",
+            self.inner.limit,
+            self.period(),
+            self.inner.path.display(),
+        )
+    }
+}
+
+
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct Cache {
+    pub inner: Arc<cache::ResponseCache>
+}
+
+#[pymethods]
+impl Cache {
+    #[new]
+    #[pyo3(signature = (ttl = 300.0, path = None, ttls = None, max_entries = 1024))]
+    pub fn new(
+        ttl: f64,
+        path: Option<PathBuf>,
+        ttls: Option<Vec<(String, f64)>>,
+        max_entries: usize,
+    ) -> PyResult<Self> {
+        let backend = match path {
+            Some(path) => cache::CacheBackend::Disk { path },
+            None => cache::CacheBackend::Memory { max_entries },
+        };
+
+        let ttls = ttls
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(prefix, ttl)| Ok((prefix, seconds("ttls", ttl)?)))
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(|reason| ClientError::InvalidCache { reason })?;
+
+        let inner = cache::ResponseCache::new(cache::CacheConfig {
+            backend,
+            ttl: seconds("ttl", ttl).map_err(|reason| ClientError::InvalidCache { reason })?,
+            ttls,
+        })?;
+
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    #[getter]
+    fn ttl(&self) -> f64 {
+        self.inner.config.ttl.as_secs_f64()
+    }
+
+    #[getter]
+    fn ttls(&self) -> Vec<(String, f64)> {
+        self.inner.config.ttls
+            .iter()
+            .map(|(prefix, ttl)| (prefix.clone(), ttl.as_secs_f64()))
+            .collect()
+    }
+
+    #[getter]
+    fn path(&self) -> Option<PathBuf> {
+        match &self.inner.config.backend {
+            cache::CacheBackend::Disk { path } => Some(path.clone()),
+            cache::CacheBackend::Memory { .. } => None,
+        }
+    }
+
+    #[getter]
+    fn max_entries(&self) -> Option<usize> {
+        match &self.inner.config.backend {
+            cache::CacheBackend::Memory { max_entries } => Some(*max_entries),
+            cache::CacheBackend::Disk { .. } => None,
+        }
+    }
+
+    fn clear(&self) -> PyResult<()> {
+        match self.inner.clear() {
+            Ok(()) => Ok(()),
+            Err(msg) => Err(msg.into()),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        match self.path() {
+            Some(path) => format!("Cache(ttl={}, path='{}')", self.ttl(), path.display()),
+            None => format!("Cache(ttl={}, max_entries={})", self.ttl(), self.max_entries().unwrap_or_default()),
+        }
+    }
+}
+
+
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct Cassette {
+    pub inner: Arc<cassette::Cassette>
+}
+
+#[pymethods]
+impl Cassette {
+    #[new]
+    #[pyo3(signature = (path, mode = "auto"))]
+    pub fn new(path: PathBuf, mode: &str) -> PyResult<Self> {
+        let mode = enums::CassetteMode::try_from(mode)?;
+        let inner = cassette::Cassette::open(path, mode)?;
+
+        Ok(Self { inner: Arc::new(inner) })
+    }
+
+    #[getter]
+    fn path(&self) -> PathBuf {
+        self.inner.path.clone()
+    }
+
+    #[getter]
+    fn mode(&self) -> &'static str {
+        self.inner.mode.as_str()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Cassette(path='{}', mode='{}')", self.inner.path.display(), self.mode())
+    }
+}
+
+
+/// Builds the options of a client from the arguments of its Python
+/// constructor.
+pub fn client_options(
+        retry: Option<RetryPolicy>,
+        rate_limit: Option<RateLimit>,
+        quota: Option<Quota>,
+        cache: Option<Cache>,
+        cassette: Option<Cassette>
+) -> native::ClientOptions {
+    native::ClientOptions {
+        retry: retry.map_or_else(retry::RetryPolicy::disabled, |r| r.inner),
+        rate_limit: rate_limit.map(|r| r.inner),
+        quota: quota.map(|q| q.inner),
+        cache: cache.map(|c| c.inner),
+        cassette: cassette.map(|c| c.inner),
+        transport: None,
+        async_transport: None,
+        settings: settings::ClientSettings::default(),
+    }
+}
+
+
+/// Builds the settings of a client from the arguments of its Python
+/// constructor.
+pub fn client_settings(
+        base_url: Option<String>,
+        timeout: Option<f64>,
+        user_agent: Option<String>,
+        proxy: Option<String>,
+        no_proxy: Option<String>,
+        ca_bundles: Option<Vec<PathBuf>>
+) -> PyResult<settings::ClientSettings> {
+    let timeout = timeout
+        .map(|t| seconds("timeout", t))
+        .transpose()
+        .map_err(|reason| ClientError::InvalidSettings { reason })?;
+
+    if proxy.is_none() && no_proxy.is_some() {
+        let reason = "`no_proxy` requires a `proxy`".to_string();
+        return Err(ClientError::InvalidSettings { reason }.into());
+    }
+
+    Ok(settings::ClientSettings {
+        base_url,
+        timeout,
+        user_agent,
+        proxy: proxy.map(|url| settings::ProxySettings { url, no_proxy }),
+        ca_bundles: ca_bundles.unwrap_or_default(),
+    })
 }
 
 
@@ -151,7 +645,25 @@ pub fn rs_base(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("HttpError", m.py().get_type::<HttpError>())?;
     m.add("BadStatus", m.py().get_type::<BadStatus>())?;
     m.add("UnsupportedFormat", m.py().get_type::<UnsupportedFormat>())?;
+    m.add("InvalidRetryPolicy", m.py().get_type::<InvalidRetryPolicy>())?;
+    m.add("InvalidRateLimit", m.py().get_type::<InvalidRateLimit>())?;
+    m.add("InvalidQuota", m.py().get_type::<InvalidQuota>())?;
+    m.add("QuotaExceeded", m.py().get_type::<QuotaExceeded>())?;
+    m.add("QuotaStorageError", m.py().get_type::<QuotaStorageError>())?;
+    m.add("InvalidCache", m.py().get_type::<InvalidCache>())?;
+    m.add("UnsupportedCacheMode", m.py().get_type::<UnsupportedCacheMode>())?;
+    m.add("CacheStorageError", m.py().get_type::<CacheStorageError>())?;
+    m.add("UnsupportedCassetteMode", m.py().get_type::<UnsupportedCassetteMode>())?;
+    m.add("CassetteMiss", m.py().get_type::<CassetteMiss>())?;
+    m.add("CassetteStorageError", m.py().get_type::<CassetteStorageError>())?;
+    m.add("InvalidSettings", m.py().get_type::<InvalidSettings>())?;
     m.add_class::<Config>()?;
+    m.add_class::<ClientSettings>()?;
     m.add_class::<Token>()?;
+    m.add_class::<RetryPolicy>()?;
+    m.add_class::<RateLimit>()?;
+    m.add_class::<Quota>()?;
+    m.add_class::<Cache>()?;
+    m.add_class::<Cassette>()?;
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,441 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::fs::create_dir_all;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::path::PathBuf;
+use std::pin::pin;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::DateTime;
+use chrono::Utc;
+use tokio::sync::Notify;
+
+use crate::enums::QuotaPeriod;
+use crate::errors::ClientError;
+use crate::utils::fnv1a;
+use crate::utils::user_cache_dir;
+
+
+/// Name of the quota file in the default directory.
+const QUOTA_FILE_NAME: &str = "quota.tsv";
+
+
+/// Maximum number of requests sent in a period of time, and in flight at
+/// once.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use base::limits::RateLimit;
+///
+/// let limit = RateLimit { max_requests: 5, period: Duration::from_secs(1), max_concurrent: Some(1) };
+/// assert!(limit.validate().is_ok());
+/// assert!(RateLimit { max_requests: 0, ..limit }.validate().is_err());
+/// assert!(RateLimit { max_concurrent: Some(0), ..limit }.validate().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The maximum number of requests started in `period`. `1` starts
+    /// requests one at a time, spaced by `period`, even if the previous
+    /// response has not arrived yet.
+    pub max_requests: u32,
+    /// The sliding window in which requests are counted.
+    pub period: Duration,
+    /// The maximum number of requests in flight at once with the key of
+    /// the client, in all the clients of the process. See
+    /// [`ConcurrencyLimiter`].
+    pub max_concurrent: Option<u32>,
+}
+
+/// Implements methods for the [`RateLimit`] struct.
+impl RateLimit {
+    /// Checks that `max_requests`, `period` and `max_concurrent` are
+    /// positive.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        if self.max_requests == 0 {
+            return Err(ClientError::InvalidRateLimit { reason: "`max_requests` must be at least 1".to_string() });
+        }
+        if self.period.is_zero() {
+            return Err(ClientError::InvalidRateLimit { reason: "`period` must be positive".to_string() });
+        }
+        if self.max_concurrent == Some(0) {
+            return Err(ClientError::InvalidRateLimit { reason: "`max_concurrent` must be at least 1".to_string() });
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Enforces a [`RateLimit`] on the requests of a client, waiting until a
+/// request is allowed.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use std::time::Instant;
+/// use base::limits::RateLimit;
+/// use base::limits::RateLimiter;
+///
+/// let limiter = RateLimiter::new(RateLimit { max_requests: 2, period: Duration::from_millis(50), max_concurrent: None });
+/// let start = Instant::now();
+/// for _ in 0..3 {
+///     limiter.acquire();
+/// }
+/// assert!(start.elapsed() >= Duration::from_millis(50));
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    pub limit: RateLimit,
+    sent: Mutex<VecDeque<Instant>>,
+}
+
+/// Implements methods for the [`RateLimiter`] struct.
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        Self { limit, sent: Mutex::new(VecDeque::new()) }
+    }
+
+    /// Waits until a request is allowed, and records it.
+    pub fn acquire(&self) {
+        let mut sent = self.sent.lock().unwrap_or_else(|e| e.into_inner());
+
+        // The lock is held while waiting, so that requests are sent in
+        // order.
+        while let Err(wait) = self.reserve(&mut sent) {
+            thread::sleep(wait);
+        }
+    }
+
+    /// Records a request if it is allowed now, without waiting.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the request is allowed.
+    /// * `Err(Duration)` - How long to wait before trying again, e.g. in
+    ///   async clients, which cannot block their thread.
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        let mut sent = self.sent.lock().unwrap_or_else(|e| e.into_inner());
+        self.reserve(&mut sent)
+    }
+
+    fn reserve(&self, sent: &mut VecDeque<Instant>) -> Result<(), Duration> {
+        let now = Instant::now();
+
+        while sent.front().is_some_and(|t| now.duration_since(*t) >= self.limit.period) {
+            sent.pop_front();
+        }
+
+        if sent.len() < self.limit.max_requests as usize {
+            sent.push_back(now);
+            return Ok(());
+        }
+
+        Err(self.limit.period.saturating_sub(now.duration_since(sent[0])))
+    }
+}
+
+
+/// Requests in flight with a key.
+#[derive(Debug, Default)]
+struct InFlight {
+    count: Mutex<u32>,
+    /// Wakes the threads waiting for a request to finish.
+    finished: Condvar,
+    /// Wakes the tasks waiting for a request to finish.
+    notify: Notify,
+}
+
+
+/// Limits the number of requests in flight with a key, waiting until one
+/// finishes when there are too many.
+///
+/// # Behavior
+/// The requests in flight are counted per base URL and key in the whole
+/// process, so the clients sharing a key, e.g. a Mercado Público ticket,
+/// share the count, each with its own limit.
+///
+/// # Examples
+/// ```
+/// use base::limits::ConcurrencyLimiter;
+///
+/// let first = ConcurrencyLimiter::new(1, "https://api.example.com", "secret");
+/// let second = ConcurrencyLimiter::new(1, "https://api.example.com", "secret");
+/// let other = ConcurrencyLimiter::new(1, "https://api.example.com", "other");
+///
+/// let permit = first.acquire();
+/// assert!(second.try_acquire().is_none());
+/// assert!(other.try_acquire().is_some());
+///
+/// drop(permit);
+/// assert!(second.try_acquire().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimiter {
+    pub max_concurrent: u32,
+    in_flight: Arc<InFlight>,
+}
+
+/// Implements methods for the [`ConcurrencyLimiter`] struct.
+impl ConcurrencyLimiter {
+    /// Creates a limiter of the requests sent to a service with a key.
+    pub fn new(max_concurrent: u32, base_url: &str, api_key: &str) -> Self {
+        static IN_FLIGHT: OnceLock<Mutex<HashMap<String, Arc<InFlight>>>> = OnceLock::new();
+
+        let mut keys = IN_FLIGHT
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let in_flight = keys.entry(format!("{}\n{}", base_url, api_key)).or_default().clone();
+
+        Self { max_concurrent, in_flight }
+    }
+
+    /// Waits until a request is allowed, and records it.
+    ///
+    /// # Returns
+    /// The permit of the request, which must be kept until its response
+    /// arrives.
+    pub fn acquire(&self) -> Permit {
+        let mut count = self.in_flight.count.lock().unwrap_or_else(|e| e.into_inner());
+
+        while *count >= self.max_concurrent {
+            count = self.in_flight.finished.wait(count).unwrap_or_else(|e| e.into_inner());
+        }
+
+        *count += 1;
+        Permit { in_flight: self.in_flight.clone() }
+    }
+
+    /// Waits until a request is allowed without blocking the thread, and
+    /// records it. See [`ConcurrencyLimiter::acquire`].
+    pub async fn acquire_async(&self) -> Permit {
+        loop {
+            // Registered before trying, so that a request finishing in
+            // between still wakes the task.
+            let mut finished = pin!(self.in_flight.notify.notified());
+            finished.as_mut().enable();
+
+            if let Some(permit) = self.try_acquire() {
+                return permit;
+            }
+            finished.await;
+        }
+    }
+
+    /// Records a request if it is allowed now, without waiting.
+    pub fn try_acquire(&self) -> Option<Permit> {
+        let mut count = self.in_flight.count.lock().unwrap_or_else(|e| e.into_inner());
+
+        if *count >= self.max_concurrent {
+            return None;
+        }
+
+        *count += 1;
+        Some(Permit { in_flight: self.in_flight.clone() })
+    }
+}
+
+
+/// A request in flight, counted by a [`ConcurrencyLimiter`] until dropped.
+#[derive(Debug)]
+pub struct Permit {
+    in_flight: Arc<InFlight>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut count = self.in_flight.count.lock().unwrap_or_else(|e| e.into_inner());
+        *count -= 1;
+
+        // Every waiter is woken, since their limits may differ.
+        self.in_flight.finished.notify_all();
+        self.in_flight.notify.notify_waiters();
+    }
+}
+
+
+/// Maximum number of requests sent with a key in a [`QuotaPeriod`],
+/// counted in a local file shared by all the processes using it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quota {
+    /// The maximum number of requests in a period.
+    pub limit: u64,
+    /// The period after which the count is reset.
+    pub period: QuotaPeriod,
+    /// The file where requests are counted. See [`default_quota_path`].
+    pub path: PathBuf,
+}
+
+/// Implements methods for the [`Quota`] struct.
+impl Quota {
+    /// Checks that `limit` is positive.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        if self.limit == 0 {
+            return Err(ClientError::InvalidQuota { reason: "`limit` must be at least 1".to_string() });
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Gets the default quota file: `cl_forge/quota.tsv` in the user cache
+/// directory, i.e. `$XDG_CACHE_HOME`, `~/.cache` or `%LOCALAPPDATA%`.
+pub fn default_quota_path() -> PathBuf {
+    user_cache_dir().join(QUOTA_FILE_NAME)
+}
+
+
+/// Gets the name of the period a date belongs to, e.g. `"2025-10-19"` or
+/// `"2025-10"`.
+pub fn period_name(period: QuotaPeriod, date: DateTime<Utc>) -> String {
+    match period {
+        QuotaPeriod::Daily => date.format("%Y-%m-%d").to_string(),
+        QuotaPeriod::Monthly => date.format("%Y-%m").to_string(),
+    }
+}
+
+
+/// Counts the requests sent with a key against a [`Quota`].
+///
+/// # Behavior
+/// The quota file has one line per key and period, with the key, the
+/// period name and the count separated by tabs. Keys are hashed, so the
+/// file does not disclose them. The file is locked while it is updated, so
+/// several processes can share it.
+///
+/// # Examples
+/// ```
+/// use base::enums::QuotaPeriod;
+/// use base::limits::Quota;
+/// use base::limits::QuotaCounter;
+///
+/// let path = std::env::temp_dir().join("cl_forge_quota_doctest.tsv");
+/// let _ = std::fs::remove_file(&path);
+///
+/// let quota = Quota { limit: 2, period: QuotaPeriod::Daily, path: path.clone() };
+/// let counter = QuotaCounter::new(quota, "https://api.example.com", "secret");
+///
+/// assert_eq!(counter.acquire().unwrap(), 1);
+/// assert_eq!(counter.acquire().unwrap(), 2);
+/// assert!(counter.acquire().is_err());
+/// assert_eq!(counter.used().unwrap(), 2);
+///
+/// let _ = std::fs::remove_file(&path);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuotaCounter {
+    pub quota: Quota,
+    key: String,
+}
+
+/// Implements methods for the [`QuotaCounter`] struct.
+impl QuotaCounter {
+    /// Creates a counter of the requests sent to a service with a key.
+    pub fn new(quota: Quota, base_url: &str, api_key: &str) -> Self {
+        let key = format!("{:016x}", fnv1a(&format!("{}\n{}", base_url, api_key)));
+        Self { quota, key }
+    }
+
+    fn storage_error(&self, reason: impl ToString) -> ClientError {
+        ClientError::QuotaStorage {
+            path: self.quota.path.display().to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Opens and locks the quota file, creating it if needed.
+    fn open(&self) -> Result<File, ClientError> {
+        if let Some(parent) = self.quota.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            create_dir_all(parent).map_err(|e| self.storage_error(e))?;
+        }
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.quota.path)
+            .map_err(|e| self.storage_error(e))?;
+
+        file.lock().map_err(|e| self.storage_error(e))?;
+        Ok(file)
+    }
+
+    /// Reads the lines of the quota file, and the count of this key in the
+    /// current period.
+    fn read(&self, file: &mut File, period: &str) -> Result<(Vec<String>, u64), ClientError> {
+        let mut content = String::new();
+        file.read_to_string(&mut content).map_err(|e| self.storage_error(e))?;
+
+        let mut lines = Vec::new();
+        let mut used = 0;
+
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            let fields: Vec<&str> = line.split('\t').collect();
+
+            match fields.as_slice() {
+                [key, name, count] if *key == self.key => {
+                    // Counts of past periods are dropped.
+                    if *name == period {
+                        used = count.trim().parse().map_err(|_| self.storage_error("invalid count"))?;
+                    }
+                }
+                _ => lines.push(line.to_string()),
+            }
+        }
+
+        Ok((lines, used))
+    }
+
+    /// Gets the number of requests sent in the current period.
+    ///
+    /// # Errors
+    /// * [`ClientError::QuotaStorage`] - If the quota file cannot be read.
+    pub fn used(&self) -> Result<u64, ClientError> {
+        let period = period_name(self.quota.period, Utc::now());
+        let mut file = self.open()?;
+        Ok(self.read(&mut file, &period)?.1)
+    }
+
+    /// Records a request, if the quota allows it.
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The number of requests sent in the current period,
+    ///   including this one.
+    /// * `Err(ClientError)`:
+    ///   - [`ClientError::QuotaExceeded`] - If `limit` requests have
+    ///     already been sent in the current period.
+    ///   - [`ClientError::QuotaStorage`] - If the quota file cannot be read
+    ///     or written.
+    pub fn acquire(&self) -> Result<u64, ClientError> {
+        let period = period_name(self.quota.period, Utc::now());
+        let mut file = self.open()?;
+        let (mut lines, used) = self.read(&mut file, &period)?;
+
+        if used >= self.quota.limit {
+            return Err(ClientError::QuotaExceeded { used, limit: self.quota.limit, period });
+        }
+
+        lines.push(format!("{}\t{}\t{}", self.key, period, used + 1));
+        let content = lines.join("\n") + "\n";
+
+        file.set_len(0)
+            .and_then(|_| file.seek(SeekFrom::Start(0)))
+            .and_then(|_| file.write_all(content.as_bytes()))
+            .map_err(|e| self.storage_error(e))?;
+
+        Ok(used + 1)
+    }
+}
@@ -1,14 +1,131 @@
+use std::ops::ControlFlow;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
 use std::time::Duration;
 
-use reqwest::blocking::Client;
+use reqwest::Url;
 
+use crate::cache::ResponseCache;
+use crate::cache::cache_key;
+use crate::cassette::Cassette;
+use crate::enums::CacheMode;
 use crate::errors::ClientError;
+use crate::limits::ConcurrencyLimiter;
+use crate::limits::Quota;
+use crate::limits::RateLimit;
+use crate::limits::RateLimiter;
+use crate::limits::QuotaCounter;
+use crate::retry::RetryPolicy;
+use crate::settings::ClientSettings;
+use crate::settings::ProxySettings;
+use crate::transport::AsyncReqwestTransport;
+use crate::transport::AsyncTransport;
+use crate::transport::HttpConfig;
+use crate::transport::ReqwestTransport;
+use crate::transport::Response;
+use crate::transport::Transport;
 
 
+/// Timeout of each request, unless the client settings say otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+
+/// Optional behavior of a client, all disabled by default.
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    /// The policy to retry failed requests.
+    pub retry: RetryPolicy,
+    /// The rate limit of the client, waited for before each request, and
+    /// the maximum number of requests in flight with its key.
+    pub rate_limit: Option<RateLimit>,
+    /// The request quota of the API key, checked before each request.
+    pub quota: Option<Quota>,
+    /// The cache of successful responses, which may be shared by clients.
+    pub cache: Option<Arc<ResponseCache>>,
+    /// The cassette of recorded responses, replayed instead of sending
+    /// requests.
+    pub cassette: Option<Arc<Cassette>>,
+    /// The transport that sends the requests, instead of `reqwest`.
+    pub transport: Option<Arc<dyn Transport>>,
+    /// The transport that sends the requests of async clients, instead of
+    /// `reqwest`.
+    pub async_transport: Option<Arc<dyn AsyncTransport>>,
+    /// The base URL, timeout, user agent, proxy and CA bundles, instead of
+    /// the defaults of the client.
+    pub settings: ClientSettings,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            retry: RetryPolicy::disabled(),
+            rate_limit: None,
+            quota: None,
+            cache: None,
+            cassette: None,
+            transport: None,
+            async_transport: None,
+            settings: ClientSettings::default(),
+        }
+    }
+}
+
+
+/// Client sending GET requests to an API, with the retries, limits, cache
+/// and cassette of its [`ClientOptions`].
+///
+/// # Notes
+/// The client is `Send` and `Sync`, so it can be shared between threads,
+/// e.g. by the Python bindings, which send requests without holding the
+/// GIL. Its threads share the rate limit, which orders their requests, and
+/// the cache and cassette, which are locked while being read or written.
+/// The requests in flight are counted per key across all the clients of
+/// the process. The quota file is locked while counting a request, so
+/// counts are exact across threads and processes.
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use base::enums::CacheMode;
+/// use base::native::BaseClient;
+/// use base::native::ClientOptions;
+/// use base::transport::MockTransport;
+/// use base::transport::Response;
+///
+/// let mock = Arc::new(MockTransport::new());
+/// for _ in 0..4 {
+///     mock.push("/uf", Ok(Response::new(200, "{}")));
+/// }
+///
+/// let options = ClientOptions { transport: Some(mock.clone()), ..ClientOptions::default() };
+/// let client = BaseClient::new("secret", "https://api.example.com", "agent")
+///     .unwrap()
+///     .with_options(options)
+///     .unwrap();
+///
+/// thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| client.get("/uf", &[], CacheMode::Bypass).unwrap());
+///     }
+/// });
+/// assert_eq!(mock.requests().len(), 4);
+/// ```
 pub struct BaseClient {
-    pub client: Client,
+    pub transport: Arc<dyn Transport>,
     pub api_key: String,
     pub base_url: String,
+    pub timeout: Duration,
+    pub user_agent: String,
+    pub proxy: Option<ProxySettings>,
+    pub ca_bundles: Vec<PathBuf>,
+    pub retry: RetryPolicy,
+    pub rate_limiter: Option<RateLimiter>,
+    pub concurrency_limiter: Option<ConcurrencyLimiter>,
+    pub quota: Option<QuotaCounter>,
+    pub cache: Option<Arc<ResponseCache>>,
+    pub cassette: Option<Arc<Cassette>>,
 }
 
 
@@ -24,26 +141,148 @@ impl BaseClient {
             return Err(ClientError::EmptyApiKey);
         }
 
-        let client = Client::builder()
-            .timeout(Duration::from_secs(10))
-            .user_agent(user_agent)
-            .build()?;
+        let transport = ReqwestTransport::new(HttpConfig::new(DEFAULT_TIMEOUT, user_agent, None, &[])?);
 
         Ok(Self {
-            client,
+            transport: Arc::new(transport),
             api_key: api_key.to_string(),
             base_url: base_url.to_string(),
+            timeout: DEFAULT_TIMEOUT,
+            user_agent: user_agent.to_string(),
+            proxy: None,
+            ca_bundles: Vec::new(),
+            retry: RetryPolicy::disabled(),
+            rate_limiter: None,
+            concurrency_limiter: None,
+            quota: None,
+            cache: None,
+            cassette: None,
         })
     }
 
+    /// Sets the settings, retry policy, rate limit, quota, cache, cassette
+    /// and transport of the client.
+    ///
+    /// # Errors
+    /// * [`ClientError::InvalidSettings`] - If the base URL is not an HTTP
+    ///   URL, the timeout is zero, the proxy URL is invalid, or a CA bundle
+    ///   cannot be read.
+    /// * Any error of the validation of the other options.
+    pub fn with_options(mut self, options: ClientOptions) -> Result<Self, ClientError> {
+        self = self.with_settings(options.settings)?;
+
+        options.retry.validate()?;
+        self.retry = options.retry;
+
+        if let Some(limit) = options.rate_limit {
+            limit.validate()?;
+            self.rate_limiter = Some(RateLimiter::new(limit));
+            self.concurrency_limiter = limit.max_concurrent
+                .map(|n| ConcurrencyLimiter::new(n, &self.base_url, &self.api_key));
+        }
+
+        if let Some(quota) = options.quota {
+            quota.validate()?;
+            self.quota = Some(QuotaCounter::new(quota, &self.base_url, &self.api_key));
+        }
+
+        self.cache = options.cache;
+        self.cassette = options.cassette;
+
+        if let Some(transport) = options.transport {
+            self.transport = transport;
+        }
+
+        Ok(self)
+    }
+
+    /// Sets the base URL, timeout, user agent, proxy and CA bundles of the
+    /// client, keeping the current ones for the settings that are not set.
+    fn with_settings(mut self, settings: ClientSettings) -> Result<Self, ClientError> {
+        if let Some(base_url) = settings.base_url {
+            let base_url = base_url.trim().trim_end_matches('/');
+            let invalid = || ClientError::InvalidSettings {
+                reason: format!("base URL '{}' is not an HTTP URL", base_url),
+            };
+
+            let url = Url::parse(base_url).map_err(|_| invalid())?;
+            if !matches!(url.scheme(), "http" | "https") || !url.has_host() {
+                return Err(invalid());
+            }
+            self.base_url = base_url.to_string();
+        }
+
+        if settings.timeout.is_none()
+            && settings.user_agent.is_none()
+            && settings.proxy.is_none()
+            && settings.ca_bundles.is_empty() {
+            return Ok(self);
+        }
+
+        if let Some(timeout) = settings.timeout {
+            if timeout.is_zero() {
+                return Err(ClientError::InvalidSettings { reason: "timeout must be positive".to_string() });
+            }
+            self.timeout = timeout;
+        }
+        if let Some(user_agent) = settings.user_agent {
+            self.user_agent = user_agent;
+        }
+        if let Some(proxy) = settings.proxy {
+            self.proxy = Some(proxy);
+        }
+        if !settings.ca_bundles.is_empty() {
+            self.ca_bundles = settings.ca_bundles;
+        }
+
+        self.transport = Arc::new(ReqwestTransport::new(self.http_config()?));
+        Ok(self)
+    }
+
+    /// Sends a GET request, retrying it as the retry policy says.
+    ///
+    /// Each attempt waits for the rate limit and counts against the quota,
+    /// returning [`ClientError::QuotaExceeded`] without sending it when the
+    /// quota is exhausted. With [`RateLimit::max_concurrent`], it also waits
+    /// until fewer requests are in flight with the key, and counts as one
+    /// until its response arrives. When all the attempts fail, the error of
+    /// the last one is returned.
+    ///
+    /// If the client has a cache, `mode` says how the request uses it.
+    /// Cached responses are returned without sending a request, so they do
+    /// not wait for the rate limit nor count against the quota. The same
+    /// goes for the responses replayed from the cassette of the client.
     pub fn get(
         &self,
         path: &str,
-        query: &[(&str, &str)]
+        query: &[(&str, &str)],
+        mode: CacheMode
     ) -> Result<String, ClientError> {
         let path = path.trim();
-        let url = format!("{}{}", self.base_url, path);
-        
+        let url = self.url(path)?;
+
+        let cache = self.cache.as_ref().filter(|_| mode != CacheMode::Bypass);
+        let key = cache_key(&self.base_url, path, query, &self.api_key);
+
+        if let Some(cache) = cache.filter(|_| mode == CacheMode::Use)
+            && let Some(body) = cache.get(&key) {
+            return Ok(body);
+        }
+
+        let body = match &self.cassette {
+            Some(cassette) => cassette.respond(&url, query, &self.api_key, || self.send(&url, query))?,
+            None => self.send(&url, query)?,
+        };
+
+        if let Some(cache) = cache {
+            cache.put(&key, path, &body);
+        }
+
+        Ok(body)
+    }
+
+    /// Gets the URL of a path, checking the path.
+    fn url(&self, path: &str) -> Result<String, ClientError> {
         if path.is_empty() {
             return Err(ClientError::EmptyPath);
         }
@@ -51,18 +290,211 @@ impl BaseClient {
             return Err(ClientError::InvalidPath);
         }
 
-        let response = self.client
-            .get(url)
-            .query(query)
-            .send()?;
-        
-        if !response.status().is_success() {
-            return Err(ClientError::BadStatus {
-                status: response.status().as_u16(),
-                body: response.text().unwrap_or_default()
-            });
+        Ok(format!("{}{}", self.base_url, path))
+    }
+
+    /// Gets the options of the `reqwest` clients, from the settings of the
+    /// client.
+    pub fn http_config(&self) -> Result<HttpConfig, ClientError> {
+        HttpConfig::new(self.timeout, &self.user_agent, self.proxy.as_ref(), &self.ca_bundles)
+    }
+
+    /// Sends a GET request to a URL, with retries.
+    fn send(&self, url: &str, query: &[(&str, &str)]) -> Result<String, ClientError> {
+        let mut attempt = 1;
+
+        loop {
+            if let Some(quota) = &self.quota {
+                quota.acquire()?;
+            }
+            // Taken before the rate limit, so that waiting for it does not
+            // bunch the requests that were spaced.
+            let permit = self.concurrency_limiter.as_ref().map(|l| l.acquire());
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.acquire();
+            }
+
+            let result = self.transport.get(url, query);
+            drop(permit);
+
+            match self.check(attempt, result) {
+                ControlFlow::Break(result) => return result,
+                ControlFlow::Continue(delay) => thread::sleep(delay),
+            }
+
+            attempt += 1;
+        }
+    }
+
+    /// Checks the result of an attempt to send a request.
+    ///
+    /// # Returns
+    /// * `Break` - The body of the response, or the error to return.
+    /// * `Continue` - The delay before retrying the request.
+    fn check(
+        &self,
+        attempt: u32,
+        result: Result<Response, ClientError>
+    ) -> ControlFlow<Result<String, ClientError>, Duration> {
+        let (error, retry_after) = match result {
+            Ok(response) if response.is_success() => {
+                return ControlFlow::Break(Ok(response.body));
+            }
+            Ok(response) => {
+                let status = response.status;
+                let error = ClientError::BadStatus { status, body: response.body };
+
+                if !self.retry.retries_status(status) {
+                    return ControlFlow::Break(Err(error));
+                }
+                (error, response.retry_after)
+            }
+            Err(e) if e.is_transient() => (e, None),
+            Err(e) => return ControlFlow::Break(Err(e)),
+        };
+
+        match self.retry.delay(attempt, retry_after) {
+            Some(delay) => ControlFlow::Continue(delay),
+            None => ControlFlow::Break(Err(error)),
+        }
+    }
+}
+
+
+/// Async variant of [`BaseClient`], sending requests with an
+/// [`AsyncTransport`] instead of blocking the thread.
+///
+/// # Behavior
+/// Requests behave as the ones of [`BaseClient::get`], with the options of
+/// [`AsyncBaseClient::base`], but their responses, retry delays and rate
+/// limit are awaited. Reading the cache, the cassette and the quota file
+/// still blocks for the short time it takes.
+///
+/// # Notes
+/// The default transport needs a tokio runtime, such as the one of
+/// [`crate::runtime::runtime`].
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use base::enums::CacheMode;
+/// use base::native::AsyncBaseClient;
+/// use base::native::ClientOptions;
+/// use base::transport::MockTransport;
+/// use base::transport::Response;
+///
+/// let mock = Arc::new(MockTransport::new());
+/// mock.push("/uf", Ok(Response::new(200, "{}")));
+///
+/// let options = ClientOptions { async_transport: Some(mock.clone()), ..ClientOptions::default() };
+/// let client = AsyncBaseClient::new("secret", "https://api.example.com", "agent")
+///     .unwrap()
+///     .with_options(options)
+///     .unwrap();
+///
+/// let body = base::runtime::runtime().block_on(client.get("/uf", &[("apikey", "secret")], CacheMode::Use));
+/// assert_eq!(body.unwrap(), "{}");
+/// assert_eq!(mock.requests()[0].url, "https://api.example.com/uf");
+/// ```
+pub struct AsyncBaseClient {
+    /// The options and state of the client. Its blocking transport is not
+    /// used.
+    pub base: BaseClient,
+    pub transport: Arc<dyn AsyncTransport>,
+}
+
+/// Implements methods for the [`AsyncBaseClient`] struct.
+impl AsyncBaseClient {
+    pub fn new(
+        api_key: &str,
+        base_url: &str,
+        user_agent: &str
+    ) -> Result<Self, ClientError> {
+        let base = BaseClient::new(api_key, base_url, user_agent)?;
+        let transport = AsyncReqwestTransport::new(&base.http_config()?)?;
+
+        Ok(Self { base, transport: Arc::new(transport) })
+    }
+
+    /// Sets the options of the client, as [`BaseClient::with_options`]
+    /// does, but with [`ClientOptions::async_transport`] as transport.
+    pub fn with_options(mut self, mut options: ClientOptions) -> Result<Self, ClientError> {
+        let transport = options.async_transport.take();
+        let rebuild = options.settings != ClientSettings::default();
+
+        self.base = self.base.with_options(options)?;
+
+        if let Some(transport) = transport {
+            self.transport = transport;
+        } else if rebuild {
+            self.transport = Arc::new(AsyncReqwestTransport::new(&self.base.http_config()?)?);
         }
 
-        Ok(response.text()?)
+        Ok(self)
     }
-}
\ No newline at end of file
+
+    /// Sends a GET request, as [`BaseClient::get`] does.
+    pub async fn get(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        mode: CacheMode
+    ) -> Result<String, ClientError> {
+        let base = &self.base;
+        let path = path.trim();
+        let url = base.url(path)?;
+
+        let cache = base.cache.as_ref().filter(|_| mode != CacheMode::Bypass);
+        let key = cache_key(&base.base_url, path, query, &base.api_key);
+
+        if let Some(cache) = cache.filter(|_| mode == CacheMode::Use)
+            && let Some(body) = cache.get(&key) {
+            return Ok(body);
+        }
+
+        let body = match &base.cassette {
+            Some(cassette) => match cassette.replay(&url, query, &base.api_key) {
+                Some(result) => result?,
+                None => cassette.save(&url, query, &base.api_key, self.send(&url, query).await)?,
+            },
+            None => self.send(&url, query).await?,
+        };
+
+        if let Some(cache) = cache {
+            cache.put(&key, path, &body);
+        }
+
+        Ok(body)
+    }
+
+    /// Sends a GET request to a URL, with retries.
+    async fn send(&self, url: &str, query: &[(&str, &str)]) -> Result<String, ClientError> {
+        let base = &self.base;
+        let mut attempt = 1;
+
+        loop {
+            if let Some(quota) = &base.quota {
+                quota.acquire()?;
+            }
+            let permit = match &base.concurrency_limiter {
+                Some(limiter) => Some(limiter.acquire_async().await),
+                None => None,
+            };
+            if let Some(limiter) = &base.rate_limiter {
+                while let Err(wait) = limiter.try_acquire() {
+                    tokio::time::sleep(wait).await;
+                }
+            }
+
+            let result = self.transport.get(url, query).await;
+            drop(permit);
+
+            match base.check(attempt, result) {
+                ControlFlow::Break(result) => return result,
+                ControlFlow::Continue(delay) => tokio::time::sleep(delay).await,
+            }
+
+            attempt += 1;
+        }
+    }
+}
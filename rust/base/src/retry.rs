@@ -0,0 +1,179 @@
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use rand::Rng;
+
+use crate::errors::ClientError;
+
+
+/// Statuses retried by default: too many requests, and server errors that
+/// are usually transient.
+pub const DEFAULT_RETRY_STATUSES: [u16; 5] = [429, 500, 502, 503, 504];
+
+
+/// Policy to retry failed requests, with exponential backoff.
+///
+/// A request is retried when it times out, fails to connect, loses its
+/// connection or response body, or gets one of `retry_statuses`, until
+/// `max_attempts` requests have been sent. See [`ClientError::is_transient`].
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use base::retry::RetryPolicy;
+///
+/// let policy = RetryPolicy { jitter: false, ..RetryPolicy::default() };
+/// assert_eq!(policy.delay(1, None), Some(Duration::from_millis(500)));
+/// assert_eq!(policy.delay(2, None), Some(Duration::from_secs(1)));
+/// assert_eq!(policy.delay(3, None), None);
+///
+/// // A `Retry-After` header takes precedence over the backoff.
+/// assert_eq!(policy.delay(1, Some(Duration::from_secs(5))), Some(Duration::from_secs(5)));
+/// assert_eq!(policy.delay(1, Some(Duration::from_secs(60))), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of requests sent, including the first one. `1`
+    /// disables retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The maximum delay between requests.
+    pub max_backoff: Duration,
+    /// The factor the delay is multiplied by after each retry.
+    pub multiplier: f64,
+    /// Whether to randomize each delay between half and all of it, so that
+    /// clients failing together do not retry together.
+    pub jitter: bool,
+    /// The HTTP statuses that are retried.
+    pub retry_statuses: Vec<u16>,
+    /// Whether to wait as long as the `Retry-After` header of a response
+    /// asks, instead of the backoff.
+    pub respect_retry_after: bool,
+}
+
+/// Retries up to three times, waiting half a second and then one second.
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            retry_statuses: DEFAULT_RETRY_STATUSES.to_vec(),
+            respect_retry_after: true,
+        }
+    }
+}
+
+/// Implements methods for the [`RetryPolicy`] struct.
+impl RetryPolicy {
+    /// Returns a policy that sends a single request, without retries.
+    pub fn disabled() -> Self {
+        Self { max_attempts: 1, ..Self::default() }
+    }
+
+    /// Checks that the policy is consistent.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the policy is valid.
+    /// * `Err(ClientError::InvalidRetryPolicy)` - If `max_attempts` is `0`,
+    ///   `multiplier` is less than `1` or `initial_backoff` is greater than
+    ///   `max_backoff`.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        let invalid = |reason: &str| Err(ClientError::InvalidRetryPolicy { reason: reason.to_string() });
+
+        if self.max_attempts == 0 {
+            return invalid("`max_attempts` must be at least 1");
+        }
+        if !(self.multiplier >= 1.0 && self.multiplier.is_finite()) {
+            return invalid("`multiplier` must be a finite number of at least 1");
+        }
+        if self.initial_backoff > self.max_backoff {
+            return invalid("`initial_backoff` cannot be greater than `max_backoff`");
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether responses with a status are retried.
+    pub fn retries_status(&self, status: u16) -> bool {
+        self.retry_statuses.contains(&status)
+    }
+
+    /// Computes the backoff after a failed attempt, without jitter:
+    /// `initial_backoff * multiplier ^ (attempt - 1)`, up to `max_backoff`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let seconds = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+
+        Duration::try_from_secs_f64(seconds)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    /// Computes the delay before retrying a failed attempt.
+    ///
+    /// # Arguments
+    /// * `attempt` - The number of the failed attempt, from `1`.
+    /// * `retry_after` - The delay asked by the `Retry-After` header of the
+    ///   response, if any.
+    ///
+    /// # Returns
+    /// * `Some(Duration)` - The delay before the next attempt.
+    /// * `None` - If no more attempts are allowed, or if `Retry-After` asks
+    ///   for a longer delay than `max_backoff`, so that the error is
+    ///   returned instead of blocking for too long.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        if let Some(retry_after) = retry_after.filter(|_| self.respect_retry_after) {
+            return (retry_after <= self.max_backoff).then_some(retry_after);
+        }
+
+        let backoff = self.backoff(attempt);
+
+        if self.jitter && !backoff.is_zero() {
+            let factor = rand::rng().random_range(0.5..=1.0);
+            return Some(backoff.mul_f64(factor));
+        }
+
+        Some(backoff)
+    }
+}
+
+
+/// Parses the value of a `Retry-After` header.
+///
+/// # Arguments
+/// * `value` - The header value: a number of seconds, or an HTTP date.
+///
+/// # Returns
+/// The delay to wait, or `None` if the value is invalid. Dates in the past
+/// give a zero delay.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use base::retry::parse_retry_after;
+///
+/// assert_eq!(parse_retry_after(" 120 "), Some(Duration::from_secs(120)));
+/// assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO));
+/// assert_eq!(parse_retry_after("soon"), None);
+/// ```
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let delta = date.with_timezone(&Utc) - Utc::now();
+
+    Some(delta.to_std().unwrap_or(Duration::ZERO))
+}
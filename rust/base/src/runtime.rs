@@ -0,0 +1,78 @@
+use std::future::Future;
+use std::panic;
+use std::pin::Pin;
+use std::sync::OnceLock;
+use std::task::Context;
+use std::task::Poll;
+
+use tokio::runtime::Builder;
+use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
+
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+
+/// Gets the tokio runtime of the async clients of the Python bindings,
+/// starting it on the first call.
+///
+/// # Panics
+/// If the runtime cannot be started, e.g. when no thread can be spawned.
+pub fn runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| {
+        Builder::new_multi_thread()
+            .enable_all()
+            .thread_name("cl-forge-runtime")
+            .build()
+            .expect("failed to start the tokio runtime")
+    })
+}
+
+
+/// Task spawned on the [`runtime`], which can be awaited from any executor,
+/// such as the event loop of Python.
+///
+/// # Behavior
+/// Dropping the task before it completes aborts it, so that cancelling a
+/// Python coroutine also cancels its request. Panics of the task are
+/// resumed when awaiting it.
+#[derive(Debug)]
+pub struct Task<T>(JoinHandle<T>);
+
+impl<T> Future for Task<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        Pin::new(&mut self.0).poll(cx).map(|result| match result {
+            Ok(value) => value,
+            Err(e) if e.is_panic() => panic::resume_unwind(e.into_panic()),
+            // Only dropping the task aborts it.
+            Err(e) => unreachable!("task was cancelled: {}", e),
+        })
+    }
+}
+
+impl<T> Drop for Task<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+
+/// Spawns a future on the [`runtime`].
+///
+/// # Examples
+/// ```
+/// use base::runtime::runtime;
+/// use base::runtime::spawn;
+///
+/// let task = spawn(async { 1 + 1 });
+/// assert_eq!(runtime().block_on(task), 2);
+/// ```
+pub fn spawn<F>(future: F) -> Task<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    Task(runtime().spawn(future))
+}
@@ -0,0 +1,40 @@
+use std::env;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+
+/// Number of temporary paths given by [`temporary_path`] in this process.
+static TEMPORARY_FILES: AtomicU64 = AtomicU64::new(0);
+
+
+/// Hashes a text with 64-bit FNV-1a, which is stable across platforms and
+/// versions, unlike the standard library hasher.
+pub(crate) fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3))
+}
+
+
+/// Gets the `cl_forge` directory in the user cache directory, i.e.
+/// `$XDG_CACHE_HOME`, `%LOCALAPPDATA%` or `~/.cache`, falling back to the
+/// temporary directory.
+pub(crate) fn user_cache_dir() -> PathBuf {
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| env::var_os("HOME").map(|h| Path::new(&h).join(".cache")))
+        .unwrap_or_else(env::temp_dir)
+        .join("cl_forge")
+}
+
+
+/// Gets a path to write a file to before renaming it to `path`, unique to
+/// the process and the call, so that concurrent writers never share it.
+pub(crate) fn temporary_path(path: &Path) -> PathBuf {
+    let count = TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed);
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}.{}.tmp", process::id(), count));
+    path.with_file_name(name)
+}
@@ -0,0 +1,210 @@
+use chrono::Datelike;
+use chrono::Days;
+use chrono::NaiveDate;
+use chrono::Weekday;
+use territory::registry::Comuna;
+
+use crate::errors::CalendarError;
+use crate::holidays::get_holidays;
+use crate::holidays::check_year;
+
+
+/// Moves a date one day forward or backward, checking that it stays within
+/// the supported years.
+fn step(date: NaiveDate, forward: bool) -> Result<NaiveDate, CalendarError> {
+    let next = if forward { date + Days::new(1) } else { date - Days::new(1) };
+    check_year(next.year())?;
+    Ok(next)
+}
+
+
+/// Holidays of the year of the last date checked, so that checking
+/// consecutive dates builds the holidays once per year instead of once per
+/// date.
+struct YearHolidays<'a> {
+    comuna: Option<&'a Comuna>,
+    year: Option<i32>,
+    dates: Vec<NaiveDate>,
+}
+
+/// Implements methods for the [`YearHolidays`] struct.
+impl<'a> YearHolidays<'a> {
+    fn new(comuna: Option<&'a Comuna>) -> Self {
+        Self { comuna, year: None, dates: Vec::new() }
+    }
+
+    /// Checks whether a date is a business day, see [`is_business_day`].
+    fn is_business_day(&mut self, date: NaiveDate) -> Result<bool, CalendarError> {
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            check_year(date.year())?;
+            return Ok(false);
+        }
+
+        if self.year != Some(date.year()) {
+            self.dates = get_holidays(date.year(), self.comuna)?
+                .into_iter()
+                .map(|h| h.date)
+                .collect();
+            self.year = Some(date.year());
+        }
+
+        Ok(!self.dates.contains(&date))
+    }
+}
+
+
+/// Checks whether a date is a business day (día hábil), i.e. a weekday
+/// that is not a holiday.
+///
+/// # Arguments
+/// * `date` - The date to check.
+/// * `comuna` - An optional comuna, to also consider its regional and
+///   comunal holidays.
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the date is a business day.
+/// * `Err(CalendarError::UnsupportedYear)` - If the year is out of range.
+///
+/// # Notes
+/// Saturdays are not business days. Some legal terms, such as those of the
+/// Código de Procedimiento Civil, count Saturdays as business days (días
+/// hábiles judiciales); this function does not.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use calendar::business::is_business_day;
+///
+/// assert!(is_business_day(NaiveDate::from_ymd_opt(2025, 9, 17).unwrap(), None).unwrap());
+/// assert!(!is_business_day(NaiveDate::from_ymd_opt(2025, 9, 18).unwrap(), None).unwrap());
+/// assert!(!is_business_day(NaiveDate::from_ymd_opt(2025, 9, 20).unwrap(), None).unwrap());
+/// ```
+pub fn is_business_day(date: NaiveDate, comuna: Option<&Comuna>) -> Result<bool, CalendarError> {
+    YearHolidays::new(comuna).is_business_day(date)
+}
+
+
+/// Gets the first business day after a date.
+///
+/// # Arguments
+/// * `date` - The date to start from, excluded.
+/// * `comuna` - An optional comuna, see [`is_business_day`].
+///
+/// # Returns
+/// * `Ok(NaiveDate)` - The next business day.
+/// * `Err(CalendarError::UnsupportedYear)` - If a year out of range is
+///   reached.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use calendar::business::next_business_day;
+///
+/// // Friday, September 19 is followed by a weekend.
+/// let date = NaiveDate::from_ymd_opt(2025, 9, 17).unwrap();
+/// assert_eq!(next_business_day(date, None).unwrap(), NaiveDate::from_ymd_opt(2025, 9, 22).unwrap());
+/// ```
+pub fn next_business_day(date: NaiveDate, comuna: Option<&Comuna>) -> Result<NaiveDate, CalendarError> {
+    add_business_days(date, 1, comuna)
+}
+
+
+/// Adds a number of business days to a date.
+///
+/// # Arguments
+/// * `date` - The date to start from.
+/// * `days` - The number of business days to add. Negative numbers go
+///   backward.
+/// * `comuna` - An optional comuna, see [`is_business_day`].
+///
+/// # Returns
+/// * `Ok(NaiveDate)` - The business day reached after counting `days`
+///   business days, excluding `date`. If `days` is `0`, `date` itself.
+/// * `Err(CalendarError::UnsupportedYear)` - If a year out of range is
+///   reached.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use calendar::business::add_business_days;
+///
+/// let date = NaiveDate::from_ymd_opt(2025, 12, 23).unwrap();
+/// assert_eq!(add_business_days(date, 2, None).unwrap(), NaiveDate::from_ymd_opt(2025, 12, 26).unwrap());
+/// assert_eq!(add_business_days(date, -2, None).unwrap(), NaiveDate::from_ymd_opt(2025, 12, 19).unwrap());
+/// assert_eq!(add_business_days(date, 0, None).unwrap(), date);
+/// ```
+pub fn add_business_days(
+    date: NaiveDate,
+    days: i64,
+    comuna: Option<&Comuna>,
+) -> Result<NaiveDate, CalendarError> {
+    check_year(date.year())?;
+
+    let mut holidays = YearHolidays::new(comuna);
+    let mut current = date;
+    let mut remaining = days.unsigned_abs();
+
+    while remaining > 0 {
+        current = step(current, days > 0)?;
+
+        if holidays.is_business_day(current)? {
+            remaining -= 1;
+        }
+    }
+
+    Ok(current)
+}
+
+
+/// Counts the business days between two dates.
+///
+/// # Arguments
+/// * `start` - The first date, included.
+/// * `end` - The last date, excluded.
+/// * `comuna` - An optional comuna, see [`is_business_day`].
+///
+/// # Returns
+/// * `Ok(i64)` - The number of business days from `start` to `end`, or its
+///   negative if `end` is before `start`, so that adding it to `start`
+///   with [`add_business_days`] gives back `end` when both are business
+///   days.
+/// * `Err(CalendarError::UnsupportedYear)` - If a year is out of range.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use calendar::business::add_business_days;
+/// use calendar::business::business_days_between;
+///
+/// let start = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2025, 9, 22).unwrap();
+/// assert_eq!(business_days_between(start, end, None).unwrap(), 3);
+/// assert_eq!(business_days_between(end, start, None).unwrap(), -3);
+/// assert_eq!(business_days_between(start, start, None).unwrap(), 0);
+///
+/// // Ranges over several years build the holidays once per year.
+/// let end = NaiveDate::from_ymd_opt(2035, 9, 24).unwrap();
+/// let days = business_days_between(start, end, None).unwrap();
+/// assert_eq!(add_business_days(start, days, None).unwrap(), end);
+/// ```
+pub fn business_days_between(
+    start: NaiveDate,
+    end: NaiveDate,
+    comuna: Option<&Comuna>,
+) -> Result<i64, CalendarError> {
+    let (first, last, sign) = if start <= end { (start, end, 1) } else { (end, start, -1) };
+    check_year(last.year())?;
+
+    let mut holidays = YearHolidays::new(comuna);
+    let mut count = 0;
+    let mut current = first;
+
+    while current < last {
+        if holidays.is_business_day(current)? {
+            count += 1;
+        }
+        current = step(current, true)?;
+    }
+
+    Ok(sign * count)
+}
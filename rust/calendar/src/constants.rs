@@ -0,0 +1,68 @@
+use crate::enums::Scope;
+use crate::holidays::FixedHoliday;
+
+
+/// First year supported by the calendar. Moved holidays were introduced by
+/// Ley 19.668, in force since 2000.
+pub const MIN_YEAR: i32 = 2000;
+
+/// Last year supported by the calendar, since the solstice is computed
+/// with an approximation valid up to 3000.
+pub const MAX_YEAR: i32 = 2999;
+
+/// Offset of continental Chile from UTC in June, in hours, used to get the
+/// local date of the June solstice.
+pub const JUNE_UTC_OFFSET: f64 = -4.0;
+
+
+/// Holidays observed on the same date every year.
+pub const FIXED_HOLIDAYS: [FixedHoliday; 10] = [
+    FixedHoliday { month: 1, day: 1, name: "Año Nuevo", since: MIN_YEAR, irrenunciable: true, scope: Scope::National },
+    FixedHoliday { month: 5, day: 1, name: "Día Nacional del Trabajo", since: MIN_YEAR, irrenunciable: true, scope: Scope::National },
+    FixedHoliday { month: 5, day: 21, name: "Día de las Glorias Navales", since: MIN_YEAR, irrenunciable: false, scope: Scope::National },
+    FixedHoliday { month: 7, day: 16, name: "Día de la Virgen del Carmen", since: 2007, irrenunciable: false, scope: Scope::National },
+    FixedHoliday { month: 8, day: 15, name: "Asunción de la Virgen", since: MIN_YEAR, irrenunciable: false, scope: Scope::National },
+    FixedHoliday { month: 9, day: 18, name: "Independencia Nacional", since: MIN_YEAR, irrenunciable: true, scope: Scope::National },
+    FixedHoliday { month: 9, day: 19, name: "Día de las Glorias del Ejército", since: MIN_YEAR, irrenunciable: true, scope: Scope::National },
+    FixedHoliday { month: 11, day: 1, name: "Día de Todos los Santos", since: MIN_YEAR, irrenunciable: false, scope: Scope::National },
+    FixedHoliday { month: 12, day: 8, name: "Inmaculada Concepción", since: MIN_YEAR, irrenunciable: false, scope: Scope::National },
+    FixedHoliday { month: 12, day: 25, name: "Navidad", since: MIN_YEAR, irrenunciable: true, scope: Scope::National },
+];
+
+
+/// Holidays observed only in some regions or comunas, on the same date
+/// every year.
+pub const LOCAL_HOLIDAYS: [FixedHoliday; 2] = [
+    FixedHoliday { month: 6, day: 7, name: "Asalto y Toma del Morro de Arica", since: 2013, irrenunciable: false, scope: Scope::Regional("15") },
+    FixedHoliday { month: 8, day: 20, name: "Nacimiento del Prócer de la Independencia", since: 2014, irrenunciable: false, scope: Scope::Comunal(&["16101", "16103"]) },
+];
+
+
+/// Periodic terms `(A, B, C)` of the June solstice, from Meeus,
+/// *Astronomical Algorithms*, table 27.C.
+pub const SOLSTICE_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
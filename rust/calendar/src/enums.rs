@@ -0,0 +1,36 @@
+use territory::registry::Comuna;
+
+
+/// Territory where a holiday is observed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Observed in the whole country.
+    National,
+    /// Observed in the region with the given CUT code.
+    Regional(&'static str),
+    /// Observed in the comunas with the given CUT codes.
+    Comunal(&'static [&'static str]),
+}
+
+
+impl Scope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::National => "national",
+            Scope::Regional(_) => "regional",
+            Scope::Comunal(_) => "comunal",
+        }
+    }
+
+    /// Returns whether a holiday with this scope is observed in a comuna.
+    ///
+    /// Without a comuna, only national holidays are observed.
+    pub fn applies_to(&self, comuna: Option<&Comuna>) -> bool {
+        match (self, comuna) {
+            (Scope::National, _) => true,
+            (Scope::Regional(region), Some(comuna)) => comuna.region().code == *region,
+            (Scope::Comunal(codes), Some(comuna)) => codes.contains(&comuna.code),
+            (_, None) => false,
+        }
+    }
+}
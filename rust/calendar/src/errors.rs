@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum CalendarError {
+    #[error("Year {year} is not supported. Expected a year between {min} and {max}.")]
+    UnsupportedYear { year: i32, min: i32, max: i32 },
+}
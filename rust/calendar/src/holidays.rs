@@ -0,0 +1,307 @@
+use chrono::Datelike;
+use chrono::Days;
+use chrono::NaiveDate;
+use chrono::Weekday;
+use territory::registry::Comuna;
+
+use crate::enums::Scope;
+use crate::errors::CalendarError;
+use crate::constants::MAX_YEAR;
+use crate::constants::MIN_YEAR;
+use crate::constants::FIXED_HOLIDAYS;
+use crate::constants::LOCAL_HOLIDAYS;
+use crate::constants::SOLSTICE_TERMS;
+use crate::constants::JUNE_UTC_OFFSET;
+
+
+/// A holiday observed on the same date every year, since a given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedHoliday {
+    pub month: u32,
+    pub day: u32,
+    pub name: &'static str,
+    /// The first year the holiday was observed.
+    pub since: i32,
+    pub irrenunciable: bool,
+    pub scope: Scope,
+}
+
+
+/// Represents a holiday (feriado) of a given year.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use calendar::holidays::get_holidays;
+///
+/// let holidays = get_holidays(2025, None).unwrap();
+/// let christmas = holidays.last().unwrap();
+/// assert_eq!(christmas.date, NaiveDate::from_ymd_opt(2025, 12, 25).unwrap());
+/// assert_eq!(christmas.name, "Navidad");
+/// assert!(christmas.irrenunciable);
+/// assert_eq!(christmas.scope.as_str(), "national");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Holiday {
+    /// The date the holiday is observed, after moving it if the law says
+    /// so.
+    pub date: NaiveDate,
+    /// The official name of the holiday.
+    pub name: &'static str,
+    /// Whether the holiday is mandatory for retail workers (Ley 19.973).
+    pub irrenunciable: bool,
+    /// The territory where the holiday is observed.
+    pub scope: Scope,
+}
+
+/// Implements methods for the [`Holiday`] struct.
+impl Holiday {
+    fn national(date: NaiveDate, name: &'static str) -> Self {
+        Self { date, name, irrenunciable: false, scope: Scope::National }
+    }
+}
+
+
+/// Returns an error if the year is out of [`MIN_YEAR`] and [`MAX_YEAR`].
+pub(crate) fn check_year(year: i32) -> Result<(), CalendarError> {
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return Err(CalendarError::UnsupportedYear { year, min: MIN_YEAR, max: MAX_YEAR });
+    }
+
+    Ok(())
+}
+
+
+/// Builds a date of a supported year, which always exists.
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+
+/// Computes the date of Easter Sunday, with the anonymous Gregorian
+/// algorithm.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use calendar::holidays::easter;
+///
+/// assert_eq!(easter(2024), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+/// assert_eq!(easter(2025), NaiveDate::from_ymd_opt(2025, 4, 20).unwrap());
+/// assert_eq!(easter(2038), NaiveDate::from_ymd_opt(2038, 4, 25).unwrap());
+/// ```
+pub fn easter(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    ymd(year, month as u32, day as u32)
+}
+
+
+/// Computes the date of the June (winter) solstice in continental Chile.
+///
+/// # Behavior
+/// Uses the algorithm of Meeus, *Astronomical Algorithms*, chapter 27,
+/// accurate to about a minute for years 1000 to 3000.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use calendar::holidays::june_solstice;
+///
+/// assert_eq!(june_solstice(2024), NaiveDate::from_ymd_opt(2024, 6, 20).unwrap());
+/// assert_eq!(june_solstice(2025), NaiveDate::from_ymd_opt(2025, 6, 20).unwrap());
+/// assert_eq!(june_solstice(2026), NaiveDate::from_ymd_opt(2026, 6, 21).unwrap());
+/// ```
+pub fn june_solstice(year: i32) -> NaiveDate {
+    let y = (year as f64 - 2000.0) / 1000.0;
+    let jde0 = 2451716.56767 + 365241.62603 * y + 0.00325 * y.powi(2) + 0.00888 * y.powi(3)
+        - 0.00030 * y.powi(4);
+
+    let t = (jde0 - 2451545.0) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = SOLSTICE_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+
+    let jde = jde0 + 0.00001 * s / lambda;
+
+    // Julian day 2440587.5 is 1970-01-01 at 00:00 UTC.
+    let days = (jde - 2440587.5 + JUNE_UTC_OFFSET / 24.0).floor() as u64;
+    ymd(1970, 1, 1) + Days::new(days)
+}
+
+
+/// Moves a holiday per Ley 19.668: to the Monday of the same week if it
+/// falls on Tuesday, Wednesday or Thursday, and to the next Monday if it
+/// falls on Friday.
+fn move_to_monday(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Tue | Weekday::Wed | Weekday::Thu => {
+            date - Days::new(date.weekday().num_days_from_monday() as u64)
+        }
+        Weekday::Fri => date + Days::new(3),
+        _ => date,
+    }
+}
+
+
+/// Moves a holiday per Ley 20.299: to the previous Friday if it falls on
+/// Tuesday, and to the next Friday if it falls on Wednesday.
+fn move_to_friday(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Tue => date - Days::new(4),
+        Weekday::Wed => date + Days::new(2),
+        _ => date,
+    }
+}
+
+
+/// Gets the holidays of a year.
+///
+/// # Arguments
+/// * `year` - The year, between [`MIN_YEAR`] and [`MAX_YEAR`].
+/// * `comuna` - An optional comuna, to include its regional and comunal
+///   holidays. Without a comuna, only national holidays are returned.
+///
+/// # Returns
+/// * `Ok(Vec<Holiday>)` - The holidays of the year, by date.
+/// * `Err(CalendarError::UnsupportedYear)` - If the year is out of range.
+///
+/// # Behavior
+/// Besides the holidays on fixed dates, includes:
+/// - Good Friday and Holy Saturday, from Easter.
+/// - Corpus Christi, until 2006.
+/// - San Pedro y San Pablo and Encuentro de Dos Mundos, moved to a Monday
+///   per Ley 19.668.
+/// - Día de las Iglesias Evangélicas y Protestantes, since 2008, moved to a
+///   Friday per Ley 20.299.
+/// - Día Nacional de los Pueblos Indígenas, on the June solstice, since
+///   2021.
+/// - January 2 when it falls on Monday (since 2017), and September 17 or
+///   20 when they fall on Monday or Friday (since 2007).
+///
+/// # Notes
+/// Holidays declared for a single year, such as election days, and bank
+/// holidays (December 31) are not included.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use territory::registry::get_comuna;
+/// use calendar::holidays::get_holidays;
+///
+/// let holidays = get_holidays(2025, None).unwrap();
+/// assert_eq!(holidays.len(), 16);
+///
+/// // San Pedro y San Pablo, on Sunday, is not moved.
+/// let date = NaiveDate::from_ymd_opt(2025, 6, 29).unwrap();
+/// assert!(holidays.iter().any(|h| h.date == date));
+///
+/// // Encuentro de Dos Mundos, on Sunday, is not moved either, but in 2023
+/// // it fell on Thursday and was moved to Monday.
+/// let holidays = get_holidays(2023, None).unwrap();
+/// let date = NaiveDate::from_ymd_opt(2023, 10, 9).unwrap();
+/// assert!(holidays.iter().any(|h| h.date == date && h.name == "Encuentro de Dos Mundos"));
+///
+/// let chillan = get_comuna("Chillán").unwrap();
+/// assert_eq!(get_holidays(2025, Some(chillan)).unwrap().len(), 17);
+/// assert!(get_holidays(1999, None).is_err());
+/// ```
+pub fn get_holidays(year: i32, comuna: Option<&Comuna>) -> Result<Vec<Holiday>, CalendarError> {
+    check_year(year)?;
+
+    let mut holidays: Vec<Holiday> = FIXED_HOLIDAYS
+        .iter()
+        .chain(LOCAL_HOLIDAYS.iter())
+        .filter(|h| year >= h.since && h.scope.applies_to(comuna))
+        .map(|h| Holiday {
+            date: ymd(year, h.month, h.day),
+            name: h.name,
+            irrenunciable: h.irrenunciable,
+            scope: h.scope,
+        })
+        .collect();
+
+    let easter = easter(year);
+    holidays.push(Holiday::national(easter - Days::new(2), "Viernes Santo"));
+    holidays.push(Holiday::national(easter - Days::new(1), "Sábado Santo"));
+
+    if year <= 2006 {
+        holidays.push(Holiday::national(move_to_monday(easter + Days::new(60)), "Corpus Christi"));
+    }
+
+    holidays.push(Holiday::national(move_to_monday(ymd(year, 6, 29)), "San Pedro y San Pablo"));
+    holidays.push(Holiday::national(move_to_monday(ymd(year, 10, 12)), "Encuentro de Dos Mundos"));
+
+    if year >= 2008 {
+        holidays.push(Holiday::national(
+            move_to_friday(ymd(year, 10, 31)),
+            "Día de las Iglesias Evangélicas y Protestantes",
+        ));
+    }
+
+    match year {
+        // Ley 21.357 fixed the first one on June 21.
+        2021 => holidays.push(Holiday::national(ymd(year, 6, 21), "Día Nacional de los Pueblos Indígenas")),
+        2022.. => holidays.push(Holiday::national(june_solstice(year), "Día Nacional de los Pueblos Indígenas")),
+        _ => {}
+    }
+
+    let january_2 = ymd(year, 1, 2);
+    if year >= 2017 && january_2.weekday() == Weekday::Mon {
+        holidays.push(Holiday::national(january_2, "Feriado de Año Nuevo"));
+    }
+
+    let september_17 = ymd(year, 9, 17);
+    if year >= 2007 && september_17.weekday() == Weekday::Mon {
+        holidays.push(Holiday::national(september_17, "Fiestas Patrias"));
+    }
+
+    let september_20 = ymd(year, 9, 20);
+    if year >= 2007 && september_20.weekday() == Weekday::Fri {
+        holidays.push(Holiday::national(september_20, "Fiestas Patrias"));
+    }
+
+    holidays.sort_by_key(|h| h.date);
+    Ok(holidays)
+}
+
+
+/// Checks whether a date is a holiday.
+///
+/// # Arguments
+/// * `date` - The date to check.
+/// * `comuna` - An optional comuna, see [`get_holidays`].
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the date is a holiday.
+/// * `Err(CalendarError::UnsupportedYear)` - If the year is out of range.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use territory::registry::get_comuna;
+/// use calendar::holidays::is_holiday;
+///
+/// let date = NaiveDate::from_ymd_opt(2025, 6, 7).unwrap();
+/// assert!(!is_holiday(date, None).unwrap());
+/// assert!(is_holiday(date, Some(get_comuna("Putre").unwrap())).unwrap());
+/// ```
+pub fn is_holiday(date: NaiveDate, comuna: Option<&Comuna>) -> Result<bool, CalendarError> {
+    Ok(get_holidays(date.year(), comuna)?.iter().any(|h| h.date == date))
+}
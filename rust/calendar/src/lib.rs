@@ -0,0 +1,145 @@
+pub mod constants;
+pub mod enums;
+pub mod errors;
+pub mod holidays;
+pub mod business;
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use territory::registry::Comuna;
+use territory::registry::get_comuna;
+
+use crate::errors::CalendarError;
+
+
+create_exception!(
+    rs_calendar, CalendarException, PyException,
+    "Base class for all exceptions raised by the holiday calendar."
+);
+create_exception!(
+    rs_calendar, UnsupportedYear, CalendarException,
+    "Raised when the given year is out of the supported range."
+);
+
+
+impl From<CalendarError> for PyErr {
+    fn from(err: CalendarError) -> PyErr {
+        match err {
+            CalendarError::UnsupportedYear { .. } => UnsupportedYear::new_err(err.to_string()),
+        }
+    }
+}
+
+
+/// Resolves an optional comuna query, see [`get_comuna`].
+fn resolve_comuna(comuna: Option<&str>) -> PyResult<Option<&'static Comuna>> {
+    match comuna {
+        Some(query) => Ok(Some(get_comuna(query)?)),
+        None => Ok(None),
+    }
+}
+
+
+#[pyclass(frozen, eq, hash)]
+#[derive(PartialEq, Eq, Hash)]
+struct Holiday {
+    inner: holidays::Holiday,
+}
+
+#[pymethods]
+impl Holiday {
+    #[getter]
+    fn date(&self) -> NaiveDate {
+        self.inner.date
+    }
+
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.inner.name
+    }
+
+    #[getter]
+    fn irrenunciable(&self) -> bool {
+        self.inner.irrenunciable
+    }
+
+    #[getter]
+    fn scope(&self) -> &'static str {
+        self.inner.scope.as_str()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Holiday(date='{}', name='{}')", self.inner.date, self.inner.name)
+    }
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (year, comuna=None))]
+fn get_holidays(year: i32, comuna: Option<&str>) -> PyResult<Vec<Holiday>> {
+    let comuna = resolve_comuna(comuna)?;
+
+    match holidays::get_holidays(year, comuna) {
+        Ok(result) => Ok(result.into_iter().map(|inner| Holiday { inner }).collect()),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (date, comuna=None))]
+fn is_holiday(date: NaiveDate, comuna: Option<&str>) -> PyResult<bool> {
+    let comuna = resolve_comuna(comuna)?;
+    Ok(holidays::is_holiday(date, comuna)?)
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (date, comuna=None))]
+fn is_business_day(date: NaiveDate, comuna: Option<&str>) -> PyResult<bool> {
+    let comuna = resolve_comuna(comuna)?;
+    Ok(business::is_business_day(date, comuna)?)
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (date, comuna=None))]
+fn next_business_day(date: NaiveDate, comuna: Option<&str>) -> PyResult<NaiveDate> {
+    let comuna = resolve_comuna(comuna)?;
+    Ok(business::next_business_day(date, comuna)?)
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (date, days, comuna=None))]
+fn add_business_days(date: NaiveDate, days: i64, comuna: Option<&str>) -> PyResult<NaiveDate> {
+    let comuna = resolve_comuna(comuna)?;
+    Ok(business::add_business_days(date, days, comuna)?)
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (start, end, comuna=None))]
+fn business_days_between(start: NaiveDate, end: NaiveDate, comuna: Option<&str>) -> PyResult<i64> {
+    let comuna = resolve_comuna(comuna)?;
+    Ok(business::business_days_between(start, end, comuna)?)
+}
+
+
+#[pymodule]
+pub fn rs_calendar(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(get_holidays, m)?)?;
+    m.add_function(wrap_pyfunction!(is_holiday, m)?)?;
+    m.add_function(wrap_pyfunction!(is_business_day, m)?)?;
+    m.add_function(wrap_pyfunction!(next_business_day, m)?)?;
+    m.add_function(wrap_pyfunction!(add_business_days, m)?)?;
+    m.add_function(wrap_pyfunction!(business_days_between, m)?)?;
+    m.add_class::<Holiday>()?;
+
+    m.add("CalendarException", m.py().get_type::<CalendarException>())?;
+    m.add("UnsupportedYear", m.py().get_type::<UnsupportedYear>())?;
+
+    Ok(())
+}
@@ -18,11 +18,31 @@ fn rs_cl_forge(py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     base::rs_base(&base_mod)?;
     module.add_submodule(&base_mod)?;
 
+    let territory_mod = PyModule::new(py, "rs_territory")?;
+    territory::rs_territory(&territory_mod)?;
+    module.add_submodule(&territory_mod)?;
+
+    let amounts_mod = PyModule::new(py, "rs_amounts")?;
+    amounts::rs_amounts(&amounts_mod)?;
+    module.add_submodule(&amounts_mod)?;
+
+    let calendar_mod = PyModule::new(py, "rs_calendar")?;
+    calendar::rs_calendar(&calendar_mod)?;
+    module.add_submodule(&calendar_mod)?;
+
+    let dte_mod = PyModule::new(py, "rs_dte")?;
+    dte::rs_dte(&dte_mod)?;
+    module.add_submodule(&dte_mod)?;
+
     let sys_mod = py.import("sys")?.getattr("modules")?;
     sys_mod.set_item("cl_forge.core.impl.rs_cl_forge.rs_cmf", cmf_mod)?;
     sys_mod.set_item("cl_forge.core.impl.rs_cl_forge.rs_verify", verify_mod)?;
     sys_mod.set_item("cl_forge.core.impl.rs_cl_forge.rs_market", market_mod)?;
     sys_mod.set_item("cl_forge.core.impl.rs_cl_forge.rs_base", base_mod)?;
+    sys_mod.set_item("cl_forge.core.impl.rs_cl_forge.rs_territory", territory_mod)?;
+    sys_mod.set_item("cl_forge.core.impl.rs_cl_forge.rs_amounts", amounts_mod)?;
+    sys_mod.set_item("cl_forge.core.impl.rs_cl_forge.rs_calendar", calendar_mod)?;
+    sys_mod.set_item("cl_forge.core.impl.rs_cl_forge.rs_dte", dte_mod)?;
 
     Ok(())
 }
\ No newline at end of file
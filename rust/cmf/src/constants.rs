@@ -1,2 +1,3 @@
 pub const BASE_URL: &str = "https://api.cmfchile.cl/api-sbifv3/recursos_api";
-pub const USER_AGENT: &str = concat!("py-cl-forge-cmf/", env!("CARGO_PKG_VERSION"));
\ No newline at end of file
+pub const USER_AGENT: &str = concat!("py-cl-forge-cmf/", env!("CARGO_PKG_VERSION"));
+pub const ENV_NAME: &str = "CMF";
\ No newline at end of file
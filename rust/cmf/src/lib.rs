@@ -1,13 +1,22 @@
 mod constants;
-mod native;
+pub mod native;
+
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use pyo3::prelude::*;
 use pyo3::types::{PyString, PyAny};
 
+use base::Cache;
+use base::Cassette;
+use base::Quota;
+use base::RateLimit;
+use base::RetryPolicy;
+use base::enums::CacheMode;
 use base::enums::ResponseFormat;
 
 
-#[pyclass]
+#[pyclass(frozen)]
 struct CmfClient {
     client: native::CmfClient,
 }
@@ -15,8 +24,40 @@ struct CmfClient {
 #[pymethods]
 impl CmfClient {
     #[new]
-    fn new(api_key: &str) -> PyResult<Self> {
-        let client = native::CmfClient::new(api_key)?;
+    #[pyo3(signature = (
+        api_key,
+        retry=None,
+        rate_limit=None,
+        quota=None,
+        cache=None,
+        cassette=None,
+        base_url=None,
+        timeout=None,
+        user_agent=None,
+        proxy=None,
+        no_proxy=None,
+        ca_bundles=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+            api_key: &str,
+            retry: Option<RetryPolicy>,
+            rate_limit: Option<RateLimit>,
+            quota: Option<Quota>,
+            cache: Option<Cache>,
+            cassette: Option<Cassette>,
+            base_url: Option<String>,
+            timeout: Option<f64>,
+            user_agent: Option<String>,
+            proxy: Option<String>,
+            no_proxy: Option<String>,
+            ca_bundles: Option<Vec<PathBuf>>
+    ) -> PyResult<Self> {
+        let mut options = base::client_options(retry, rate_limit, quota, cache, cassette);
+        options.settings = base::client_settings(
+            base_url, timeout, user_agent, proxy, no_proxy, ca_bundles,
+        )?;
+        let client = native::CmfClient::new(api_key, options)?;
         
         Ok(Self { client })
     }
@@ -31,16 +72,83 @@ impl CmfClient {
         self.client.base.api_key.clone()
     }
 
+    #[getter]
+    fn timeout(&self) -> f64 {
+        self.client.base.timeout.as_secs_f64()
+    }
+
+    #[getter]
+    fn user_agent(&self) -> String {
+        self.client.base.user_agent.clone()
+    }
+
+    #[getter]
+    fn proxy(&self) -> Option<String> {
+        self.client.base.proxy.as_ref().map(|p| p.url.clone())
+    }
+
+    #[getter]
+    fn no_proxy(&self) -> Option<String> {
+        self.client.base.proxy.as_ref().and_then(|p| p.no_proxy.clone())
+    }
+
+    #[getter]
+    fn ca_bundles(&self) -> Vec<PathBuf> {
+        self.client.base.ca_bundles.clone()
+    }
+
+    #[getter]
+    fn retry(&self) -> RetryPolicy {
+        RetryPolicy { inner: self.client.base.retry.clone() }
+    }
+
+    #[getter]
+    fn rate_limit(&self) -> Option<RateLimit> {
+        self.client.base.rate_limiter
+            .as_ref()
+            .map(|l| RateLimit { inner: l.limit })
+    }
+
+    #[getter]
+    fn quota(&self) -> Option<Quota> {
+        self.client.base.quota
+            .as_ref()
+            .map(|q| Quota { inner: q.quota.clone() })
+    }
+
+    #[getter]
+    fn cache(&self) -> Option<Cache> {
+        self.client.base.cache
+            .clone()
+            .map(|c| Cache { inner: c })
+    }
+
+    #[getter]
+    fn cassette(&self) -> Option<Cassette> {
+        self.client.base.cassette
+            .clone()
+            .map(|c| Cassette { inner: c })
+    }
+
+    fn quota_used(&self) -> PyResult<Option<u64>> {
+        match &self.client.base.quota {
+            Some(quota) => Ok(Some(quota.used()?)),
+            None => Ok(None),
+        }
+    }
+
     //noinspection DuplicatedCode
-    #[pyo3(signature = (path, fmt="json"))]
+    #[pyo3(signature = (path, fmt="json", cache="use"))]
     fn get<'py>(
             &self,
             py: Python<'py>,
             path: &str,
-            fmt: Option<&str>
+            fmt: Option<&str>,
+            cache: &str
     ) -> PyResult<Bound<'py, PyAny>> {
         let fmt = ResponseFormat::try_from(fmt)?;
-        let body: String = self.client.get(path, fmt)?;
+        let mode = CacheMode::try_from(cache)?;
+        let body: String = py.detach(|| self.client.get(path, fmt, mode))?;
         
         match fmt {
             ResponseFormat::Json => {
@@ -62,8 +170,166 @@ impl CmfClient {
 }
 
 
+#[pyclass(frozen)]
+struct AsyncCmfClient {
+    client: Arc<native::AsyncCmfClient>,
+}
+
+#[pymethods]
+impl AsyncCmfClient {
+    #[new]
+    #[pyo3(signature = (
+        api_key,
+        retry=None,
+        rate_limit=None,
+        quota=None,
+        cache=None,
+        cassette=None,
+        base_url=None,
+        timeout=None,
+        user_agent=None,
+        proxy=None,
+        no_proxy=None,
+        ca_bundles=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+            api_key: &str,
+            retry: Option<RetryPolicy>,
+            rate_limit: Option<RateLimit>,
+            quota: Option<Quota>,
+            cache: Option<Cache>,
+            cassette: Option<Cassette>,
+            base_url: Option<String>,
+            timeout: Option<f64>,
+            user_agent: Option<String>,
+            proxy: Option<String>,
+            no_proxy: Option<String>,
+            ca_bundles: Option<Vec<PathBuf>>
+    ) -> PyResult<Self> {
+        let mut options = base::client_options(retry, rate_limit, quota, cache, cassette);
+        options.settings = base::client_settings(
+            base_url, timeout, user_agent, proxy, no_proxy, ca_bundles,
+        )?;
+        let client = native::AsyncCmfClient::new(api_key, options)?;
+        
+        Ok(Self { client: Arc::new(client) })
+    }
+    
+    #[getter]
+    fn base_url(&self) -> String {
+        self.client.base.base.base_url.clone()
+    }
+    
+    #[getter]
+    fn api_key(&self) -> String {
+        self.client.base.base.api_key.clone()
+    }
+
+    #[getter]
+    fn timeout(&self) -> f64 {
+        self.client.base.base.timeout.as_secs_f64()
+    }
+
+    #[getter]
+    fn user_agent(&self) -> String {
+        self.client.base.base.user_agent.clone()
+    }
+
+    #[getter]
+    fn proxy(&self) -> Option<String> {
+        self.client.base.base.proxy.as_ref().map(|p| p.url.clone())
+    }
+
+    #[getter]
+    fn no_proxy(&self) -> Option<String> {
+        self.client.base.base.proxy.as_ref().and_then(|p| p.no_proxy.clone())
+    }
+
+    #[getter]
+    fn ca_bundles(&self) -> Vec<PathBuf> {
+        self.client.base.base.ca_bundles.clone()
+    }
+
+    #[getter]
+    fn retry(&self) -> RetryPolicy {
+        RetryPolicy { inner: self.client.base.base.retry.clone() }
+    }
+
+    #[getter]
+    fn rate_limit(&self) -> Option<RateLimit> {
+        self.client.base.base.rate_limiter
+            .as_ref()
+            .map(|l| RateLimit { inner: l.limit })
+    }
+
+    #[getter]
+    fn quota(&self) -> Option<Quota> {
+        self.client.base.base.quota
+            .as_ref()
+            .map(|q| Quota { inner: q.quota.clone() })
+    }
+
+    #[getter]
+    fn cache(&self) -> Option<Cache> {
+        self.client.base.base.cache
+            .clone()
+            .map(|c| Cache { inner: c })
+    }
+
+    #[getter]
+    fn cassette(&self) -> Option<Cassette> {
+        self.client.base.base.cassette
+            .clone()
+            .map(|c| Cassette { inner: c })
+    }
+
+    fn quota_used(&self) -> PyResult<Option<u64>> {
+        match &self.client.base.base.quota {
+            Some(quota) => Ok(Some(quota.used()?)),
+            None => Ok(None),
+        }
+    }
+
+    //noinspection DuplicatedCode
+    #[pyo3(signature = (path, fmt=Some("json".to_string()), cache="use".to_string()))]
+    async fn get(
+            &self,
+            path: String,
+            fmt: Option<String>,
+            cache: String
+    ) -> PyResult<Py<PyAny>> {
+        let fmt = ResponseFormat::try_from(fmt.as_deref())?;
+        let mode = CacheMode::try_from(cache.as_str())?;
+        let client = self.client.clone();
+
+        let body: String = base::runtime::spawn(async move {
+            client.get(&path, fmt, mode).await
+        }).await?;
+
+        Python::attach(|py| match fmt {
+            ResponseFormat::Json => {
+                Ok(base::json_to_dict(py, &body)?.unbind())
+            }
+            ResponseFormat::Xml => {
+                Ok(PyString::new(py, &body).into_any().unbind())
+            }
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AsyncCmfClient(api_key='{}', base_url='{}')",
+            self.api_key(),
+            self.base_url(),
+        )
+    }
+}
+
+
 #[pymodule]
 pub fn rs_cmf(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<CmfClient>()?;
+    m.add_class::<AsyncCmfClient>()?;
     Ok(())
 }
\ No newline at end of file
@@ -1,21 +1,71 @@
 use crate::constants;
 
+use base::native::AsyncBaseClient;
 use base::native::BaseClient;
+use base::native::ClientOptions;
 use base::errors::ClientError;
+use base::enums::CacheMode;
 use base::enums::ResponseFormat;
+use base::settings::ClientSettings;
 
+/// Client of the CMF API.
+///
+/// # Behavior
+/// The base URL, timeout and user agent not set in the settings of the
+/// options are read from the `CLFORGE_CMF_BASE_URL`, `CLFORGE_CMF_TIMEOUT`
+/// and `CLFORGE_CMF_USER_AGENT` environment variables, and default to the
+/// ones of the CMF API otherwise.
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use base::enums::CacheMode;
+/// use base::enums::ResponseFormat;
+/// use base::native::ClientOptions;
+/// use base::settings::ClientSettings;
+/// use base::transport::MockTransport;
+/// use base::transport::Response;
+/// use cmf::native::CmfClient;
+///
+/// let mock = Arc::new(MockTransport::new());
+/// mock.push("/uf", Ok(Response::new(200, r#"{"UFs": [{"Valor": "39.485,65", "Fecha": "2025-10-19"}]}"#)));
+///
+/// let options = ClientOptions { transport: Some(mock.clone()), ..ClientOptions::default() };
+/// let client = CmfClient::new("secret", options).unwrap();
+///
+/// let body = client.get("/uf", ResponseFormat::Json, CacheMode::Use).unwrap();
+/// assert!(body.contains("39.485,65"));
+///
+/// let request = &mock.requests()[0];
+/// assert_eq!(request.url, "https://api.cmfchile.cl/api-sbifv3/recursos_api/uf");
+/// assert_eq!(request.query[1], ("formato".to_string(), "json".to_string()));
+///
+/// // e.g., a local mock server
+/// mock.push("/uf", Ok(Response::new(200, "{}")));
+/// let settings = ClientSettings {
+///     base_url: Some("http://127.0.0.1:8787/api-sbifv3/recursos_api/".to_string()),
+///     ..ClientSettings::default()
+/// };
+/// let options = ClientOptions { transport: Some(mock.clone()), settings, ..ClientOptions::default() };
+/// let client = CmfClient::new("secret", options).unwrap();
+///
+/// client.get("/uf", ResponseFormat::Json, CacheMode::Use).unwrap();
+/// assert_eq!(mock.requests()[1].url, "http://127.0.0.1:8787/api-sbifv3/recursos_api/uf");
+/// ```
 pub struct CmfClient {
     pub base: BaseClient,
 }
 
 impl CmfClient {
     //noinspection DuplicatedCode
-    pub fn new(api_key: &str) -> Result<Self, ClientError> {
+    pub fn new(api_key: &str, mut options: ClientOptions) -> Result<Self, ClientError> {
+        options.settings = options.settings.or(ClientSettings::from_env(constants::ENV_NAME));
+
         let base = BaseClient::new(
             api_key,
             constants::BASE_URL,
             constants::USER_AGENT,
-        )?;
+        )?.with_options(options)?;
 
         Ok(Self { base })
     }
@@ -23,15 +73,77 @@ impl CmfClient {
     pub fn get(
             &self,
             path: &str,
-            fmt: ResponseFormat
+            fmt: ResponseFormat,
+            mode: CacheMode
     ) -> Result<String, ClientError> {
         let query = [
             ("apikey", self.base.api_key.as_str()),
             ("formato", fmt.as_str())
         ];
         
-        let response = self.base.get(path, &query)?;
+        let response = self.base.get(path, &query, mode)?;
 
         Ok(response)
     }
+}
+
+
+/// Async client of the CMF API.
+///
+/// # Behavior
+/// As [`CmfClient`], with the transport of
+/// [`ClientOptions::async_transport`].
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use base::enums::CacheMode;
+/// use base::enums::ResponseFormat;
+/// use base::native::ClientOptions;
+/// use base::runtime::runtime;
+/// use base::transport::MockTransport;
+/// use base::transport::Response;
+/// use cmf::native::AsyncCmfClient;
+///
+/// let mock = Arc::new(MockTransport::new());
+/// mock.push("/dolar", Ok(Response::new(200, r#"{"Dolares": [{"Valor": "944,32", "Fecha": "2025-10-17"}]}"#)));
+///
+/// let options = ClientOptions { async_transport: Some(mock.clone()), ..ClientOptions::default() };
+/// let client = AsyncCmfClient::new("secret", options).unwrap();
+///
+/// let body = runtime().block_on(client.get("/dolar", ResponseFormat::Json, CacheMode::Use)).unwrap();
+/// assert!(body.contains("944,32"));
+/// assert_eq!(mock.requests()[0].url, "https://api.cmfchile.cl/api-sbifv3/recursos_api/dolar");
+/// ```
+pub struct AsyncCmfClient {
+    pub base: AsyncBaseClient,
+}
+
+impl AsyncCmfClient {
+    //noinspection DuplicatedCode
+    pub fn new(api_key: &str, mut options: ClientOptions) -> Result<Self, ClientError> {
+        options.settings = options.settings.or(ClientSettings::from_env(constants::ENV_NAME));
+
+        let base = AsyncBaseClient::new(
+            api_key,
+            constants::BASE_URL,
+            constants::USER_AGENT,
+        )?.with_options(options)?;
+
+        Ok(Self { base })
+    }
+
+    pub async fn get(
+            &self,
+            path: &str,
+            fmt: ResponseFormat,
+            mode: CacheMode
+    ) -> Result<String, ClientError> {
+        let query = [
+            ("apikey", self.base.base.api_key.as_str()),
+            ("formato", fmt.as_str())
+        ];
+
+        self.base.get(path, &query, mode).await
+    }
 }
\ No newline at end of file
@@ -0,0 +1,346 @@
+use chrono::NaiveDate;
+use roxmltree::Document;
+use roxmltree::Node;
+use rust_decimal::Decimal;
+use verify::utils::Rut;
+
+use crate::enums::DteType;
+use crate::enums::StampStatus;
+use crate::errors::DteError;
+use crate::stamp::Stamp;
+use crate::utils::child;
+use crate::utils::children;
+use crate::utils::required;
+use crate::utils::parse_value;
+use crate::utils::format_rut;
+use crate::utils::required_rut;
+use crate::utils::required_date;
+use crate::utils::optional_text;
+use crate::utils::required_text;
+use crate::utils::optional_value;
+use crate::utils::required_value;
+
+
+/// Local names of the elements holding a document, by document type:
+/// `Documento` for most types, `Exportaciones` for export documents and
+/// `Liquidacion` for liquidaciones factura.
+const DOCUMENT_ELEMENTS: [&str; 3] = ["Documento", "Exportaciones", "Liquidacion"];
+
+
+/// Represents the emitter of a document.
+#[derive(Debug, Clone)]
+pub struct Emitter {
+    /// The RUT of the emitter.
+    pub rut: Rut,
+    /// The business name (razón social).
+    pub business_name: String,
+    /// The business activity (giro).
+    pub activity: Option<String>,
+    /// The economic activity codes (Acteco).
+    pub activity_codes: Vec<u32>,
+    /// The address of origin.
+    pub address: Option<String>,
+    /// The comuna of origin.
+    pub comuna: Option<String>,
+    /// The city of origin.
+    pub city: Option<String>,
+}
+
+/// Implements methods for the [`Emitter`] struct.
+impl Emitter {
+    /// Parses an `Emisor` element. Boletas use `RznSocEmisor` and
+    /// `GiroEmisor` instead of `RznSoc` and `GiroEmis`.
+    fn parse(node: Node) -> Result<Self, DteError> {
+        let business_name = optional_text(node, "RznSoc")
+            .or_else(|| optional_text(node, "RznSocEmisor"))
+            .ok_or_else(|| DteError::MissingElement { element: "RznSoc".to_string() })?;
+
+        Ok(Self {
+            rut: required_rut(node, "RUTEmisor")?,
+            business_name,
+            activity: optional_text(node, "GiroEmis").or_else(|| optional_text(node, "GiroEmisor")),
+            activity_codes: children(node, "Acteco")
+                .filter_map(|n| n.text())
+                .map(|t| parse_value("Acteco", t.trim()))
+                .collect::<Result<_, _>>()?,
+            address: optional_text(node, "DirOrigen"),
+            comuna: optional_text(node, "CmnaOrigen"),
+            city: optional_text(node, "CiudadOrigen"),
+        })
+    }
+}
+
+
+/// Represents the receiver of a document.
+#[derive(Debug, Clone)]
+pub struct Receiver {
+    /// The RUT of the receiver. Boletas to unidentified consumers use
+    /// `66666666-6`.
+    pub rut: Rut,
+    /// The business name (razón social).
+    pub business_name: Option<String>,
+    /// The business activity (giro).
+    pub activity: Option<String>,
+    /// The address.
+    pub address: Option<String>,
+    /// The comuna.
+    pub comuna: Option<String>,
+    /// The city.
+    pub city: Option<String>,
+}
+
+/// Implements methods for the [`Receiver`] struct.
+impl Receiver {
+    /// Parses a `Receptor` element.
+    fn parse(node: Node) -> Result<Self, DteError> {
+        Ok(Self {
+            rut: required_rut(node, "RUTRecep")?,
+            business_name: optional_text(node, "RznSocRecep"),
+            activity: optional_text(node, "GiroRecep"),
+            address: optional_text(node, "DirRecep"),
+            comuna: optional_text(node, "CmnaRecep"),
+            city: optional_text(node, "CiudadRecep"),
+        })
+    }
+}
+
+
+/// Represents the totals of a document, in pesos.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Totals {
+    /// The net amount subject to VAT.
+    pub net: Option<i64>,
+    /// The amount exempt from VAT.
+    pub exempt: Option<i64>,
+    /// The VAT rate, in percent.
+    pub vat_rate: Option<Decimal>,
+    /// The VAT amount.
+    pub vat: Option<i64>,
+    /// The total amount.
+    pub total: i64,
+}
+
+/// Implements methods for the [`Totals`] struct.
+impl Totals {
+    /// Parses a `Totales` element.
+    fn parse(node: Node) -> Result<Self, DteError> {
+        Ok(Self {
+            net: optional_value(node, "MntNeto")?,
+            exempt: optional_value(node, "MntExe")?,
+            vat_rate: optional_value(node, "TasaIVA")?,
+            vat: optional_value(node, "IVA")?,
+            total: required_value(node, "MntTotal")?,
+        })
+    }
+}
+
+
+/// Represents an item (detail line) of a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    /// The line number, from `1`.
+    pub line: u32,
+    /// The name of the item.
+    pub name: String,
+    /// The description of the item.
+    pub description: Option<String>,
+    /// The quantity.
+    pub quantity: Option<Decimal>,
+    /// The unit of measure.
+    pub unit: Option<String>,
+    /// The unit price.
+    pub price: Option<Decimal>,
+    /// The discount amount.
+    pub discount: Option<i64>,
+    /// The amount of the line.
+    pub amount: i64,
+    /// Whether the item is exempt from VAT (or otherwise not billable).
+    pub exempt: bool,
+}
+
+/// Implements methods for the [`Item`] struct.
+impl Item {
+    /// Parses a `Detalle` element.
+    fn parse(node: Node) -> Result<Self, DteError> {
+        Ok(Self {
+            line: required_value(node, "NroLinDet")?,
+            name: required_text(node, "NmbItem")?,
+            description: optional_text(node, "DscItem"),
+            quantity: optional_value(node, "QtyItem")?,
+            unit: optional_text(node, "UnmdItem"),
+            price: optional_value(node, "PrcItem")?,
+            discount: optional_value(node, "DescuentoMonto")?,
+            amount: required_value(node, "MontoItem")?,
+            exempt: child(node, "IndExe").is_some(),
+        })
+    }
+}
+
+
+/// Represents an electronic tax document (DTE) of the SII.
+///
+/// # Examples
+/// ```
+/// use dte::document::parse_dte;
+/// use dte::enums::StampStatus;
+/// use dte::utils::decode_xml;
+/// use dte::utils::format_rut;
+///
+/// let data = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../tests/fixtures/dte_33.xml"));
+/// let dte = parse_dte(&decode_xml(data).unwrap()).unwrap();
+///
+/// assert_eq!(dte.dte_type.code(), 33);
+/// assert_eq!(dte.folio, 60);
+/// assert_eq!(format_rut(&dte.emitter.rut), "97975000-5");
+/// assert_eq!(dte.receiver.business_name.as_deref(), Some("Comercial Niño & Cía"));
+/// assert_eq!(dte.items.len(), 2);
+/// assert_eq!(dte.items[1].quantity.unwrap().to_string(), "1.5");
+/// assert_eq!(dte.totals.total, 61285);
+/// assert_eq!(dte.check_stamp().unwrap(), StampStatus::Consistent);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dte {
+    /// The document type.
+    pub dte_type: DteType,
+    /// The folio (number) of the document.
+    pub folio: u64,
+    /// The issue date.
+    pub issue_date: NaiveDate,
+    /// The emitter.
+    pub emitter: Emitter,
+    /// The receiver.
+    pub receiver: Receiver,
+    /// The totals.
+    pub totals: Totals,
+    /// The items, by line number.
+    pub items: Vec<Item>,
+    /// The electronic stamp, if any.
+    pub stamp: Option<Stamp>,
+}
+
+/// Implements methods for the [`Dte`] struct.
+impl Dte {
+    /// Parses a `Documento`, `Exportaciones` or `Liquidacion` element.
+    fn parse(node: Node) -> Result<Self, DteError> {
+        let header = required(node, "Encabezado")?;
+        let id = required(header, "IdDoc")?;
+
+        Ok(Self {
+            dte_type: DteType::try_from(required_text(id, "TipoDTE")?.as_str())?,
+            folio: required_value(id, "Folio")?,
+            issue_date: required_date(id, "FchEmis")?,
+            emitter: Emitter::parse(required(header, "Emisor")?)?,
+            receiver: Receiver::parse(required(header, "Receptor")?)?,
+            totals: Totals::parse(required(header, "Totales")?)?,
+            items: children(node, "Detalle").map(Item::parse).collect::<Result<_, _>>()?,
+            stamp: child(node, "TED").map(Stamp::parse).transpose()?,
+        })
+    }
+
+    /// Checks that the electronic stamp is consistent with the document
+    /// and with its CAF, offline.
+    ///
+    /// # Returns
+    /// * `Ok(StampStatus::Consistent)` - If the stamp matches the document
+    ///   and its signature is valid for the public key of the CAF.
+    /// * `Ok(StampStatus::Invalid)` - If the signature is invalid.
+    /// * `Err(DteError)`:
+    ///   - [`DteError::MissingElement`] - If the document has no stamp.
+    ///   - [`DteError::StampMismatch`] - If the emitter, document type,
+    ///     folio, issue date, receiver or total of the stamp or its CAF
+    ///     differ from those of the document.
+    ///   - [`DteError::FolioOutOfRange`] - If the folio is not authorized
+    ///     by the CAF.
+    ///   - [`DteError::InvalidKey`] - If the CAF public key is invalid.
+    ///
+    /// # Notes
+    /// This is a consistency check, not a proof of authenticity. The
+    /// signature of the CAF by the SII (`FRMA`) is not verified, since the
+    /// public keys of the SII are not bundled, so anyone can make a
+    /// consistent stamp with a CAF and key of their own. Neither is the XML
+    /// signature of the whole document.
+    pub fn check_stamp(&self) -> Result<StampStatus, DteError> {
+        let stamp = self.stamp.as_ref().ok_or_else(|| DteError::MissingElement { element: "TED".to_string() })?;
+
+        let fields = [
+            ("RE", format_rut(&self.emitter.rut), format_rut(&stamp.emitter)),
+            ("TD", self.dte_type.code().to_string(), stamp.dte_type.code().to_string()),
+            ("F", self.folio.to_string(), stamp.folio.to_string()),
+            ("FE", self.issue_date.to_string(), stamp.issue_date.to_string()),
+            ("RR", format_rut(&self.receiver.rut), format_rut(&stamp.receiver)),
+            ("MNT", self.totals.total.to_string(), stamp.total.to_string()),
+            ("CAF/RE", format_rut(&self.emitter.rut), format_rut(&stamp.caf.emitter)),
+            ("CAF/TD", self.dte_type.code().to_string(), stamp.caf.dte_type.code().to_string()),
+        ];
+
+        if let Some((field, document, stamp)) = fields.into_iter().find(|(_, d, s)| d != s) {
+            return Err(DteError::StampMismatch { field: field.to_string(), document, stamp });
+        }
+
+        if !stamp.caf.contains(self.folio) {
+            return Err(DteError::FolioOutOfRange {
+                folio: self.folio,
+                from: stamp.caf.folio_from,
+                to: stamp.caf.folio_to,
+            });
+        }
+
+        if stamp.verify_signature()? {
+            Ok(StampStatus::Consistent)
+        } else {
+            Ok(StampStatus::Invalid)
+        }
+    }
+}
+
+
+/// Parses all the documents of a DTE XML, such as a single `DTE` or an
+/// `EnvioDTE` or `EnvioBOLETA` with several documents.
+///
+/// # Arguments
+/// * `xml` - A reference to a string slice containing the XML. For raw
+///   bytes, see [`crate::utils::decode_xml`].
+///
+/// # Returns
+/// * `Ok(Vec<Dte>)` - The documents, in order of appearance.
+/// * `Err(DteError)`:
+///   - [`DteError::InvalidXml`] - If the XML is malformed.
+///   - [`DteError::MissingElement`] - If a required element is missing.
+///   - [`DteError::InvalidValue`] - If a value cannot be parsed.
+///   - [`DteError::UnknownDteType`] - If a document type is unknown.
+///   - [`DteError::InvalidRut`] - If a RUT is malformed or its verifier is
+///     invalid.
+///
+/// # Behavior
+/// Namespaces are ignored. All RUTs, including those of the stamp and the
+/// CAF, are validated.
+pub fn parse_dtes(xml: &str) -> Result<Vec<Dte>, DteError> {
+    let document = Document::parse(xml).map_err(|e| DteError::InvalidXml { reason: e.to_string() })?;
+
+    document
+        .descendants()
+        .filter(|n| n.is_element() && DOCUMENT_ELEMENTS.contains(&n.tag_name().name()))
+        .map(Dte::parse)
+        .collect()
+}
+
+
+/// Parses the first document of a DTE XML. See [`parse_dtes`].
+///
+/// # Errors
+/// * [`DteError::MissingElement`] - If the XML has no document.
+/// * Any error of [`parse_dtes`].
+///
+/// # Examples
+/// ```
+/// use dte::document::parse_dte;
+///
+/// assert!(parse_dte("<DTE><Documento/></DTE>").is_err());
+/// assert!(parse_dte("<DTE>").is_err());
+/// ```
+pub fn parse_dte(xml: &str) -> Result<Dte, DteError> {
+    parse_dtes(xml)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| DteError::MissingElement { element: "Documento".to_string() })
+}
@@ -0,0 +1,140 @@
+use crate::errors::DteError;
+
+
+/// Type of electronic tax document (DTE), by SII code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DteType {
+    Factura,
+    FacturaExenta,
+    Boleta,
+    BoletaExenta,
+    LiquidacionFactura,
+    FacturaCompra,
+    GuiaDespacho,
+    NotaDebito,
+    NotaCredito,
+    FacturaExportacion,
+    NotaDebitoExportacion,
+    NotaCreditoExportacion,
+}
+
+
+impl DteType {
+    pub const ALL: &'static [Self] = &[
+        Self::Factura,
+        Self::FacturaExenta,
+        Self::Boleta,
+        Self::BoletaExenta,
+        Self::LiquidacionFactura,
+        Self::FacturaCompra,
+        Self::GuiaDespacho,
+        Self::NotaDebito,
+        Self::NotaCredito,
+        Self::FacturaExportacion,
+        Self::NotaDebitoExportacion,
+        Self::NotaCreditoExportacion,
+    ];
+
+    /// Returns the SII code of the document type, e.g. `33`.
+    pub fn code(&self) -> u16 {
+        match self {
+            DteType::Factura => 33,
+            DteType::FacturaExenta => 34,
+            DteType::Boleta => 39,
+            DteType::BoletaExenta => 41,
+            DteType::LiquidacionFactura => 43,
+            DteType::FacturaCompra => 46,
+            DteType::GuiaDespacho => 52,
+            DteType::NotaDebito => 56,
+            DteType::NotaCredito => 61,
+            DteType::FacturaExportacion => 110,
+            DteType::NotaDebitoExportacion => 111,
+            DteType::NotaCreditoExportacion => 112,
+        }
+    }
+
+    /// Returns the official name of the document type.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DteType::Factura => "Factura Electrónica",
+            DteType::FacturaExenta => "Factura No Afecta o Exenta Electrónica",
+            DteType::Boleta => "Boleta Electrónica",
+            DteType::BoletaExenta => "Boleta Exenta Electrónica",
+            DteType::LiquidacionFactura => "Liquidación Factura Electrónica",
+            DteType::FacturaCompra => "Factura de Compra Electrónica",
+            DteType::GuiaDespacho => "Guía de Despacho Electrónica",
+            DteType::NotaDebito => "Nota de Débito Electrónica",
+            DteType::NotaCredito => "Nota de Crédito Electrónica",
+            DteType::FacturaExportacion => "Factura de Exportación Electrónica",
+            DteType::NotaDebitoExportacion => "Nota de Débito de Exportación Electrónica",
+            DteType::NotaCreditoExportacion => "Nota de Crédito de Exportación Electrónica",
+        }
+    }
+
+    /// Returns string with enum codes separated by comma.
+    pub fn values() -> String {
+        Self::ALL
+            .iter()
+            .map(|v| format!("'{}'", v.code()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+
+impl TryFrom<&str> for DteType {
+    type Error = DteError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let code: u16 = s.trim().parse().unwrap_or_default();
+
+        Self::ALL
+            .iter()
+            .find(|t| t.code() == code)
+            .copied()
+            .ok_or_else(|| DteError::UnknownDteType {
+                expected: Self::values(),
+                actual: s.to_string(),
+            })
+    }
+}
+
+
+/// Outcome of checking the electronic stamp of a document offline. See
+/// [`crate::document::Dte::check_stamp`].
+///
+/// There is no authentic outcome: the signature of the CAF by the SII is
+/// not verified, so a consistent stamp may still come from a forged CAF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StampStatus {
+    /// The signature of the stamp is invalid, i.e. the stamp data was
+    /// altered or signed with a key other than that of its CAF.
+    Invalid,
+    /// The stamp matches the document and is signed with the key of its
+    /// CAF.
+    Consistent,
+}
+
+
+impl StampStatus {
+    pub const ALL: &'static [Self] = &[
+        Self::Invalid,
+        Self::Consistent,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StampStatus::Invalid => "invalid",
+            StampStatus::Consistent => "consistent",
+        }
+    }
+
+    /// Returns string with enum values separated by comma.
+    pub fn values() -> String {
+        Self::ALL
+            .iter()
+            .map(|v| format!("'{}'", v.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum DteError {
+    #[error("Invalid XML: {reason}.")]
+    InvalidXml { reason: String },
+
+    #[error("Missing element '{element}'.")]
+    MissingElement { element: String },
+
+    #[error("Invalid value of element '{element}': '{value}'.")]
+    InvalidValue { element: String, value: String },
+
+    #[error("Unknown DTE type. Expected one of: {expected}. Got: '{actual}'.")]
+    UnknownDteType { expected: String, actual: String },
+
+    #[error("Invalid RUT in element '{element}': '{rut}'.")]
+    InvalidRut { element: String, rut: String },
+
+    #[error("Invalid CAF public key: {reason}.")]
+    InvalidKey { reason: String },
+
+    #[error("Character '{character}' of the stamp cannot be encoded in ISO-8859-1.")]
+    UnencodableCharacter { character: char },
+
+    #[error("Stamp field '{field}' is '{stamp}', but the document has '{document}'.")]
+    StampMismatch { field: String, document: String, stamp: String },
+
+    #[error("Folio {folio} is out of the CAF range {from}-{to}.")]
+    FolioOutOfRange { folio: u64, from: u64, to: u64 },
+}
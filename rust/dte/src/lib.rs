@@ -0,0 +1,523 @@
+pub mod enums;
+pub mod errors;
+pub mod utils;
+pub mod stamp;
+pub mod document;
+
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use rust_decimal::Decimal;
+
+use crate::errors::DteError;
+use crate::utils::format_rut;
+
+
+create_exception!(
+    rs_dte, DteException, PyException,
+    "Base class for all exceptions raised when parsing or verifying DTE documents."
+);
+create_exception!(
+    rs_dte, InvalidXml, DteException,
+    "Raised when the given XML is malformed."
+);
+create_exception!(
+    rs_dte, MissingElement, DteException,
+    "Raised when a required element of the document is missing."
+);
+create_exception!(
+    rs_dte, InvalidElementValue, DteException,
+    "Raised when the value of an element of the document is invalid."
+);
+create_exception!(
+    rs_dte, UnknownDteType, DteException,
+    "Raised when the document type is unknown."
+);
+create_exception!(
+    rs_dte, InvalidDteRut, DteException,
+    "Raised when a RUT of the document is malformed or has an invalid verifier."
+);
+create_exception!(
+    rs_dte, InvalidCafKey, DteException,
+    "Raised when the public key of the CAF is invalid."
+);
+create_exception!(
+    rs_dte, UnencodableCharacter, DteException,
+    "Raised when the stamp has characters that cannot be encoded in ISO-8859-1."
+);
+create_exception!(
+    rs_dte, StampMismatch, DteException,
+    "Raised when the electronic stamp does not match the document."
+);
+create_exception!(
+    rs_dte, FolioOutOfRange, DteException,
+    "Raised when the folio of the document is not authorized by the CAF."
+);
+
+
+impl From<DteError> for PyErr {
+    fn from(err: DteError) -> PyErr {
+        match err {
+            DteError::InvalidXml { .. } => InvalidXml::new_err(err.to_string()),
+            DteError::MissingElement { .. } => MissingElement::new_err(err.to_string()),
+            DteError::InvalidValue { .. } => InvalidElementValue::new_err(err.to_string()),
+            DteError::UnknownDteType { .. } => UnknownDteType::new_err(err.to_string()),
+            DteError::InvalidRut { .. } => InvalidDteRut::new_err(err.to_string()),
+            DteError::InvalidKey { .. } => InvalidCafKey::new_err(err.to_string()),
+            DteError::UnencodableCharacter { .. } => UnencodableCharacter::new_err(err.to_string()),
+            DteError::StampMismatch { .. } => StampMismatch::new_err(err.to_string()),
+            DteError::FolioOutOfRange { .. } => FolioOutOfRange::new_err(err.to_string()),
+        }
+    }
+}
+
+
+/// XML input, either already decoded or as raw bytes.
+#[derive(FromPyObject)]
+enum XmlInput {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+impl XmlInput {
+    fn decode(self) -> Result<String, DteError> {
+        match self {
+            XmlInput::Text(text) => Ok(text),
+            XmlInput::Bytes(data) => utils::decode_xml(&data),
+        }
+    }
+}
+
+
+#[pyclass(frozen)]
+struct Emitter {
+    inner: document::Emitter,
+}
+
+#[pymethods]
+impl Emitter {
+    #[getter]
+    fn rut(&self) -> String {
+        format_rut(&self.inner.rut)
+    }
+
+    #[getter]
+    fn business_name(&self) -> String {
+        self.inner.business_name.clone()
+    }
+
+    #[getter]
+    fn activity(&self) -> Option<String> {
+        self.inner.activity.clone()
+    }
+
+    #[getter]
+    fn activity_codes(&self) -> Vec<u32> {
+        self.inner.activity_codes.clone()
+    }
+
+    #[getter]
+    fn address(&self) -> Option<String> {
+        self.inner.address.clone()
+    }
+
+    #[getter]
+    fn comuna(&self) -> Option<String> {
+        self.inner.comuna.clone()
+    }
+
+    #[getter]
+    fn city(&self) -> Option<String> {
+        self.inner.city.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Emitter(rut='{}', business_name='{}')", self.rut(), self.inner.business_name)
+    }
+}
+
+
+#[pyclass(frozen)]
+struct Receiver {
+    inner: document::Receiver,
+}
+
+#[pymethods]
+impl Receiver {
+    #[getter]
+    fn rut(&self) -> String {
+        format_rut(&self.inner.rut)
+    }
+
+    #[getter]
+    fn business_name(&self) -> Option<String> {
+        self.inner.business_name.clone()
+    }
+
+    #[getter]
+    fn activity(&self) -> Option<String> {
+        self.inner.activity.clone()
+    }
+
+    #[getter]
+    fn address(&self) -> Option<String> {
+        self.inner.address.clone()
+    }
+
+    #[getter]
+    fn comuna(&self) -> Option<String> {
+        self.inner.comuna.clone()
+    }
+
+    #[getter]
+    fn city(&self) -> Option<String> {
+        self.inner.city.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Receiver(rut='{}', business_name='{}')",
+            self.rut(),
+            self.inner.business_name.as_deref().unwrap_or("None"),
+        )
+    }
+}
+
+
+#[pyclass(frozen)]
+struct Totals {
+    inner: document::Totals,
+}
+
+#[pymethods]
+impl Totals {
+    #[getter]
+    fn net(&self) -> Option<i64> {
+        self.inner.net
+    }
+
+    #[getter]
+    fn exempt(&self) -> Option<i64> {
+        self.inner.exempt
+    }
+
+    #[getter]
+    fn vat_rate(&self) -> Option<Decimal> {
+        self.inner.vat_rate
+    }
+
+    #[getter]
+    fn vat(&self) -> Option<i64> {
+        self.inner.vat
+    }
+
+    #[getter]
+    fn total(&self) -> i64 {
+        self.inner.total
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Totals(total={})", self.inner.total)
+    }
+}
+
+
+#[pyclass(frozen)]
+struct Item {
+    inner: document::Item,
+}
+
+#[pymethods]
+impl Item {
+    #[getter]
+    fn line(&self) -> u32 {
+        self.inner.line
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name.clone()
+    }
+
+    #[getter]
+    fn description(&self) -> Option<String> {
+        self.inner.description.clone()
+    }
+
+    #[getter]
+    fn quantity(&self) -> Option<Decimal> {
+        self.inner.quantity
+    }
+
+    #[getter]
+    fn unit(&self) -> Option<String> {
+        self.inner.unit.clone()
+    }
+
+    #[getter]
+    fn price(&self) -> Option<Decimal> {
+        self.inner.price
+    }
+
+    #[getter]
+    fn discount(&self) -> Option<i64> {
+        self.inner.discount
+    }
+
+    #[getter]
+    fn amount(&self) -> i64 {
+        self.inner.amount
+    }
+
+    #[getter]
+    fn exempt(&self) -> bool {
+        self.inner.exempt
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Item(line={}, name='{}', amount={})", self.inner.line, self.inner.name, self.inner.amount)
+    }
+}
+
+
+#[pyclass(frozen)]
+struct Caf {
+    inner: stamp::Caf,
+}
+
+#[pymethods]
+impl Caf {
+    #[getter]
+    fn emitter(&self) -> String {
+        format_rut(&self.inner.emitter)
+    }
+
+    #[getter]
+    fn business_name(&self) -> String {
+        self.inner.business_name.clone()
+    }
+
+    #[getter]
+    fn dte_type(&self) -> u16 {
+        self.inner.dte_type.code()
+    }
+
+    #[getter]
+    fn folio_from(&self) -> u64 {
+        self.inner.folio_from
+    }
+
+    #[getter]
+    fn folio_to(&self) -> u64 {
+        self.inner.folio_to
+    }
+
+    #[getter]
+    fn authorized(&self) -> NaiveDate {
+        self.inner.authorized
+    }
+
+    #[getter]
+    fn key_id(&self) -> u32 {
+        self.inner.key_id
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Caf(emitter='{}', dte_type={}, folio_from={}, folio_to={})",
+            self.emitter(),
+            self.dte_type(),
+            self.inner.folio_from,
+            self.inner.folio_to,
+        )
+    }
+}
+
+
+#[pyclass(frozen)]
+struct Stamp {
+    inner: stamp::Stamp,
+}
+
+#[pymethods]
+impl Stamp {
+    #[getter]
+    fn emitter(&self) -> String {
+        format_rut(&self.inner.emitter)
+    }
+
+    #[getter]
+    fn dte_type(&self) -> u16 {
+        self.inner.dte_type.code()
+    }
+
+    #[getter]
+    fn folio(&self) -> u64 {
+        self.inner.folio
+    }
+
+    #[getter]
+    fn issue_date(&self) -> NaiveDate {
+        self.inner.issue_date
+    }
+
+    #[getter]
+    fn receiver(&self) -> String {
+        format_rut(&self.inner.receiver)
+    }
+
+    #[getter]
+    fn receiver_name(&self) -> String {
+        self.inner.receiver_name.clone()
+    }
+
+    #[getter]
+    fn total(&self) -> i64 {
+        self.inner.total
+    }
+
+    #[getter]
+    fn first_item(&self) -> String {
+        self.inner.first_item.clone()
+    }
+
+    #[getter]
+    fn caf(&self) -> Caf {
+        Caf { inner: self.inner.caf.clone() }
+    }
+
+    #[getter]
+    fn timestamp(&self) -> String {
+        self.inner.timestamp.clone()
+    }
+
+    fn verify_signature(&self) -> PyResult<bool> {
+        Ok(self.inner.verify_signature()?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Stamp(emitter='{}', dte_type={}, folio={}, timestamp='{}')",
+            self.emitter(),
+            self.dte_type(),
+            self.inner.folio,
+            self.inner.timestamp,
+        )
+    }
+}
+
+
+#[pyclass(frozen)]
+struct Dte {
+    inner: document::Dte,
+}
+
+#[pymethods]
+impl Dte {
+    /// Create a new `Dte` instance.
+    #[new]
+    fn new(xml: XmlInput) -> PyResult<Self> {
+        let inner = document::parse_dte(&xml.decode()?)?;
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn dte_type(&self) -> u16 {
+        self.inner.dte_type.code()
+    }
+
+    #[getter]
+    fn dte_type_name(&self) -> &'static str {
+        self.inner.dte_type.as_str()
+    }
+
+    #[getter]
+    fn folio(&self) -> u64 {
+        self.inner.folio
+    }
+
+    #[getter]
+    fn issue_date(&self) -> NaiveDate {
+        self.inner.issue_date
+    }
+
+    #[getter]
+    fn emitter(&self) -> Emitter {
+        Emitter { inner: self.inner.emitter.clone() }
+    }
+
+    #[getter]
+    fn receiver(&self) -> Receiver {
+        Receiver { inner: self.inner.receiver.clone() }
+    }
+
+    #[getter]
+    fn totals(&self) -> Totals {
+        Totals { inner: self.inner.totals.clone() }
+    }
+
+    #[getter]
+    fn items(&self) -> Vec<Item> {
+        self.inner.items
+            .iter()
+            .map(|i| Item { inner: i.clone() })
+            .collect()
+    }
+
+    #[getter]
+    fn stamp(&self) -> Option<Stamp> {
+        self.inner.stamp
+            .as_ref()
+            .map(|s| Stamp { inner: s.clone() })
+    }
+
+    fn check_stamp(&self) -> PyResult<&'static str> {
+        Ok(self.inner.check_stamp()?.as_str())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Dte(dte_type={}, folio={}, emitter='{}', total={})",
+            self.dte_type(),
+            self.inner.folio,
+            format_rut(&self.inner.emitter.rut),
+            self.inner.totals.total,
+        )
+    }
+}
+
+
+#[pyfunction]
+fn parse_dtes(xml: XmlInput) -> PyResult<Vec<Dte>> {
+    match document::parse_dtes(&xml.decode()?) {
+        Ok(result) => Ok(result.into_iter().map(|inner| Dte { inner }).collect()),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pymodule]
+pub fn rs_dte(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse_dtes, m)?)?;
+    m.add_class::<Dte>()?;
+    m.add_class::<Emitter>()?;
+    m.add_class::<Receiver>()?;
+    m.add_class::<Totals>()?;
+    m.add_class::<Item>()?;
+    m.add_class::<Stamp>()?;
+    m.add_class::<Caf>()?;
+
+    m.add("DteException", m.py().get_type::<DteException>())?;
+    m.add("InvalidXml", m.py().get_type::<InvalidXml>())?;
+    m.add("MissingElement", m.py().get_type::<MissingElement>())?;
+    m.add("InvalidElementValue", m.py().get_type::<InvalidElementValue>())?;
+    m.add("UnknownDteType", m.py().get_type::<UnknownDteType>())?;
+    m.add("InvalidDteRut", m.py().get_type::<InvalidDteRut>())?;
+    m.add("InvalidCafKey", m.py().get_type::<InvalidCafKey>())?;
+    m.add("UnencodableCharacter", m.py().get_type::<UnencodableCharacter>())?;
+    m.add("StampMismatch", m.py().get_type::<StampMismatch>())?;
+    m.add("FolioOutOfRange", m.py().get_type::<FolioOutOfRange>())?;
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::NaiveDate;
+use roxmltree::Node;
+use rsa::BigUint;
+use rsa::Pkcs1v15Sign;
+use rsa::RsaPublicKey;
+use sha1::Digest;
+use sha1::Sha1;
+use verify::utils::Rut;
+
+use crate::enums::DteType;
+use crate::errors::DteError;
+use crate::utils::flatten;
+use crate::utils::required;
+use crate::utils::required_rut;
+use crate::utils::required_date;
+use crate::utils::required_text;
+use crate::utils::required_value;
+use crate::utils::encode_latin1;
+
+
+/// Decodes a base64 element, ignoring whitespace.
+fn required_base64(node: Node, name: &str) -> Result<Vec<u8>, DteError> {
+    let value = required_text(node, name)?;
+    let compact: String = value.split_whitespace().collect();
+
+    STANDARD.decode(compact).map_err(|_| DteError::InvalidValue {
+        element: name.to_string(),
+        value,
+    })
+}
+
+
+/// Represents a folio authorization code (CAF), issued by the SII to
+/// authorize a range of folios of a document type.
+#[derive(Debug, Clone)]
+pub struct Caf {
+    /// The RUT of the authorized emitter.
+    pub emitter: Rut,
+    /// The business name of the authorized emitter.
+    pub business_name: String,
+    /// The authorized document type.
+    pub dte_type: DteType,
+    /// The first authorized folio.
+    pub folio_from: u64,
+    /// The last authorized folio (inclusive).
+    pub folio_to: u64,
+    /// The authorization date.
+    pub authorized: NaiveDate,
+    /// The modulus of the RSA public key, in big-endian bytes.
+    pub modulus: Vec<u8>,
+    /// The exponent of the RSA public key, in big-endian bytes.
+    pub exponent: Vec<u8>,
+    /// The identifier of the SII key that signed the CAF.
+    pub key_id: u32,
+}
+
+/// Implements methods for the [`Caf`] struct.
+impl Caf {
+    /// Parses a `CAF` element.
+    pub(crate) fn parse(node: Node) -> Result<Self, DteError> {
+        let da = required(node, "DA")?;
+        let range = required(da, "RNG")?;
+        let key = required(da, "RSAPK")?;
+
+        Ok(Self {
+            emitter: required_rut(da, "RE")?,
+            business_name: required_text(da, "RS")?,
+            dte_type: DteType::try_from(required_text(da, "TD")?.as_str())?,
+            folio_from: required_value(range, "D")?,
+            folio_to: required_value(range, "H")?,
+            authorized: required_date(da, "FA")?,
+            modulus: required_base64(key, "M")?,
+            exponent: required_base64(key, "E")?,
+            key_id: required_value(da, "IDK")?,
+        })
+    }
+
+    /// Returns whether a folio is in the authorized range.
+    pub fn contains(&self, folio: u64) -> bool {
+        (self.folio_from..=self.folio_to).contains(&folio)
+    }
+
+    /// Returns the RSA public key of the CAF.
+    pub fn public_key(&self) -> Result<RsaPublicKey, DteError> {
+        RsaPublicKey::new(BigUint::from_bytes_be(&self.modulus), BigUint::from_bytes_be(&self.exponent))
+            .map_err(|e| DteError::InvalidKey { reason: e.to_string() })
+    }
+}
+
+
+/// Represents the electronic stamp (TED, Timbre Electrónico DTE) of a
+/// document, printed as a PDF417 barcode on its representation.
+#[derive(Debug, Clone)]
+pub struct Stamp {
+    /// The RUT of the emitter.
+    pub emitter: Rut,
+    /// The document type.
+    pub dte_type: DteType,
+    /// The folio of the document.
+    pub folio: u64,
+    /// The issue date of the document.
+    pub issue_date: NaiveDate,
+    /// The RUT of the receiver.
+    pub receiver: Rut,
+    /// The business name of the receiver, up to 40 characters.
+    pub receiver_name: String,
+    /// The total amount of the document.
+    pub total: i64,
+    /// The name of the first item, up to 40 characters.
+    pub first_item: String,
+    /// The CAF authorizing the folio.
+    pub caf: Caf,
+    /// The stamp timestamp, e.g. `"2025-10-13T09:33:20"`.
+    pub timestamp: String,
+    /// The signature of the stamp data, made with the private key of the
+    /// CAF.
+    pub signature: Vec<u8>,
+    /// The flattened stamp data (`DD` element) in ISO-8859-1, as signed.
+    pub signed_data: Vec<u8>,
+}
+
+/// Implements methods for the [`Stamp`] struct.
+impl Stamp {
+    /// Parses a `TED` element.
+    pub(crate) fn parse(node: Node) -> Result<Self, DteError> {
+        let dd = required(node, "DD")?;
+
+        Ok(Self {
+            emitter: required_rut(dd, "RE")?,
+            dte_type: DteType::try_from(required_text(dd, "TD")?.as_str())?,
+            folio: required_value(dd, "F")?,
+            issue_date: required_date(dd, "FE")?,
+            receiver: required_rut(dd, "RR")?,
+            receiver_name: required_text(dd, "RSR")?,
+            total: required_value(dd, "MNT")?,
+            first_item: required_text(dd, "IT1")?,
+            caf: Caf::parse(required(dd, "CAF")?)?,
+            timestamp: required_text(dd, "TSTED")?,
+            signature: required_base64(node, "FRMT")?,
+            signed_data: encode_latin1(&flatten(dd))?,
+        })
+    }
+
+    /// Verifies the signature of the stamp against the public key of the
+    /// CAF, with RSA and SHA-1 (`SHA1withRSA`).
+    ///
+    /// # Returns
+    /// * `Ok(true)` - If the signature is valid.
+    /// * `Ok(false)` - If the signature is invalid, i.e. the stamp data was
+    ///   altered or signed with another key.
+    /// * `Err(DteError::InvalidKey)` - If the CAF public key is invalid.
+    ///
+    /// # Notes
+    /// The signature of the CAF itself (`FRMA`), made by the SII, is not
+    /// verified.
+    pub fn verify_signature(&self) -> Result<bool, DteError> {
+        let key = self.caf.public_key()?;
+        let hash = Sha1::digest(&self.signed_data);
+
+        Ok(key.verify(Pkcs1v15Sign::new::<Sha1>(), &hash, &self.signature).is_ok())
+    }
+}
@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use roxmltree::Node;
+use verify::utils::Rut;
+use verify::utils::validate_rut;
+
+use crate::errors::DteError;
+
+
+/// Finds the first child element of a node with the given local name,
+/// ignoring namespaces.
+pub(crate) fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+
+/// Finds all the child elements of a node with the given local name.
+pub(crate) fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children().filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+
+/// Finds a required child element.
+pub(crate) fn required<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Result<Node<'a, 'input>, DteError> {
+    child(node, name).ok_or_else(|| DteError::MissingElement { element: name.to_string() })
+}
+
+
+/// Gets the trimmed text of an optional child element. Empty elements are
+/// treated as missing.
+pub(crate) fn optional_text(node: Node, name: &str) -> Option<String> {
+    child(node, name)
+        .and_then(|n| n.text())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+}
+
+
+/// Gets the trimmed text of a required child element.
+pub(crate) fn required_text(node: Node, name: &str) -> Result<String, DteError> {
+    optional_text(node, name).ok_or_else(|| DteError::MissingElement { element: name.to_string() })
+}
+
+
+/// Parses a value of an element.
+pub(crate) fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, DteError> {
+    value.parse().map_err(|_| DteError::InvalidValue {
+        element: name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+
+/// Parses the text of an optional child element.
+pub(crate) fn optional_value<T: FromStr>(node: Node, name: &str) -> Result<Option<T>, DteError> {
+    optional_text(node, name)
+        .map(|t| parse_value(name, &t))
+        .transpose()
+}
+
+
+/// Parses the text of a required child element.
+pub(crate) fn required_value<T: FromStr>(node: Node, name: &str) -> Result<T, DteError> {
+    parse_value(name, &required_text(node, name)?)
+}
+
+
+/// Parses a date of an element, in `YYYY-MM-DD` format.
+pub(crate) fn required_date(node: Node, name: &str) -> Result<NaiveDate, DteError> {
+    let value = required_text(node, name)?;
+
+    NaiveDate::parse_from_str(&value, "%Y-%m-%d").map_err(|_| DteError::InvalidValue {
+        element: name.to_string(),
+        value,
+    })
+}
+
+
+/// Parses and validates a RUT of an element, in `12345678-5` format.
+pub(crate) fn required_rut(node: Node, name: &str) -> Result<Rut, DteError> {
+    let value = required_text(node, name)?;
+    let invalid = || DteError::InvalidRut { element: name.to_string(), rut: value.clone() };
+
+    let (digits, verifier) = value.split_once('-').ok_or_else(invalid)?;
+    let correlative: u32 = digits.replace('.', "").parse().map_err(|_| invalid())?;
+
+    if !validate_rut(correlative, verifier).unwrap_or(false) {
+        return Err(invalid());
+    }
+
+    Ok(Rut { correlative, verifier: verifier.trim().to_ascii_uppercase().chars().next().unwrap() })
+}
+
+
+/// Formats a RUT as `12345678-5`, as written in DTE documents.
+pub fn format_rut(rut: &Rut) -> String {
+    format!("{}-{}", rut.correlative, rut.verifier)
+}
+
+
+/// Decodes the bytes of a DTE XML document.
+///
+/// # Arguments
+/// * `data` - The bytes of the document.
+///
+/// # Returns
+/// * `Ok(String)` - The decoded document.
+/// * `Err(DteError::InvalidXml)` - If the document is not valid UTF-8.
+///
+/// # Behavior
+/// DTE documents are usually encoded in ISO-8859-1, as required by the
+/// SII. Documents declaring that encoding in the XML declaration are
+/// decoded as such, and any other as UTF-8.
+///
+/// # Examples
+/// ```
+/// use dte::utils::decode_xml;
+///
+/// let data = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><A>Ca\xF1a</A>";
+/// assert!(decode_xml(data).unwrap().ends_with("<A>Caña</A>"));
+/// assert!(decode_xml(b"<A>Ca\xF1a</A>").is_err());
+/// ```
+pub fn decode_xml(data: &[u8]) -> Result<String, DteError> {
+    let declaration = data
+        .strip_prefix(b"<?xml")
+        .and_then(|d| d.split(|b| *b == b'>').next())
+        .map(|d| String::from_utf8_lossy(d).to_ascii_uppercase())
+        .unwrap_or_default();
+
+    if declaration.contains("ISO-8859-1") || declaration.contains("LATIN1") {
+        return Ok(data.iter().map(|b| *b as char).collect());
+    }
+
+    String::from_utf8(data.to_vec()).map_err(|e| DteError::InvalidXml { reason: e.to_string() })
+}
+
+
+/// Escapes the special characters of a text, as required by the SII to
+/// flatten the stamp.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+
+/// Flattens an element as signed by the SII: without whitespace between
+/// elements, without namespaces, and escaping special characters.
+pub(crate) fn flatten(node: Node) -> String {
+    let mut output = String::new();
+    flatten_into(node, &mut output);
+    output
+}
+
+
+fn flatten_into(node: Node, output: &mut String) {
+    let name = node.tag_name().name();
+    output.push('<');
+    output.push_str(name);
+
+    for attribute in node.attributes() {
+        output.push_str(&format!(" {}=\"{}\"", attribute.name(), escape(attribute.value())));
+    }
+
+    output.push('>');
+
+    for child in node.children() {
+        if child.is_element() {
+            flatten_into(child, output);
+        } else if let Some(text) = child.text().filter(|t| !t.trim().is_empty()) {
+            output.push_str(&escape(text));
+        }
+    }
+
+    output.push_str("</");
+    output.push_str(name);
+    output.push('>');
+}
+
+
+/// Encodes a text in ISO-8859-1.
+pub(crate) fn encode_latin1(text: &str) -> Result<Vec<u8>, DteError> {
+    text.chars()
+        .map(|c| u8::try_from(c).map_err(|_| DteError::UnencodableCharacter { character: c }))
+        .collect()
+}
@@ -1,2 +1,3 @@
 pub const BASE_URL: &str = "https://api.mercadopublico.cl/servicios/v1/publico";
-pub const USER_AGENT: &str = concat!("py-cl-forge-market/", env!("CARGO_PKG_VERSION"));
\ No newline at end of file
+pub const USER_AGENT: &str = concat!("py-cl-forge-market/", env!("CARGO_PKG_VERSION"));
+pub const ENV_NAME: &str = "MARKET";
\ No newline at end of file
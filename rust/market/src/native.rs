@@ -1,22 +1,62 @@
 use crate::constants;
 
+use base::native::AsyncBaseClient;
 use base::native::BaseClient;
+use base::native::ClientOptions;
 use base::errors::ClientError;
+use base::enums::CacheMode;
 use base::enums::ResponseFormat;
+use base::settings::ClientSettings;
 
 
+/// Client of the Mercado Público API.
+///
+/// # Behavior
+/// The base URL, timeout and user agent not set in the settings of the
+/// options are read from the `CLFORGE_MARKET_BASE_URL`,
+/// `CLFORGE_MARKET_TIMEOUT` and `CLFORGE_MARKET_USER_AGENT` environment
+/// variables, and default to the ones of the Mercado Público API otherwise.
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use base::enums::CacheMode;
+/// use base::enums::ResponseFormat;
+/// use base::errors::ClientError;
+/// use base::native::ClientOptions;
+/// use base::transport::MockTransport;
+/// use base::transport::Response;
+/// use market::native::MarketClient;
+///
+/// let mock = Arc::new(MockTransport::new());
+/// mock.push("/licitaciones.json", Ok(Response::new(200, r#"{"Cantidad": 0, "Listado": []}"#)));
+/// mock.push("/ordenesdecompra.json", Ok(Response::new(500, "Error")));
+///
+/// let options = ClientOptions { transport: Some(mock.clone()), ..ClientOptions::default() };
+/// let client = MarketClient::new("ticket", options).unwrap();
+///
+/// let params = [("estado".to_string(), "activas".to_string())];
+/// let body = client.get("/licitaciones", ResponseFormat::Json, &params, CacheMode::Use).unwrap();
+/// assert!(body.contains("Listado"));
+/// assert_eq!(mock.requests()[0].query[1], ("estado".to_string(), "activas".to_string()));
+///
+/// let error = client.get("/ordenesdecompra", ResponseFormat::Json, &[], CacheMode::Use);
+/// assert!(matches!(error, Err(ClientError::BadStatus { status: 500, .. })));
+/// ```
 pub struct MarketClient {
     pub base: BaseClient,
 }
 
 impl MarketClient {
     //noinspection DuplicatedCode
-    pub fn new(api_key: &str) -> Result<Self, ClientError> {
+    pub fn new(api_key: &str, mut options: ClientOptions) -> Result<Self, ClientError> {
+        options.settings = options.settings.or(ClientSettings::from_env(constants::ENV_NAME));
+
         let base = BaseClient::new(
             api_key,
             constants::BASE_URL,
             constants::USER_AGENT,
-        )?;
+        )?.with_options(options)?;
 
         Ok(Self { base })
     }
@@ -25,23 +65,97 @@ impl MarketClient {
             &self,
             path: &str,
             fmt: ResponseFormat,
-            params: &[(String, String)]
+            params: &[(String, String)],
+            mode: CacheMode
     ) -> Result<String, ClientError> {
-        // In "Mercado Público", the response format
-        // is part of the path, not a query parameter.
-        // e.g., "<BASE_URL>/licitaciones.json
-        let path = format!("{}.{}", path, fmt.as_str());
+        let (path, query) = request(&self.base.api_key, path, fmt, params);
+
+        let response = self.base.get(path.as_str(), &query, mode)?;
 
-        let mut query = vec![
-            ("ticket", self.base.api_key.as_str())
-        ];
+        Ok(response)
+    }
+}
 
-        for (k, v) in params {
-            query.push((k, v));
-        }
 
-        let response = self.base.get(path.as_str(), &query)?;
+/// Async client of the Mercado Público API.
+///
+/// # Behavior
+/// As [`MarketClient`], with the transport of
+/// [`ClientOptions::async_transport`].
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use base::enums::CacheMode;
+/// use base::enums::ResponseFormat;
+/// use base::native::ClientOptions;
+/// use base::runtime::runtime;
+/// use base::transport::MockTransport;
+/// use base::transport::Response;
+/// use market::native::AsyncMarketClient;
+///
+/// let mock = Arc::new(MockTransport::new());
+/// mock.push("/ordenesdecompra.json", Ok(Response::new(200, r#"{"Cantidad": 0, "Listado": []}"#)));
+///
+/// let options = ClientOptions { async_transport: Some(mock.clone()), ..ClientOptions::default() };
+/// let client = AsyncMarketClient::new("ticket", options).unwrap();
+///
+/// let params = [("fecha".to_string(), "17102025".to_string())];
+/// let body = runtime().block_on(client.get("/ordenesdecompra", ResponseFormat::Json, &params, CacheMode::Use));
+/// assert!(body.unwrap().contains("Listado"));
+/// assert_eq!(mock.requests()[0].query[1], ("fecha".to_string(), "17102025".to_string()));
+/// ```
+pub struct AsyncMarketClient {
+    pub base: AsyncBaseClient,
+}
 
-        Ok(response)
+impl AsyncMarketClient {
+    //noinspection DuplicatedCode
+    pub fn new(api_key: &str, mut options: ClientOptions) -> Result<Self, ClientError> {
+        options.settings = options.settings.or(ClientSettings::from_env(constants::ENV_NAME));
+
+        let base = AsyncBaseClient::new(
+            api_key,
+            constants::BASE_URL,
+            constants::USER_AGENT,
+        )?.with_options(options)?;
+
+        Ok(Self { base })
     }
-}
\ No newline at end of file
+
+    pub async fn get(
+            &self,
+            path: &str,
+            fmt: ResponseFormat,
+            params: &[(String, String)],
+            mode: CacheMode
+    ) -> Result<String, ClientError> {
+        let (path, query) = request(&self.base.base.api_key, path, fmt, params);
+
+        self.base.get(path.as_str(), &query, mode).await
+    }
+}
+
+
+/// Builds the path and the query of a request.
+fn request<'a>(
+    ticket: &'a str,
+    path: &str,
+    fmt: ResponseFormat,
+    params: &'a [(String, String)]
+) -> (String, Vec<(&'a str, &'a str)>) {
+    // In "Mercado Público", the response format
+    // is part of the path, not a query parameter.
+    // e.g., "<BASE_URL>/licitaciones.json
+    let path = format!("{}.{}", path, fmt.as_str());
+
+    let mut query = vec![
+        ("ticket", ticket)
+    ];
+
+    for (k, v) in params {
+        query.push((k, v));
+    }
+
+    (path, query)
+}
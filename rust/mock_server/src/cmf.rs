@@ -0,0 +1,203 @@
+use chrono::NaiveDate;
+use chrono::Utc;
+use serde_json::json;
+
+use crate::MockResponse;
+use crate::fixtures::Fixtures;
+use crate::fixtures::Record;
+use crate::param;
+use crate::simulated_status;
+use crate::xml;
+
+
+/// Gets the names of the list and of the items of an indicator in the
+/// responses, e.g. `("UFs", "UF")`.
+fn root_names(indicator: &str) -> Option<(&'static str, &'static str)> {
+    match indicator {
+        "uf" => Some(("UFs", "UF")),
+        "dolar" => Some(("Dolares", "Dolar")),
+        "euro" => Some(("Euros", "Euro")),
+        "utm" => Some(("UTMs", "UTM")),
+        "ipc" => Some(("IPCs", "IPC")),
+        _ => None,
+    }
+}
+
+
+/// Dates requested by a path of an indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    /// The last value published up to today.
+    Current,
+    /// The values between two dates, both included.
+    Range(NaiveDate, NaiveDate),
+}
+
+
+fn year(segment: &str) -> Option<i32> {
+    segment.parse().ok().filter(|y| (1900..=9999).contains(y))
+}
+
+
+fn month(segment: &str) -> Option<u32> {
+    segment.parse().ok().filter(|m| (1..=12).contains(m))
+}
+
+
+/// Gets the first and last days of a year, a month or a day.
+fn bounds(parts: &[&str]) -> Option<(NaiveDate, NaiveDate)> {
+    match parts {
+        [y] => {
+            let y = year(y)?;
+            Some((NaiveDate::from_ymd_opt(y, 1, 1)?, NaiveDate::from_ymd_opt(y, 12, 31)?))
+        }
+        [y, m] => {
+            let first = NaiveDate::from_ymd_opt(year(y)?, month(m)?, 1)?;
+            let last = first.checked_add_months(chrono::Months::new(1))?.pred_opt()?;
+            Some((first, last))
+        }
+        [y, m, "dias", d] => {
+            let day = NaiveDate::from_ymd_opt(year(y)?, month(m)?, d.parse().ok()?)?;
+            Some((day, day))
+        }
+        _ => None,
+    }
+}
+
+
+/// Parses the segments of a path after the indicator into the dates it
+/// requests, following the routes of the CMF API.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use mock_server::cmf::Period;
+/// use mock_server::cmf::parse_period;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// assert_eq!(parse_period(&[]), Some(Period::Current));
+/// assert_eq!(parse_period(&["2025", "02"]), Some(Period::Range(date(2025, 2, 1), date(2025, 2, 28))));
+/// assert_eq!(
+///     parse_period(&["periodo", "2024", "11", "2025", "01"]),
+///     Some(Period::Range(date(2024, 11, 1), date(2025, 1, 31))),
+/// );
+/// assert_eq!(
+///     parse_period(&["periodo", "2024", "11", "dias_i", "15", "2024", "12", "dias_f", "02"]),
+///     Some(Period::Range(date(2024, 11, 15), date(2024, 12, 2))),
+/// );
+/// assert_eq!(parse_period(&["posteriores", "2025", "10"]), Some(Period::Range(date(2025, 11, 1), NaiveDate::MAX)));
+/// assert_eq!(parse_period(&["2025", "13"]), None);
+/// ```
+pub fn parse_period(segments: &[&str]) -> Option<Period> {
+    let range = |first: NaiveDate, last: NaiveDate| (first <= last).then_some(Period::Range(first, last));
+
+    match segments {
+        [] => Some(Period::Current),
+        ["anteriores", parts @ ..] => range(NaiveDate::MIN, bounds(parts)?.0.pred_opt()?),
+        ["posteriores", parts @ ..] => range(bounds(parts)?.1.succ_opt()?, NaiveDate::MAX),
+        ["periodo", y1, y2] => range(bounds(&[y1])?.0, bounds(&[y2])?.1),
+        ["periodo", y1, m1, y2, m2] => range(bounds(&[y1, m1])?.0, bounds(&[y2, m2])?.1),
+        ["periodo", y1, m1, "dias_i", d1, y2, m2, "dias_f", d2] => {
+            range(bounds(&[y1, m1, "dias", d1])?.0, bounds(&[y2, m2, "dias", d2])?.1)
+        }
+        parts => bounds(parts).map(|(first, last)| Period::Range(first, last)),
+    }
+}
+
+
+/// Selects the records of a period.
+fn select(records: &[Record], period: Period) -> Vec<&Record> {
+    match period {
+        Period::Current => {
+            let today = Utc::now().date_naive();
+            records
+                .iter()
+                .rev()
+                .find(|r| r.date <= today)
+                .or(records.last())
+                .into_iter()
+                .collect()
+        }
+        Period::Range(first, last) => {
+            records.iter().filter(|r| (first..=last).contains(&r.date)).collect()
+        }
+    }
+}
+
+
+/// Builds an error response, as the CMF API does.
+fn error(status: u16, code: u16, message: &str, xml_format: bool) -> MockResponse {
+    if xml_format {
+        let mut body = String::from(xml::DECLARATION);
+        body.push_str("<IndicadoresFinancieros>");
+        xml::write_text(&mut body, "CodigoHTTP", &status.to_string());
+        xml::write_text(&mut body, "Mensaje", message);
+        xml::write_text(&mut body, "CodigoError", &code.to_string());
+        body.push_str("</IndicadoresFinancieros>");
+        MockResponse::xml(status, body)
+    } else {
+        let body = json!({ "CodigoHTTP": status, "Mensaje": message, "CodigoError": code });
+        MockResponse::json(status, body.to_string())
+    }
+}
+
+
+/// Answers a request to the CMF API.
+///
+/// # Arguments
+/// * `fixtures` - The data served.
+/// * `path` - The path of the request, after [`crate::CMF_PREFIX`], e.g.
+///   `"/uf/2025"`.
+/// * `query` - The query parameters of the request.
+pub fn respond(fixtures: &Fixtures, path: &str, query: &[(String, String)]) -> MockResponse {
+    let xml_format = match param(query, "formato").map(str::to_lowercase).as_deref() {
+        None | Some("json") => false,
+        Some("xml") => true,
+        Some(_) => return error(400, 82, "El formato solicitado no es válido. Use 'json' o 'xml'.", false),
+    };
+
+    let key = param(query, "apikey").unwrap_or_default().trim();
+    if key.is_empty() {
+        return error(401, 89, "Debe proporcionar una API key válida.", xml_format);
+    }
+    if let Some(status) = simulated_status(key) {
+        return error(status, 99, &format!("Error simulado con estado {}.", status), xml_format);
+    }
+
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let Some((indicator, rest)) = segments.split_first() else {
+        return error(404, 81, "El recurso solicitado no existe.", xml_format);
+    };
+
+    let names = root_names(&indicator.to_lowercase());
+    let records = fixtures.series(&indicator.to_lowercase());
+    let (Some((list, item)), Some(records), Some(period)) = (names, records, parse_period(rest)) else {
+        return error(404, 81, "El recurso solicitado no existe.", xml_format);
+    };
+
+    let selected = select(records, period);
+    if selected.is_empty() {
+        return error(404, 80, "No se encontraron datos para el período solicitado.", xml_format);
+    }
+
+    if xml_format {
+        let mut body = String::from(xml::DECLARATION);
+        body.push_str(&format!("<IndicadoresFinancieros><{}>", list));
+        for record in selected {
+            body.push_str(&format!("<{}>", item));
+            xml::write_text(&mut body, "Valor", &record.value);
+            xml::write_text(&mut body, "Fecha", &record.date.to_string());
+            body.push_str(&format!("</{}>", item));
+        }
+        body.push_str(&format!("</{}></IndicadoresFinancieros>", list));
+        MockResponse::xml(200, body)
+    } else {
+        let values: Vec<_> = selected
+            .iter()
+            .map(|r| json!({ "Valor": r.value, "Fecha": r.date.to_string() }))
+            .collect();
+        MockResponse::json(200, json!({ list: values }).to_string())
+    }
+}
+
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum FixtureError {
+    #[error("Cannot read fixture '{path}': {reason}")]
+    Unreadable { path: String, reason: String },
+
+    #[error("Invalid fixture '{file}', line {line}: {reason}.")]
+    Invalid { file: String, line: usize, reason: String },
+}
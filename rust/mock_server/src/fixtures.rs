@@ -0,0 +1,153 @@
+use std::fs;
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde_json::Value;
+
+use crate::errors::FixtureError;
+
+
+/// Indicators of the CMF API with a series file, named as their path.
+pub const INDICATORS: [&str; 5] = ["uf", "dolar", "euro", "utm", "ipc"];
+
+
+/// Fixture files embedded in the binary, by their path in the fixtures
+/// directory.
+const EMBEDDED: [(&str, &str); 7] = [
+    ("cmf/uf.tsv", include_str!("../fixtures/cmf/uf.tsv")),
+    ("cmf/dolar.tsv", include_str!("../fixtures/cmf/dolar.tsv")),
+    ("cmf/euro.tsv", include_str!("../fixtures/cmf/euro.tsv")),
+    ("cmf/utm.tsv", include_str!("../fixtures/cmf/utm.tsv")),
+    ("cmf/ipc.tsv", include_str!("../fixtures/cmf/ipc.tsv")),
+    ("market/licitaciones.json", include_str!("../fixtures/market/licitaciones.json")),
+    ("market/ordenesdecompra.json", include_str!("../fixtures/market/ordenesdecompra.json")),
+];
+
+
+/// A value of an indicator, as published by the CMF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// The date of the value.
+    pub date: NaiveDate,
+    /// The value, formatted as in the CMF API, e.g. `"39.485,65"`.
+    pub value: String,
+}
+
+
+/// Data served by the mock server.
+///
+/// # Behavior
+/// Fixtures are read from a directory with the layout of the `fixtures`
+/// directory of this crate:
+/// * `cmf/<indicator>.tsv` - The values of an indicator, with a header
+///   line and then a date (`YYYY-MM-DD`) and a value per line, separated
+///   by a tab. Dates must be in ascending order.
+/// * `market/licitaciones.json` and `market/ordenesdecompra.json` - JSON
+///   lists of tenders and purchase orders, as listed by the Mercado Público
+///   API when queried by code.
+///
+/// # Examples
+/// ```
+/// use mock_server::fixtures::Fixtures;
+///
+/// let fixtures = Fixtures::embedded().unwrap();
+/// assert!(fixtures.series("uf").unwrap().len() > 700);
+/// assert_eq!(fixtures.series("ipc").unwrap()[0].value, "0,7");
+/// assert!(!fixtures.tenders.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fixtures {
+    /// The values of each indicator, in ascending order of date.
+    pub series: Vec<(String, Vec<Record>)>,
+    /// The tenders (licitaciones).
+    pub tenders: Vec<Value>,
+    /// The purchase orders (órdenes de compra).
+    pub orders: Vec<Value>,
+}
+
+/// Implements methods for the [`Fixtures`] struct.
+impl Fixtures {
+    /// Loads the fixtures embedded in the binary.
+    pub fn embedded() -> Result<Self, FixtureError> {
+        Self::parse(|file| {
+            EMBEDDED
+                .iter()
+                .find(|(name, _)| *name == file)
+                .map(|(_, content)| content.to_string())
+                .ok_or_else(|| FixtureError::Unreadable { path: file.to_string(), reason: "not embedded".to_string() })
+        })
+    }
+
+    /// Loads the fixtures of a directory.
+    pub fn load(dir: &Path) -> Result<Self, FixtureError> {
+        Self::parse(|file| {
+            let path = dir.join(file);
+            fs::read_to_string(&path).map_err(|e| FixtureError::Unreadable {
+                path: path.display().to_string(),
+                reason: e.to_string(),
+            })
+        })
+    }
+
+    /// Gets the values of an indicator.
+    pub fn series(&self, indicator: &str) -> Option<&[Record]> {
+        self.series
+            .iter()
+            .find(|(name, _)| name == indicator)
+            .map(|(_, records)| records.as_slice())
+    }
+
+    fn parse(read: impl Fn(&str) -> Result<String, FixtureError>) -> Result<Self, FixtureError> {
+        let mut series = Vec::new();
+
+        for indicator in INDICATORS {
+            let file = format!("cmf/{}.tsv", indicator);
+            series.push((indicator.to_string(), parse_series(&file, &read(&file)?)?));
+        }
+
+        Ok(Self {
+            series,
+            tenders: parse_list("market/licitaciones.json", &read("market/licitaciones.json")?)?,
+            orders: parse_list("market/ordenesdecompra.json", &read("market/ordenesdecompra.json")?)?,
+        })
+    }
+}
+
+
+/// Parses a series file, skipping its header.
+fn parse_series(file: &str, content: &str) -> Result<Vec<Record>, FixtureError> {
+    let mut records: Vec<Record> = Vec::new();
+
+    for (index, line) in content.lines().enumerate().skip(1) {
+        let invalid = |reason: &str| FixtureError::Invalid {
+            file: file.to_string(),
+            line: index + 1,
+            reason: reason.to_string(),
+        };
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (date, value) = line.split_once('\t').ok_or_else(|| invalid("expected a date and a value"))?;
+        let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").map_err(|_| invalid("invalid date"))?;
+
+        if records.last().is_some_and(|r| r.date >= date) {
+            return Err(invalid("dates are not in ascending order"));
+        }
+
+        records.push(Record { date, value: value.trim().to_string() });
+    }
+
+    Ok(records)
+}
+
+
+/// Parses a JSON list file.
+fn parse_list(file: &str, content: &str) -> Result<Vec<Value>, FixtureError> {
+    match serde_json::from_str(content) {
+        Ok(Value::Array(items)) => Ok(items),
+        Ok(_) => Err(FixtureError::Invalid { file: file.to_string(), line: 1, reason: "expected a list".to_string() }),
+        Err(e) => Err(FixtureError::Invalid { file: file.to_string(), line: e.line(), reason: e.to_string() }),
+    }
+}
@@ -0,0 +1,185 @@
+pub mod errors;
+pub mod fixtures;
+pub mod cmf;
+pub mod market;
+mod xml;
+
+use crate::fixtures::Fixtures;
+
+
+/// Path where the CMF API is served, as in the real service.
+pub const CMF_PREFIX: &str = "/api-sbifv3/recursos_api";
+
+
+/// Path where the Mercado Público API is served, as in the real service.
+pub const MARKET_PREFIX: &str = "/servicios/v1/publico";
+
+
+/// Prefix of the API keys that simulate an error status, e.g.
+/// `"error-503"`.
+pub const ERROR_KEY_PREFIX: &str = "error-";
+
+
+/// Response of the mock server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    /// The HTTP status.
+    pub status: u16,
+    /// The value of the `Content-Type` header.
+    pub content_type: &'static str,
+    /// The body.
+    pub body: String,
+    /// The value of the `Retry-After` header, in seconds, if any.
+    pub retry_after: Option<u64>,
+}
+
+/// Implements methods for the [`MockResponse`] struct.
+impl MockResponse {
+    pub fn json(status: u16, body: String) -> Self {
+        Self { status, content_type: "application/json; charset=utf-8", body, retry_after: None }
+    }
+
+    pub fn xml(status: u16, body: String) -> Self {
+        Self { status, content_type: "application/xml; charset=utf-8", body, retry_after: None }
+    }
+
+    pub fn text(status: u16, body: &str) -> Self {
+        Self { status, content_type: "text/plain; charset=utf-8", body: body.to_string(), retry_after: None }
+    }
+}
+
+
+/// Decodes the percent-encoded characters and `'+'` of a query component.
+fn decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let high = (bytes[i + 1] as char).to_digit(16);
+                let low = (bytes[i + 2] as char).to_digit(16);
+
+                match (high, low) {
+                    (Some(high), Some(low)) => {
+                        decoded.push((high * 16 + low) as u8);
+                        i += 2;
+                    }
+                    _ => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+
+/// Parses a query string into its parameters.
+///
+/// # Examples
+/// ```
+/// use mock_server::parse_query;
+///
+/// let query = parse_query("apikey=abc&formato=json&nombre=Comercial%20Ni%C3%B1o+Ltda");
+/// assert_eq!(query[1], ("formato".to_string(), "json".to_string()));
+/// assert_eq!(query[2].1, "Comercial Niño Ltda");
+/// ```
+pub fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let (key, value) = p.split_once('=').unwrap_or((p, ""));
+            (decode(key), decode(value))
+        })
+        .collect()
+}
+
+
+/// Gets the value of a query parameter, ignoring the case of its name.
+pub(crate) fn param<'a>(query: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    query
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+
+/// Gets the error status simulated by an API key, e.g. `503` for
+/// `"error-503"`.
+pub fn simulated_status(key: &str) -> Option<u16> {
+    key.strip_prefix(ERROR_KEY_PREFIX)
+        .and_then(|s| s.parse().ok())
+        .filter(|s| (400..600).contains(s))
+}
+
+
+/// Stand-in for the CMF and Mercado Público APIs, answering requests with
+/// the data of its fixtures.
+///
+/// # Behavior
+/// * The CMF API is served under [`CMF_PREFIX`], and the Mercado Público
+///   API under [`MARKET_PREFIX`], so clients only need a different host.
+/// * Any non-empty API key or ticket is accepted. Keys such as
+///   `"error-503"` get that status, with the error body of the service,
+///   to test error handling and retries. `429` and `503` also get a
+///   `Retry-After` header of one second.
+///
+/// # Examples
+/// ```
+/// use mock_server::MockServer;
+/// use mock_server::fixtures::Fixtures;
+///
+/// let server = MockServer::new(Fixtures::embedded().unwrap());
+///
+/// let response = server.respond("/api-sbifv3/recursos_api/uf/2025/01/dias/02?apikey=key&formato=json");
+/// assert_eq!(response.status, 200);
+/// assert!(response.body.starts_with(r#"{"UFs":[{"Fecha":"2025-01-02","Valor":""#));
+///
+/// let response = server.respond("/servicios/v1/publico/licitaciones.xml?ticket=key&estado=activas");
+/// assert_eq!(response.status, 200);
+/// assert!(response.body.contains("<Cantidad>2</Cantidad>"));
+///
+/// assert_eq!(server.respond("/api-sbifv3/recursos_api/uf?apikey=error-503").status, 503);
+/// assert_eq!(server.respond("/api-sbifv3/recursos_api/uf?formato=json").status, 401);
+/// assert_eq!(server.respond("/other").status, 404);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockServer {
+    pub fixtures: Fixtures,
+}
+
+/// Implements methods for the [`MockServer`] struct.
+impl MockServer {
+    pub fn new(fixtures: Fixtures) -> Self {
+        Self { fixtures }
+    }
+
+    /// Answers a GET request.
+    ///
+    /// # Arguments
+    /// * `url` - The path of the request, with its query string.
+    pub fn respond(&self, url: &str) -> MockResponse {
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let query = parse_query(query);
+        let path = path.trim_end_matches('/');
+
+        let mut response = if let Some(path) = path.strip_prefix(CMF_PREFIX) {
+            cmf::respond(&self.fixtures, path, &query)
+        } else if let Some(path) = path.strip_prefix(MARKET_PREFIX) {
+            market::respond(&self.fixtures, path, &query)
+        } else {
+            MockResponse::text(404, "Not found")
+        };
+
+        if matches!(response.status, 429 | 503) {
+            response.retry_after = Some(1);
+        }
+        response
+    }
+}
@@ -0,0 +1,118 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use tiny_http::Header;
+use tiny_http::Method;
+use tiny_http::Response;
+use tiny_http::Server;
+
+use mock_server::CMF_PREFIX;
+use mock_server::MARKET_PREFIX;
+use mock_server::MockResponse;
+use mock_server::MockServer;
+use mock_server::fixtures::Fixtures;
+
+
+const USAGE: &str = "\
+Serves the CMF and Mercado Público APIs from fixture files.
+
+Usage: cl-forge-mock [--host <HOST>] [--port <PORT>] [--fixtures <DIR>]
+
+Options:
+  --host <HOST>      Address to listen on [default: 127.0.0.1]
+  --port <PORT>      Port to listen on [default: 8787]
+  --fixtures <DIR>   Directory with the fixtures, instead of the embedded ones
+  -h, --help         Print this help";
+
+
+struct Args {
+    host: String,
+    port: u16,
+    fixtures: Option<PathBuf>,
+}
+
+
+fn parse_args() -> Result<Option<Args>, String> {
+    let mut args = Args { host: "127.0.0.1".to_string(), port: 8787, fixtures: None };
+    let mut iter = env::args().skip(1);
+
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or_else(|| format!("missing value of '{}'", arg));
+
+        match arg.as_str() {
+            "--host" => args.host = value()?,
+            "--port" => args.port = value()?.parse().map_err(|_| "invalid port".to_string())?,
+            "--fixtures" => args.fixtures = Some(PathBuf::from(value()?)),
+            "-h" | "--help" => return Ok(None),
+            other => return Err(format!("unknown argument '{}'", other)),
+        }
+    }
+
+    Ok(Some(args))
+}
+
+
+fn to_response(mock: MockResponse) -> Response<std::io::Cursor<Vec<u8>>> {
+    let mut response = Response::from_string(mock.body)
+        .with_status_code(mock.status)
+        .with_header(Header::from_bytes("Content-Type", mock.content_type).expect("valid header"));
+
+    if let Some(seconds) = mock.retry_after {
+        response.add_header(Header::from_bytes("Retry-After", seconds.to_string()).expect("valid header"));
+    }
+    response
+}
+
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let fixtures = match &args.fixtures {
+        Some(dir) => Fixtures::load(dir),
+        None => Fixtures::embedded(),
+    };
+    let server = match fixtures {
+        Ok(fixtures) => MockServer::new(fixtures),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let http = match Server::http((args.host.as_str(), args.port)) {
+        Ok(http) => http,
+        Err(e) => {
+            eprintln!("error: cannot listen on {}:{}: {}", args.host, args.port, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("CMF API:             http://{}:{}{}", args.host, args.port, CMF_PREFIX);
+    println!("Mercado Público API: http://{}:{}{}", args.host, args.port, MARKET_PREFIX);
+
+    for request in http.incoming_requests() {
+        let mock = match request.method() {
+            Method::Get => server.respond(request.url()),
+            _ => MockResponse::text(405, "Method not allowed"),
+        };
+
+        eprintln!("{} {} -> {}", request.method(), request.url(), mock.status);
+
+        if let Err(e) = request.respond(to_response(mock)) {
+            eprintln!("error: cannot respond: {}", e);
+        }
+    }
+
+    ExitCode::SUCCESS
+}
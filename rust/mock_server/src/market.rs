@@ -0,0 +1,161 @@
+use chrono::NaiveDate;
+use chrono::Utc;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::MockResponse;
+use crate::fixtures::Fixtures;
+use crate::param;
+use crate::simulated_status;
+use crate::xml;
+
+
+/// A listing of the Mercado Público API, e.g. `licitaciones`.
+struct Resource {
+    /// The field with the code of the items.
+    code_field: &'static str,
+    /// The name of the items in XML responses.
+    item: &'static str,
+    /// The values of the `estado` parameter, and the states they select.
+    states: &'static [(&'static str, &'static str)],
+}
+
+
+const TENDERS: Resource = Resource {
+    code_field: "CodigoExterno",
+    item: "Licitacion",
+    states: &[
+        ("activas", "Publicada"),
+        ("publicada", "Publicada"),
+        ("cerrada", "Cerrada"),
+        ("desierta", "Desierta"),
+        ("adjudicada", "Adjudicada"),
+        ("revocada", "Revocada"),
+        ("suspendida", "Suspendida"),
+    ],
+};
+
+
+const ORDERS: Resource = Resource {
+    code_field: "Codigo",
+    item: "OrdenCompra",
+    states: &[
+        ("enviadaproveedor", "Enviada a proveedor"),
+        ("aceptada", "Aceptada"),
+        ("cancelada", "Cancelada"),
+        ("recepcionconforme", "Recepción Conforme"),
+        ("pendienterecepcion", "Pendiente de Recepcionar"),
+        ("recepcionaceptadaparcialmente", "Recepción Aceptada Parcialmente"),
+        ("recepcionconformeincompleta", "Recepción Conforme Incompleta"),
+    ],
+};
+
+
+/// Builds an error response, as the Mercado Público API does.
+fn error(status: u16, code: u16, message: &str, xml_format: bool) -> MockResponse {
+    if xml_format {
+        let mut body = String::from(xml::DECLARATION);
+        body.push_str("<Error>");
+        xml::write_text(&mut body, "Codigo", &code.to_string());
+        xml::write_text(&mut body, "Mensaje", message);
+        body.push_str("</Error>");
+        MockResponse::xml(status, body)
+    } else {
+        MockResponse::json(status, json!({ "Codigo": code, "Mensaje": message }).to_string())
+    }
+}
+
+
+/// Checks whether an item was created on a date.
+fn created_on(item: &Value, date: NaiveDate) -> bool {
+    item.pointer("/Fechas/FechaCreacion")
+        .and_then(Value::as_str)
+        .is_some_and(|created| created.starts_with(&date.to_string()))
+}
+
+
+/// Answers a request to the Mercado Público API.
+///
+/// # Arguments
+/// * `fixtures` - The data served.
+/// * `path` - The path of the request, after [`crate::MARKET_PREFIX`],
+///   e.g. `"/licitaciones.json"`.
+/// * `query` - The query parameters of the request: `ticket`, and
+///   optionally `codigo`, `fecha` (`ddmmaaaa`) and `estado`.
+///
+/// # Behavior
+/// Without `codigo` nor `fecha`, all the items are listed, instead of the
+/// ones of the current day.
+pub fn respond(fixtures: &Fixtures, path: &str, query: &[(String, String)]) -> MockResponse {
+    let Some((name, extension)) = path.trim_start_matches('/').rsplit_once('.') else {
+        return MockResponse::text(404, "Not found");
+    };
+    let xml_format = match extension.to_lowercase().as_str() {
+        "json" => false,
+        "xml" => true,
+        _ => return MockResponse::text(404, "Not found"),
+    };
+    let (resource, items) = match name.to_lowercase().as_str() {
+        "licitaciones" => (TENDERS, &fixtures.tenders),
+        "ordenesdecompra" => (ORDERS, &fixtures.orders),
+        _ => return MockResponse::text(404, "Not found"),
+    };
+
+    let ticket = param(query, "ticket").unwrap_or_default().trim();
+    if ticket.is_empty() {
+        return error(401, 203, "Ticket no válido.", xml_format);
+    }
+    if let Some(status) = simulated_status(ticket) {
+        return error(status, status, &format!("Error simulado con estado {}.", status), xml_format);
+    }
+
+    let date = match param(query, "fecha") {
+        Some(fecha) => match NaiveDate::parse_from_str(fecha.trim(), "%d%m%Y") {
+            Ok(date) => Some(date),
+            Err(_) => return error(400, 400, "El parámetro fecha debe tener el formato ddmmaaaa.", xml_format),
+        },
+        None => None,
+    };
+
+    let state = match param(query, "estado").map(str::to_lowercase) {
+        None => None,
+        Some(estado) if estado == "todos" => None,
+        Some(estado) => match resource.states.iter().find(|(value, _)| *value == estado) {
+            Some((_, state)) => Some(*state),
+            None => return error(400, 400, "El parámetro estado no es válido.", xml_format),
+        },
+    };
+
+    let code = param(query, "codigo").map(str::trim);
+
+    let selected: Vec<&Value> = items
+        .iter()
+        .filter(|item| code.is_none_or(|code| item[resource.code_field].as_str() == Some(code)))
+        .filter(|item| date.is_none_or(|date| created_on(item, date)))
+        .filter(|item| state.is_none_or(|state| item["Estado"].as_str() == Some(state)))
+        .collect();
+
+    let created = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3f").to_string();
+
+    if xml_format {
+        let mut body = String::from(xml::DECLARATION);
+        body.push_str("<Resultado>");
+        xml::write_text(&mut body, "Cantidad", &selected.len().to_string());
+        xml::write_text(&mut body, "FechaCreacion", &created);
+        xml::write_text(&mut body, "Version", "v1");
+        body.push_str("<Listado>");
+        for item in &selected {
+            xml::write_element(&mut body, resource.item, item);
+        }
+        body.push_str("</Listado></Resultado>");
+        MockResponse::xml(200, body)
+    } else {
+        let body = json!({
+            "Cantidad": selected.len(),
+            "FechaCreacion": created,
+            "Version": "v1",
+            "Listado": selected,
+        });
+        MockResponse::json(200, body.to_string())
+    }
+}
@@ -0,0 +1,48 @@
+use serde_json::Value;
+
+
+/// Header of the XML documents.
+pub(crate) const DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8"?>"#;
+
+
+/// Escapes the characters of a text that are special in XML.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+
+/// Writes a text element.
+pub(crate) fn write_text(out: &mut String, name: &str, text: &str) {
+    out.push_str(&format!("<{0}>{1}</{0}>", name, escape(text)));
+}
+
+
+/// Writes a JSON value as an XML element.
+///
+/// # Behavior
+/// Objects become elements with a child per field, and `null` an empty
+/// element. Arrays become elements with a child per item, named `Item`.
+pub(crate) fn write_element(out: &mut String, name: &str, value: &Value) {
+    match value {
+        Value::Null => out.push_str(&format!("<{}/>", name)),
+        Value::Object(fields) => {
+            out.push_str(&format!("<{}>", name));
+            for (key, value) in fields {
+                write_element(out, key, value);
+            }
+            out.push_str(&format!("</{}>", name));
+        }
+        Value::Array(items) => {
+            out.push_str(&format!("<{}>", name));
+            for value in items {
+                write_element(out, "Item", value);
+            }
+            out.push_str(&format!("</{}>", name));
+        }
+        Value::String(text) => write_text(out, name, text),
+        other => write_text(out, name, &other.to_string()),
+    }
+}
@@ -0,0 +1,502 @@
+use crate::registry::Region;
+use crate::registry::Province;
+use crate::registry::Comuna;
+use crate::postal::PostalRange;
+
+
+/// Regions of Chile, by CUT code ("Código Único Territorial").
+///
+/// `order` is the geographic position of the region, from north to south.
+pub static REGIONS: [Region; 16] = [
+    Region { code: "01", roman: "I", name: "Tarapacá", order: 2 },
+    Region { code: "02", roman: "II", name: "Antofagasta", order: 3 },
+    Region { code: "03", roman: "III", name: "Atacama", order: 4 },
+    Region { code: "04", roman: "IV", name: "Coquimbo", order: 5 },
+    Region { code: "05", roman: "V", name: "Valparaíso", order: 6 },
+    Region { code: "06", roman: "VI", name: "Libertador General Bernardo O'Higgins", order: 8 },
+    Region { code: "07", roman: "VII", name: "Maule", order: 9 },
+    Region { code: "08", roman: "VIII", name: "Biobío", order: 11 },
+    Region { code: "09", roman: "IX", name: "La Araucanía", order: 12 },
+    Region { code: "10", roman: "X", name: "Los Lagos", order: 14 },
+    Region { code: "11", roman: "XI", name: "Aysén del General Carlos Ibáñez del Campo", order: 15 },
+    Region { code: "12", roman: "XII", name: "Magallanes y de la Antártica Chilena", order: 16 },
+    Region { code: "13", roman: "RM", name: "Metropolitana de Santiago", order: 7 },
+    Region { code: "14", roman: "XIV", name: "Los Ríos", order: 13 },
+    Region { code: "15", roman: "XV", name: "Arica y Parinacota", order: 1 },
+    Region { code: "16", roman: "XVI", name: "Ñuble", order: 10 },
+];
+
+
+/// Provinces of Chile, by CUT code. The first two digits are the code of
+/// the region.
+pub static PROVINCES: [Province; 56] = [
+    Province { code: "011", name: "Iquique" },
+    Province { code: "014", name: "Tamarugal" },
+    Province { code: "021", name: "Antofagasta" },
+    Province { code: "022", name: "El Loa" },
+    Province { code: "023", name: "Tocopilla" },
+    Province { code: "031", name: "Copiapó" },
+    Province { code: "032", name: "Chañaral" },
+    Province { code: "033", name: "Huasco" },
+    Province { code: "041", name: "Elqui" },
+    Province { code: "042", name: "Choapa" },
+    Province { code: "043", name: "Limarí" },
+    Province { code: "051", name: "Valparaíso" },
+    Province { code: "052", name: "Isla de Pascua" },
+    Province { code: "053", name: "Los Andes" },
+    Province { code: "054", name: "Petorca" },
+    Province { code: "055", name: "Quillota" },
+    Province { code: "056", name: "San Antonio" },
+    Province { code: "057", name: "San Felipe de Aconcagua" },
+    Province { code: "058", name: "Marga Marga" },
+    Province { code: "061", name: "Cachapoal" },
+    Province { code: "062", name: "Cardenal Caro" },
+    Province { code: "063", name: "Colchagua" },
+    Province { code: "071", name: "Talca" },
+    Province { code: "072", name: "Cauquenes" },
+    Province { code: "073", name: "Curicó" },
+    Province { code: "074", name: "Linares" },
+    Province { code: "081", name: "Concepción" },
+    Province { code: "082", name: "Arauco" },
+    Province { code: "083", name: "Biobío" },
+    Province { code: "091", name: "Cautín" },
+    Province { code: "092", name: "Malleco" },
+    Province { code: "101", name: "Llanquihue" },
+    Province { code: "102", name: "Chiloé" },
+    Province { code: "103", name: "Osorno" },
+    Province { code: "104", name: "Palena" },
+    Province { code: "111", name: "Coyhaique" },
+    Province { code: "112", name: "Aysén" },
+    Province { code: "113", name: "Capitán Prat" },
+    Province { code: "114", name: "General Carrera" },
+    Province { code: "121", name: "Magallanes" },
+    Province { code: "122", name: "Antártica Chilena" },
+    Province { code: "123", name: "Tierra del Fuego" },
+    Province { code: "124", name: "Última Esperanza" },
+    Province { code: "131", name: "Santiago" },
+    Province { code: "132", name: "Cordillera" },
+    Province { code: "133", name: "Chacabuco" },
+    Province { code: "134", name: "Maipo" },
+    Province { code: "135", name: "Melipilla" },
+    Province { code: "136", name: "Talagante" },
+    Province { code: "141", name: "Valdivia" },
+    Province { code: "142", name: "Ranco" },
+    Province { code: "151", name: "Arica" },
+    Province { code: "152", name: "Parinacota" },
+    Province { code: "161", name: "Diguillín" },
+    Province { code: "162", name: "Itata" },
+    Province { code: "163", name: "Punilla" },
+];
+
+
+/// Comunas of Chile, by CUT code. The first three digits are the code of
+/// the province.
+pub static COMUNAS: [Comuna; 346] = [
+    Comuna { code: "01101", name: "Iquique" },
+    Comuna { code: "01107", name: "Alto Hospicio" },
+    Comuna { code: "01401", name: "Pozo Almonte" },
+    Comuna { code: "01402", name: "Camiña" },
+    Comuna { code: "01403", name: "Colchane" },
+    Comuna { code: "01404", name: "Huara" },
+    Comuna { code: "01405", name: "Pica" },
+    Comuna { code: "02101", name: "Antofagasta" },
+    Comuna { code: "02102", name: "Mejillones" },
+    Comuna { code: "02103", name: "Sierra Gorda" },
+    Comuna { code: "02104", name: "Taltal" },
+    Comuna { code: "02201", name: "Calama" },
+    Comuna { code: "02202", name: "Ollagüe" },
+    Comuna { code: "02203", name: "San Pedro de Atacama" },
+    Comuna { code: "02301", name: "Tocopilla" },
+    Comuna { code: "02302", name: "María Elena" },
+    Comuna { code: "03101", name: "Copiapó" },
+    Comuna { code: "03102", name: "Caldera" },
+    Comuna { code: "03103", name: "Tierra Amarilla" },
+    Comuna { code: "03201", name: "Chañaral" },
+    Comuna { code: "03202", name: "Diego de Almagro" },
+    Comuna { code: "03301", name: "Vallenar" },
+    Comuna { code: "03302", name: "Alto del Carmen" },
+    Comuna { code: "03303", name: "Freirina" },
+    Comuna { code: "03304", name: "Huasco" },
+    Comuna { code: "04101", name: "La Serena" },
+    Comuna { code: "04102", name: "Coquimbo" },
+    Comuna { code: "04103", name: "Andacollo" },
+    Comuna { code: "04104", name: "La Higuera" },
+    Comuna { code: "04105", name: "Paiguano" },
+    Comuna { code: "04106", name: "Vicuña" },
+    Comuna { code: "04201", name: "Illapel" },
+    Comuna { code: "04202", name: "Canela" },
+    Comuna { code: "04203", name: "Los Vilos" },
+    Comuna { code: "04204", name: "Salamanca" },
+    Comuna { code: "04301", name: "Ovalle" },
+    Comuna { code: "04302", name: "Combarbalá" },
+    Comuna { code: "04303", name: "Monte Patria" },
+    Comuna { code: "04304", name: "Punitaqui" },
+    Comuna { code: "04305", name: "Río Hurtado" },
+    Comuna { code: "05101", name: "Valparaíso" },
+    Comuna { code: "05102", name: "Casablanca" },
+    Comuna { code: "05103", name: "Concón" },
+    Comuna { code: "05104", name: "Juan Fernández" },
+    Comuna { code: "05105", name: "Puchuncaví" },
+    Comuna { code: "05107", name: "Quintero" },
+    Comuna { code: "05109", name: "Viña del Mar" },
+    Comuna { code: "05201", name: "Isla de Pascua" },
+    Comuna { code: "05301", name: "Los Andes" },
+    Comuna { code: "05302", name: "Calle Larga" },
+    Comuna { code: "05303", name: "Rinconada" },
+    Comuna { code: "05304", name: "San Esteban" },
+    Comuna { code: "05401", name: "La Ligua" },
+    Comuna { code: "05402", name: "Cabildo" },
+    Comuna { code: "05403", name: "Papudo" },
+    Comuna { code: "05404", name: "Petorca" },
+    Comuna { code: "05405", name: "Zapallar" },
+    Comuna { code: "05501", name: "Quillota" },
+    Comuna { code: "05502", name: "Calera" },
+    Comuna { code: "05503", name: "Hijuelas" },
+    Comuna { code: "05504", name: "La Cruz" },
+    Comuna { code: "05506", name: "Nogales" },
+    Comuna { code: "05601", name: "San Antonio" },
+    Comuna { code: "05602", name: "Algarrobo" },
+    Comuna { code: "05603", name: "Cartagena" },
+    Comuna { code: "05604", name: "El Quisco" },
+    Comuna { code: "05605", name: "El Tabo" },
+    Comuna { code: "05606", name: "Santo Domingo" },
+    Comuna { code: "05701", name: "San Felipe" },
+    Comuna { code: "05702", name: "Catemu" },
+    Comuna { code: "05703", name: "Llaillay" },
+    Comuna { code: "05704", name: "Panquehue" },
+    Comuna { code: "05705", name: "Putaendo" },
+    Comuna { code: "05706", name: "Santa María" },
+    Comuna { code: "05801", name: "Quilpué" },
+    Comuna { code: "05802", name: "Limache" },
+    Comuna { code: "05803", name: "Olmué" },
+    Comuna { code: "05804", name: "Villa Alemana" },
+    Comuna { code: "06101", name: "Rancagua" },
+    Comuna { code: "06102", name: "Codegua" },
+    Comuna { code: "06103", name: "Coinco" },
+    Comuna { code: "06104", name: "Coltauco" },
+    Comuna { code: "06105", name: "Doñihue" },
+    Comuna { code: "06106", name: "Graneros" },
+    Comuna { code: "06107", name: "Las Cabras" },
+    Comuna { code: "06108", name: "Machalí" },
+    Comuna { code: "06109", name: "Malloa" },
+    Comuna { code: "06110", name: "Mostazal" },
+    Comuna { code: "06111", name: "Olivar" },
+    Comuna { code: "06112", name: "Peumo" },
+    Comuna { code: "06113", name: "Pichidegua" },
+    Comuna { code: "06114", name: "Quinta de Tilcoco" },
+    Comuna { code: "06115", name: "Rengo" },
+    Comuna { code: "06116", name: "Requínoa" },
+    Comuna { code: "06117", name: "San Vicente" },
+    Comuna { code: "06201", name: "Pichilemu" },
+    Comuna { code: "06202", name: "La Estrella" },
+    Comuna { code: "06203", name: "Litueche" },
+    Comuna { code: "06204", name: "Marchihue" },
+    Comuna { code: "06205", name: "Navidad" },
+    Comuna { code: "06206", name: "Paredones" },
+    Comuna { code: "06301", name: "San Fernando" },
+    Comuna { code: "06302", name: "Chépica" },
+    Comuna { code: "06303", name: "Chimbarongo" },
+    Comuna { code: "06304", name: "Lolol" },
+    Comuna { code: "06305", name: "Nancagua" },
+    Comuna { code: "06306", name: "Palmilla" },
+    Comuna { code: "06307", name: "Peralillo" },
+    Comuna { code: "06308", name: "Placilla" },
+    Comuna { code: "06309", name: "Pumanque" },
+    Comuna { code: "06310", name: "Santa Cruz" },
+    Comuna { code: "07101", name: "Talca" },
+    Comuna { code: "07102", name: "Constitución" },
+    Comuna { code: "07103", name: "Curepto" },
+    Comuna { code: "07104", name: "Empedrado" },
+    Comuna { code: "07105", name: "Maule" },
+    Comuna { code: "07106", name: "Pelarco" },
+    Comuna { code: "07107", name: "Pencahue" },
+    Comuna { code: "07108", name: "Río Claro" },
+    Comuna { code: "07109", name: "San Clemente" },
+    Comuna { code: "07110", name: "San Rafael" },
+    Comuna { code: "07201", name: "Cauquenes" },
+    Comuna { code: "07202", name: "Chanco" },
+    Comuna { code: "07203", name: "Pelluhue" },
+    Comuna { code: "07301", name: "Curicó" },
+    Comuna { code: "07302", name: "Hualañé" },
+    Comuna { code: "07303", name: "Licantén" },
+    Comuna { code: "07304", name: "Molina" },
+    Comuna { code: "07305", name: "Rauco" },
+    Comuna { code: "07306", name: "Romeral" },
+    Comuna { code: "07307", name: "Sagrada Familia" },
+    Comuna { code: "07308", name: "Teno" },
+    Comuna { code: "07309", name: "Vichuquén" },
+    Comuna { code: "07401", name: "Linares" },
+    Comuna { code: "07402", name: "Colbún" },
+    Comuna { code: "07403", name: "Longaví" },
+    Comuna { code: "07404", name: "Parral" },
+    Comuna { code: "07405", name: "Retiro" },
+    Comuna { code: "07406", name: "San Javier" },
+    Comuna { code: "07407", name: "Villa Alegre" },
+    Comuna { code: "07408", name: "Yerbas Buenas" },
+    Comuna { code: "08101", name: "Concepción" },
+    Comuna { code: "08102", name: "Coronel" },
+    Comuna { code: "08103", name: "Chiguayante" },
+    Comuna { code: "08104", name: "Florida" },
+    Comuna { code: "08105", name: "Hualqui" },
+    Comuna { code: "08106", name: "Lota" },
+    Comuna { code: "08107", name: "Penco" },
+    Comuna { code: "08108", name: "San Pedro de la Paz" },
+    Comuna { code: "08109", name: "Santa Juana" },
+    Comuna { code: "08110", name: "Talcahuano" },
+    Comuna { code: "08111", name: "Tomé" },
+    Comuna { code: "08112", name: "Hualpén" },
+    Comuna { code: "08201", name: "Lebu" },
+    Comuna { code: "08202", name: "Arauco" },
+    Comuna { code: "08203", name: "Cañete" },
+    Comuna { code: "08204", name: "Contulmo" },
+    Comuna { code: "08205", name: "Curanilahue" },
+    Comuna { code: "08206", name: "Los Álamos" },
+    Comuna { code: "08207", name: "Tirúa" },
+    Comuna { code: "08301", name: "Los Ángeles" },
+    Comuna { code: "08302", name: "Antuco" },
+    Comuna { code: "08303", name: "Cabrero" },
+    Comuna { code: "08304", name: "Laja" },
+    Comuna { code: "08305", name: "Mulchén" },
+    Comuna { code: "08306", name: "Nacimiento" },
+    Comuna { code: "08307", name: "Negrete" },
+    Comuna { code: "08308", name: "Quilaco" },
+    Comuna { code: "08309", name: "Quilleco" },
+    Comuna { code: "08310", name: "San Rosendo" },
+    Comuna { code: "08311", name: "Santa Bárbara" },
+    Comuna { code: "08312", name: "Tucapel" },
+    Comuna { code: "08313", name: "Yumbel" },
+    Comuna { code: "08314", name: "Alto Biobío" },
+    Comuna { code: "09101", name: "Temuco" },
+    Comuna { code: "09102", name: "Carahue" },
+    Comuna { code: "09103", name: "Cunco" },
+    Comuna { code: "09104", name: "Curarrehue" },
+    Comuna { code: "09105", name: "Freire" },
+    Comuna { code: "09106", name: "Galvarino" },
+    Comuna { code: "09107", name: "Gorbea" },
+    Comuna { code: "09108", name: "Lautaro" },
+    Comuna { code: "09109", name: "Loncoche" },
+    Comuna { code: "09110", name: "Melipeuco" },
+    Comuna { code: "09111", name: "Nueva Imperial" },
+    Comuna { code: "09112", name: "Padre Las Casas" },
+    Comuna { code: "09113", name: "Perquenco" },
+    Comuna { code: "09114", name: "Pitrufquén" },
+    Comuna { code: "09115", name: "Pucón" },
+    Comuna { code: "09116", name: "Saavedra" },
+    Comuna { code: "09117", name: "Teodoro Schmidt" },
+    Comuna { code: "09118", name: "Toltén" },
+    Comuna { code: "09119", name: "Vilcún" },
+    Comuna { code: "09120", name: "Villarrica" },
+    Comuna { code: "09121", name: "Cholchol" },
+    Comuna { code: "09201", name: "Angol" },
+    Comuna { code: "09202", name: "Collipulli" },
+    Comuna { code: "09203", name: "Curacautín" },
+    Comuna { code: "09204", name: "Ercilla" },
+    Comuna { code: "09205", name: "Lonquimay" },
+    Comuna { code: "09206", name: "Los Sauces" },
+    Comuna { code: "09207", name: "Lumaco" },
+    Comuna { code: "09208", name: "Purén" },
+    Comuna { code: "09209", name: "Renaico" },
+    Comuna { code: "09210", name: "Traiguén" },
+    Comuna { code: "09211", name: "Victoria" },
+    Comuna { code: "10101", name: "Puerto Montt" },
+    Comuna { code: "10102", name: "Calbuco" },
+    Comuna { code: "10103", name: "Cochamó" },
+    Comuna { code: "10104", name: "Fresia" },
+    Comuna { code: "10105", name: "Frutillar" },
+    Comuna { code: "10106", name: "Los Muermos" },
+    Comuna { code: "10107", name: "Llanquihue" },
+    Comuna { code: "10108", name: "Maullín" },
+    Comuna { code: "10109", name: "Puerto Varas" },
+    Comuna { code: "10201", name: "Castro" },
+    Comuna { code: "10202", name: "Ancud" },
+    Comuna { code: "10203", name: "Chonchi" },
+    Comuna { code: "10204", name: "Curaco de Vélez" },
+    Comuna { code: "10205", name: "Dalcahue" },
+    Comuna { code: "10206", name: "Puqueldón" },
+    Comuna { code: "10207", name: "Queilén" },
+    Comuna { code: "10208", name: "Quellón" },
+    Comuna { code: "10209", name: "Quemchi" },
+    Comuna { code: "10210", name: "Quinchao" },
+    Comuna { code: "10301", name: "Osorno" },
+    Comuna { code: "10302", name: "Puerto Octay" },
+    Comuna { code: "10303", name: "Purranque" },
+    Comuna { code: "10304", name: "Puyehue" },
+    Comuna { code: "10305", name: "Río Negro" },
+    Comuna { code: "10306", name: "San Juan de la Costa" },
+    Comuna { code: "10307", name: "San Pablo" },
+    Comuna { code: "10401", name: "Chaitén" },
+    Comuna { code: "10402", name: "Futaleufú" },
+    Comuna { code: "10403", name: "Hualaihué" },
+    Comuna { code: "10404", name: "Palena" },
+    Comuna { code: "11101", name: "Coyhaique" },
+    Comuna { code: "11102", name: "Lago Verde" },
+    Comuna { code: "11201", name: "Aysén" },
+    Comuna { code: "11202", name: "Cisnes" },
+    Comuna { code: "11203", name: "Guaitecas" },
+    Comuna { code: "11301", name: "Cochrane" },
+    Comuna { code: "11302", name: "O'Higgins" },
+    Comuna { code: "11303", name: "Tortel" },
+    Comuna { code: "11401", name: "Chile Chico" },
+    Comuna { code: "11402", name: "Río Ibáñez" },
+    Comuna { code: "12101", name: "Punta Arenas" },
+    Comuna { code: "12102", name: "Laguna Blanca" },
+    Comuna { code: "12103", name: "Río Verde" },
+    Comuna { code: "12104", name: "San Gregorio" },
+    Comuna { code: "12201", name: "Cabo de Hornos" },
+    Comuna { code: "12202", name: "Antártica" },
+    Comuna { code: "12301", name: "Porvenir" },
+    Comuna { code: "12302", name: "Primavera" },
+    Comuna { code: "12303", name: "Timaukel" },
+    Comuna { code: "12401", name: "Natales" },
+    Comuna { code: "12402", name: "Torres del Paine" },
+    Comuna { code: "13101", name: "Santiago" },
+    Comuna { code: "13102", name: "Cerrillos" },
+    Comuna { code: "13103", name: "Cerro Navia" },
+    Comuna { code: "13104", name: "Conchalí" },
+    Comuna { code: "13105", name: "El Bosque" },
+    Comuna { code: "13106", name: "Estación Central" },
+    Comuna { code: "13107", name: "Huechuraba" },
+    Comuna { code: "13108", name: "Independencia" },
+    Comuna { code: "13109", name: "La Cisterna" },
+    Comuna { code: "13110", name: "La Florida" },
+    Comuna { code: "13111", name: "La Granja" },
+    Comuna { code: "13112", name: "La Pintana" },
+    Comuna { code: "13113", name: "La Reina" },
+    Comuna { code: "13114", name: "Las Condes" },
+    Comuna { code: "13115", name: "Lo Barnechea" },
+    Comuna { code: "13116", name: "Lo Espejo" },
+    Comuna { code: "13117", name: "Lo Prado" },
+    Comuna { code: "13118", name: "Macul" },
+    Comuna { code: "13119", name: "Maipú" },
+    Comuna { code: "13120", name: "Ñuñoa" },
+    Comuna { code: "13121", name: "Pedro Aguirre Cerda" },
+    Comuna { code: "13122", name: "Peñalolén" },
+    Comuna { code: "13123", name: "Providencia" },
+    Comuna { code: "13124", name: "Pudahuel" },
+    Comuna { code: "13125", name: "Quilicura" },
+    Comuna { code: "13126", name: "Quinta Normal" },
+    Comuna { code: "13127", name: "Recoleta" },
+    Comuna { code: "13128", name: "Renca" },
+    Comuna { code: "13129", name: "San Joaquín" },
+    Comuna { code: "13130", name: "San Miguel" },
+    Comuna { code: "13131", name: "San Ramón" },
+    Comuna { code: "13132", name: "Vitacura" },
+    Comuna { code: "13201", name: "Puente Alto" },
+    Comuna { code: "13202", name: "Pirque" },
+    Comuna { code: "13203", name: "San José de Maipo" },
+    Comuna { code: "13301", name: "Colina" },
+    Comuna { code: "13302", name: "Lampa" },
+    Comuna { code: "13303", name: "Tiltil" },
+    Comuna { code: "13401", name: "San Bernardo" },
+    Comuna { code: "13402", name: "Buin" },
+    Comuna { code: "13403", name: "Calera de Tango" },
+    Comuna { code: "13404", name: "Paine" },
+    Comuna { code: "13501", name: "Melipilla" },
+    Comuna { code: "13502", name: "Alhué" },
+    Comuna { code: "13503", name: "Curacaví" },
+    Comuna { code: "13504", name: "María Pinto" },
+    Comuna { code: "13505", name: "San Pedro" },
+    Comuna { code: "13601", name: "Talagante" },
+    Comuna { code: "13602", name: "El Monte" },
+    Comuna { code: "13603", name: "Isla de Maipo" },
+    Comuna { code: "13604", name: "Padre Hurtado" },
+    Comuna { code: "13605", name: "Peñaflor" },
+    Comuna { code: "14101", name: "Valdivia" },
+    Comuna { code: "14102", name: "Corral" },
+    Comuna { code: "14103", name: "Lanco" },
+    Comuna { code: "14104", name: "Los Lagos" },
+    Comuna { code: "14105", name: "Máfil" },
+    Comuna { code: "14106", name: "Mariquina" },
+    Comuna { code: "14107", name: "Paillaco" },
+    Comuna { code: "14108", name: "Panguipulli" },
+    Comuna { code: "14201", name: "La Unión" },
+    Comuna { code: "14202", name: "Futrono" },
+    Comuna { code: "14203", name: "Lago Ranco" },
+    Comuna { code: "14204", name: "Río Bueno" },
+    Comuna { code: "15101", name: "Arica" },
+    Comuna { code: "15102", name: "Camarones" },
+    Comuna { code: "15201", name: "Putre" },
+    Comuna { code: "15202", name: "General Lagos" },
+    Comuna { code: "16101", name: "Chillán" },
+    Comuna { code: "16102", name: "Bulnes" },
+    Comuna { code: "16103", name: "Chillán Viejo" },
+    Comuna { code: "16104", name: "El Carmen" },
+    Comuna { code: "16105", name: "Pemuco" },
+    Comuna { code: "16106", name: "Pinto" },
+    Comuna { code: "16107", name: "Quillón" },
+    Comuna { code: "16108", name: "San Ignacio" },
+    Comuna { code: "16109", name: "Yungay" },
+    Comuna { code: "16201", name: "Quirihue" },
+    Comuna { code: "16202", name: "Cobquecura" },
+    Comuna { code: "16203", name: "Coelemu" },
+    Comuna { code: "16204", name: "Ninhue" },
+    Comuna { code: "16205", name: "Portezuelo" },
+    Comuna { code: "16206", name: "Ránquil" },
+    Comuna { code: "16207", name: "Trehuaco" },
+    Comuna { code: "16301", name: "San Carlos" },
+    Comuna { code: "16302", name: "Coihueco" },
+    Comuna { code: "16303", name: "Ñiquén" },
+    Comuna { code: "16304", name: "San Fabián" },
+    Comuna { code: "16305", name: "San Nicolás" },
+];
+
+
+/// General postal code of the main comunas, as assigned by Correos de
+/// Chile. Each entry covers the 10,000 codes sharing the first three
+/// digits of the general code.
+///
+/// This table is not exhaustive: load the full dataset of Correos de Chile
+/// with [`crate::postal::PostalDirectory::load_csv`] for complete coverage.
+pub static POSTAL_RANGES: [PostalRange; 51] = [
+    PostalRange { start: 1_000_000, end: 1_009_999, comuna: "15101" },
+    PostalRange { start: 1_100_000, end: 1_109_999, comuna: "01101" },
+    PostalRange { start: 1_130_000, end: 1_139_999, comuna: "01107" },
+    PostalRange { start: 1_240_000, end: 1_249_999, comuna: "02101" },
+    PostalRange { start: 1_340_000, end: 1_349_999, comuna: "02301" },
+    PostalRange { start: 1_390_000, end: 1_399_999, comuna: "02201" },
+    PostalRange { start: 1_530_000, end: 1_539_999, comuna: "03101" },
+    PostalRange { start: 1_610_000, end: 1_619_999, comuna: "03301" },
+    PostalRange { start: 1_700_000, end: 1_709_999, comuna: "04101" },
+    PostalRange { start: 1_780_000, end: 1_789_999, comuna: "04102" },
+    PostalRange { start: 1_840_000, end: 1_849_999, comuna: "04301" },
+    PostalRange { start: 2_100_000, end: 2_109_999, comuna: "05301" },
+    PostalRange { start: 2_170_000, end: 2_179_999, comuna: "05701" },
+    PostalRange { start: 2_260_000, end: 2_269_999, comuna: "05501" },
+    PostalRange { start: 2_340_000, end: 2_349_999, comuna: "05101" },
+    PostalRange { start: 2_430_000, end: 2_439_999, comuna: "05801" },
+    PostalRange { start: 2_520_000, end: 2_529_999, comuna: "05109" },
+    PostalRange { start: 2_660_000, end: 2_669_999, comuna: "05601" },
+    PostalRange { start: 2_820_000, end: 2_829_999, comuna: "06101" },
+    PostalRange { start: 3_070_000, end: 3_079_999, comuna: "06301" },
+    PostalRange { start: 3_340_000, end: 3_349_999, comuna: "07301" },
+    PostalRange { start: 3_460_000, end: 3_469_999, comuna: "07101" },
+    PostalRange { start: 3_580_000, end: 3_589_999, comuna: "07401" },
+    PostalRange { start: 3_780_000, end: 3_789_999, comuna: "16101" },
+    PostalRange { start: 4_030_000, end: 4_039_999, comuna: "08101" },
+    PostalRange { start: 4_260_000, end: 4_269_999, comuna: "08110" },
+    PostalRange { start: 4_440_000, end: 4_449_999, comuna: "08301" },
+    PostalRange { start: 4_780_000, end: 4_789_999, comuna: "09101" },
+    PostalRange { start: 5_090_000, end: 5_099_999, comuna: "14101" },
+    PostalRange { start: 5_290_000, end: 5_299_999, comuna: "10301" },
+    PostalRange { start: 5_480_000, end: 5_489_999, comuna: "10101" },
+    PostalRange { start: 5_700_000, end: 5_709_999, comuna: "10201" },
+    PostalRange { start: 5_950_000, end: 5_959_999, comuna: "11101" },
+    PostalRange { start: 6_200_000, end: 6_209_999, comuna: "12101" },
+    PostalRange { start: 7_500_000, end: 7_509_999, comuna: "13123" },
+    PostalRange { start: 7_550_000, end: 7_559_999, comuna: "13114" },
+    PostalRange { start: 7_630_000, end: 7_639_999, comuna: "13132" },
+    PostalRange { start: 7_690_000, end: 7_699_999, comuna: "13115" },
+    PostalRange { start: 7_750_000, end: 7_759_999, comuna: "13120" },
+    PostalRange { start: 7_810_000, end: 7_819_999, comuna: "13118" },
+    PostalRange { start: 7_850_000, end: 7_859_999, comuna: "13113" },
+    PostalRange { start: 8_050_000, end: 8_059_999, comuna: "13401" },
+    PostalRange { start: 8_150_000, end: 8_159_999, comuna: "13201" },
+    PostalRange { start: 8_240_000, end: 8_249_999, comuna: "13110" },
+    PostalRange { start: 8_320_000, end: 8_329_999, comuna: "13101" },
+    PostalRange { start: 8_380_000, end: 8_389_999, comuna: "13108" },
+    PostalRange { start: 8_420_000, end: 8_429_999, comuna: "13127" },
+    PostalRange { start: 8_700_000, end: 8_709_999, comuna: "13125" },
+    PostalRange { start: 8_900_000, end: 8_909_999, comuna: "13130" },
+    PostalRange { start: 9_160_000, end: 9_169_999, comuna: "13106" },
+    PostalRange { start: 9_250_000, end: 9_259_999, comuna: "13119" },
+];
\ No newline at end of file
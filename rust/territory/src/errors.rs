@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum TerritoryError {
+    #[error("Unknown region: '{query}'.")]
+    UnknownRegion { query: String },
+
+    #[error("Unknown province: '{query}'.")]
+    UnknownProvince { query: String },
+
+    #[error("Unknown comuna: '{query}'.")]
+    UnknownComuna { query: String },
+}
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum PostalError {
+    #[error("Postal code must have 7 digits and not start with '0', but got '{code}'.")]
+    InvalidPostalCode { code: String },
+
+    #[error("Unknown postal code: '{code}'.")]
+    UnknownPostalCode { code: String },
+
+    #[error("Invalid postal code record at line {line}: '{record}'.")]
+    InvalidRecord { line: usize, record: String },
+
+    #[error("Unknown comuna '{comuna}' at line {line}.")]
+    UnknownComuna { line: usize, comuna: String },
+
+    #[error("Could not read postal code dataset '{path}': {reason}.")]
+    ReadError { path: String, reason: String },
+}
@@ -0,0 +1,359 @@
+pub mod constants;
+pub mod utils;
+pub mod errors;
+pub mod registry;
+pub mod postal;
+
+use pyo3::prelude::*;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+
+use crate::errors::TerritoryError;
+use crate::errors::PostalError;
+
+
+create_exception!(
+    rs_territory, TerritoryException, PyException,
+    "Base class for all exceptions raised by the territory registry."
+);
+create_exception!(
+    rs_territory, UnknownRegion, TerritoryException,
+    "Raised when no region matches the given query."
+);
+create_exception!(
+    rs_territory, UnknownProvince, TerritoryException,
+    "Raised when no province matches the given query."
+);
+create_exception!(
+    rs_territory, UnknownComuna, TerritoryException,
+    "Raised when no comuna matches the given query."
+);
+
+create_exception!(
+    rs_territory, PostalException, PyException,
+    "Base class for all exceptions raised by the postal code directory."
+);
+create_exception!(
+    rs_territory, InvalidPostalCode, PostalException,
+    "Raised when the given postal code is invalid."
+);
+create_exception!(
+    rs_territory, UnknownPostalCode, PostalException,
+    "Raised when the given postal code is not in the directory."
+);
+create_exception!(
+    rs_territory, InvalidPostalRecord, PostalException,
+    "Raised when a record of a postal code dataset is invalid."
+);
+create_exception!(
+    rs_territory, UnknownPostalComuna, PostalException,
+    "Raised when a record of a postal code dataset has an unknown comuna."
+);
+create_exception!(
+    rs_territory, PostalReadError, PostalException,
+    "Raised when a postal code dataset cannot be read."
+);
+
+
+impl From<TerritoryError> for PyErr {
+    fn from(err: TerritoryError) -> PyErr {
+        match err {
+            TerritoryError::UnknownRegion { .. } => UnknownRegion::new_err(err.to_string()),
+            TerritoryError::UnknownProvince { .. } => UnknownProvince::new_err(err.to_string()),
+            TerritoryError::UnknownComuna { .. } => UnknownComuna::new_err(err.to_string()),
+        }
+    }
+}
+
+impl From<PostalError> for PyErr {
+    fn from(err: PostalError) -> PyErr {
+        match err {
+            PostalError::InvalidPostalCode { .. } => InvalidPostalCode::new_err(err.to_string()),
+            PostalError::UnknownPostalCode { .. } => UnknownPostalCode::new_err(err.to_string()),
+            PostalError::InvalidRecord { .. } => InvalidPostalRecord::new_err(err.to_string()),
+            PostalError::UnknownComuna { .. } => UnknownPostalComuna::new_err(err.to_string()),
+            PostalError::ReadError { .. } => PostalReadError::new_err(err.to_string()),
+        }
+    }
+}
+
+
+#[pyclass(frozen, eq, ord, hash)]
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Region {
+    inner: &'static registry::Region,
+}
+
+#[pymethods]
+impl Region {
+    /// Create a new `Region` instance.
+    #[new]
+    fn new(query: &str) -> PyResult<Self> {
+        let inner = registry::get_region(query)?;
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn code(&self) -> &'static str {
+        self.inner.code
+    }
+
+    #[getter]
+    fn roman(&self) -> &'static str {
+        self.inner.roman
+    }
+
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.inner.name
+    }
+
+    #[getter]
+    fn order(&self) -> u8 {
+        self.inner.order
+    }
+
+    fn provinces(&self) -> Vec<Province> {
+        self.inner
+            .provinces()
+            .into_iter()
+            .map(|inner| Province { inner })
+            .collect()
+    }
+
+    fn comunas(&self) -> Vec<Comuna> {
+        self.inner
+            .comunas()
+            .into_iter()
+            .map(|inner| Comuna { inner })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Region(code='{}', name='{}')", self.inner.code, self.inner.name)
+    }
+}
+
+
+#[pyclass(frozen, eq, hash)]
+#[derive(PartialEq, Eq, Hash)]
+struct Province {
+    inner: &'static registry::Province,
+}
+
+#[pymethods]
+impl Province {
+    /// Create a new `Province` instance.
+    #[new]
+    fn new(query: &str) -> PyResult<Self> {
+        let inner = registry::get_province(query)?;
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn code(&self) -> &'static str {
+        self.inner.code
+    }
+
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.inner.name
+    }
+
+    #[getter]
+    fn region(&self) -> Region {
+        Region { inner: self.inner.region() }
+    }
+
+    fn comunas(&self) -> Vec<Comuna> {
+        self.inner
+            .comunas()
+            .into_iter()
+            .map(|inner| Comuna { inner })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Province(code='{}', name='{}')", self.inner.code, self.inner.name)
+    }
+}
+
+
+#[pyclass(frozen, eq, hash)]
+#[derive(PartialEq, Eq, Hash)]
+struct Comuna {
+    inner: &'static registry::Comuna,
+}
+
+#[pymethods]
+impl Comuna {
+    /// Create a new `Comuna` instance.
+    #[new]
+    fn new(query: &str) -> PyResult<Self> {
+        let inner = registry::get_comuna(query)?;
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn code(&self) -> &'static str {
+        self.inner.code
+    }
+
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.inner.name
+    }
+
+    #[getter]
+    fn province(&self) -> Province {
+        Province { inner: self.inner.province() }
+    }
+
+    #[getter]
+    fn region(&self) -> Region {
+        Region { inner: self.inner.region() }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Comuna(code='{}', name='{}')", self.inner.code, self.inner.name)
+    }
+}
+
+
+#[pyclass]
+struct PostalDirectory {
+    inner: postal::PostalDirectory,
+}
+
+#[pymethods]
+impl PostalDirectory {
+    /// Create a new `PostalDirectory` instance.
+    #[new]
+    #[pyo3(signature = (embedded=true))]
+    fn new(embedded: bool) -> Self {
+        let inner = if embedded {
+            postal::PostalDirectory::embedded()
+        } else {
+            postal::PostalDirectory::new()
+        };
+        Self { inner }
+    }
+
+    fn load_csv(&mut self, data: &str) -> PyResult<usize> {
+        match self.inner.load_csv(data) {
+            Ok(count) => Ok(count),
+            Err(msg) => Err(msg.into()),
+        }
+    }
+
+    fn load_file(&mut self, path: &str) -> PyResult<usize> {
+        match self.inner.load_file(path) {
+            Ok(count) => Ok(count),
+            Err(msg) => Err(msg.into()),
+        }
+    }
+
+    fn lookup(&self, code: &str) -> PyResult<Comuna> {
+        match self.inner.lookup(code) {
+            Ok(inner) => Ok(Comuna { inner }),
+            Err(msg) => Err(msg.into()),
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PostalDirectory(ranges={})", self.inner.len())
+    }
+}
+
+
+#[pyfunction]
+fn normalize_postal_code(code: &str) -> PyResult<String> {
+    match postal::normalize_postal_code(code) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn validate_postal_code(code: &str) -> bool {
+    postal::validate_postal_code(code)
+}
+
+
+#[pyfunction]
+fn get_postal_comuna(code: &str) -> PyResult<Comuna> {
+    match postal::get_postal_comuna(code) {
+        Ok(inner) => Ok(Comuna { inner }),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn get_regions() -> Vec<Region> {
+    registry::get_regions()
+        .into_iter()
+        .map(|inner| Region { inner })
+        .collect()
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (query, limit=5))]
+fn search_comunas(query: &str, limit: usize) -> Vec<(Comuna, f64)> {
+    registry::search_comunas(query, limit)
+        .into_iter()
+        .map(|(inner, score)| (Comuna { inner }, score))
+        .collect()
+}
+
+
+#[pyfunction]
+#[pyo3(signature = (query, min_score=0.8))]
+fn match_comuna(query: &str, min_score: f64) -> PyResult<Comuna> {
+    match registry::match_comuna(query, min_score) {
+        Ok(inner) => Ok(Comuna { inner }),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn normalize_name(name: &str) -> String {
+    utils::normalize_name(name)
+}
+
+
+#[pymodule]
+pub fn rs_territory(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(get_regions, m)?)?;
+    m.add_function(wrap_pyfunction!(search_comunas, m)?)?;
+    m.add_function(wrap_pyfunction!(match_comuna, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_name, m)?)?;
+    m.add_class::<Region>()?;
+    m.add_class::<Province>()?;
+    m.add_class::<Comuna>()?;
+    m.add_function(wrap_pyfunction!(normalize_postal_code, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_postal_code, m)?)?;
+    m.add_function(wrap_pyfunction!(get_postal_comuna, m)?)?;
+    m.add_class::<PostalDirectory>()?;
+
+    m.add("TerritoryException", m.py().get_type::<TerritoryException>())?;
+    m.add("UnknownRegion", m.py().get_type::<UnknownRegion>())?;
+    m.add("UnknownProvince", m.py().get_type::<UnknownProvince>())?;
+    m.add("UnknownComuna", m.py().get_type::<UnknownComuna>())?;
+
+    m.add("PostalException", m.py().get_type::<PostalException>())?;
+    m.add("InvalidPostalCode", m.py().get_type::<InvalidPostalCode>())?;
+    m.add("UnknownPostalCode", m.py().get_type::<UnknownPostalCode>())?;
+    m.add("InvalidPostalRecord", m.py().get_type::<InvalidPostalRecord>())?;
+    m.add("UnknownPostalComuna", m.py().get_type::<UnknownPostalComuna>())?;
+    m.add("PostalReadError", m.py().get_type::<PostalReadError>())?;
+
+    Ok(())
+}
@@ -0,0 +1,267 @@
+use std::fs;
+use std::path::Path;
+
+use crate::errors::PostalError;
+use crate::registry::Comuna;
+use crate::registry::get_comuna;
+use crate::constants::POSTAL_RANGES;
+
+
+/// A range of postal codes assigned to a comuna.
+///
+/// # Examples
+/// ```
+/// use territory::postal::PostalRange;
+///
+/// let range = PostalRange { start: 8_320_000, end: 8_329_999, comuna: "13101" };
+/// assert!(range.contains(8_320_123));
+/// assert!(!range.contains(8_330_000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostalRange {
+    /// The first postal code of the range.
+    pub start: u32,
+    /// The last postal code of the range (inclusive).
+    pub end: u32,
+    /// The CUT code of the comuna.
+    pub comuna: &'static str,
+}
+
+/// Implements methods for the [`PostalRange`] struct.
+impl PostalRange {
+    /// Returns whether the given postal code is in the range.
+    pub fn contains(&self, code: u32) -> bool {
+        (self.start..=self.end).contains(&code)
+    }
+}
+
+
+/// A directory of postal codes, used to look up the comuna of a postal
+/// code.
+///
+/// # Examples
+/// ```
+/// use territory::postal::PostalDirectory;
+///
+/// let mut directory = PostalDirectory::embedded();
+/// assert_eq!(directory.lookup("8320000").unwrap().name, "Santiago");
+///
+/// // Loaded records take precedence over broader ranges.
+/// directory.load_csv("postal_code,comuna\n8320123,Providencia").unwrap();
+/// assert_eq!(directory.lookup("8320123").unwrap().name, "Providencia");
+/// assert_eq!(directory.lookup("8320124").unwrap().name, "Santiago");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PostalDirectory {
+    ranges: Vec<PostalRange>,
+}
+
+/// Implements methods for the [`PostalDirectory`] struct.
+impl PostalDirectory {
+    /// Creates an empty directory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a directory with the embedded dataset, see [`POSTAL_RANGES`].
+    pub fn embedded() -> Self {
+        Self { ranges: POSTAL_RANGES.to_vec() }
+    }
+
+    /// Returns the number of ranges in the directory.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns whether the directory has no ranges.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Loads postal code records from CSV data.
+    ///
+    /// # Arguments
+    /// * `data` - A reference to a string slice containing the records.
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - The number of loaded records.
+    /// * `Err(PostalError)`:
+    ///   - [`PostalError::InvalidRecord`] - If a record has an invalid
+    ///     number of fields or an invalid postal code.
+    ///   - [`PostalError::UnknownComuna`] - If the comuna of a record is
+    ///     not in the registry.
+    ///
+    /// # Behavior
+    /// Each line is either `postal_code,comuna` or `start,end,comuna`,
+    /// separated by `,` or `;`. The comuna can be given by CUT code or
+    /// name, see [`get_comuna`]. Empty lines, lines starting with `#` and
+    /// a header line are skipped.
+    ///
+    /// Records are only added if the whole data is valid. When looking up
+    /// a postal code, the narrowest matching range wins, so exact codes
+    /// take precedence over the embedded ranges.
+    pub fn load_csv(&mut self, data: &str) -> Result<usize, PostalError> {
+        let mut ranges = Vec::new();
+        let mut first = true;
+
+        for (i, line) in data.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split([',', ';']).map(str::trim).collect();
+            let invalid = || PostalError::InvalidRecord { line: i + 1, record: line.to_string() };
+
+            // The header, if any, is the first line not starting with a digit.
+            if std::mem::take(&mut first) && !fields[0].starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+
+            let (start, end, comuna) = match fields[..] {
+                [code, comuna] => (code, code, comuna),
+                [start, end, comuna] => (start, end, comuna),
+                _ => return Err(invalid()),
+            };
+
+            let start = parse_postal_code(start).map_err(|_| invalid())?;
+            let end = parse_postal_code(end).map_err(|_| invalid())?;
+
+            if start > end {
+                return Err(invalid());
+            }
+
+            let comuna = get_comuna(comuna)
+                .map_err(|_| PostalError::UnknownComuna { line: i + 1, comuna: comuna.to_string() })?;
+
+            ranges.push(PostalRange { start, end, comuna: comuna.code });
+        }
+
+        let count = ranges.len();
+        self.ranges.extend(ranges);
+        Ok(count)
+    }
+
+    /// Loads postal code records from a CSV file. See
+    /// [`PostalDirectory::load_csv`].
+    ///
+    /// # Errors
+    /// * [`PostalError::ReadError`] - If the file cannot be read.
+    /// * Any error of [`PostalDirectory::load_csv`].
+    pub fn load_file<P: AsRef<Path>>(&mut self, path: P) -> Result<usize, PostalError> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path).map_err(|e| PostalError::ReadError {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+
+        self.load_csv(&data)
+    }
+
+    /// Looks up the comuna of a postal code.
+    ///
+    /// # Arguments
+    /// * `code` - A reference to a string slice containing the postal code.
+    ///
+    /// # Returns
+    /// * `Ok(&Comuna)` - The comuna of the narrowest range containing the
+    ///   code. Among ranges of equal width, the last loaded wins.
+    /// * `Err(PostalError)`:
+    ///   - [`PostalError::InvalidPostalCode`] - See [`normalize_postal_code`].
+    ///   - [`PostalError::UnknownPostalCode`] - If no range contains the
+    ///     code.
+    pub fn lookup(&self, code: &str) -> Result<&'static Comuna, PostalError> {
+        find_comuna(&self.ranges, code)
+    }
+}
+
+
+/// Parses a postal code into its numeric value.
+fn parse_postal_code(code: &str) -> Result<u32, PostalError> {
+    let invalid = || PostalError::InvalidPostalCode { code: code.to_string() };
+    let digits: String = code.trim().chars().filter(|c| !matches!(c, ' ' | '-')).collect();
+
+    if digits.len() != 7 || digits.starts_with('0') || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    digits.parse().map_err(|_| invalid())
+}
+
+
+/// Finds the comuna of the narrowest range containing a postal code.
+fn find_comuna(ranges: &[PostalRange], code: &str) -> Result<&'static Comuna, PostalError> {
+    let number = parse_postal_code(code)?;
+
+    let range = ranges
+        .iter()
+        .rev()
+        .filter(|r| r.contains(number))
+        .min_by_key(|r| r.end - r.start)
+        .ok_or_else(|| PostalError::UnknownPostalCode { code: code.to_string() })?;
+
+    // Comunas were validated when the range was created.
+    Ok(get_comuna(range.comuna).unwrap())
+}
+
+
+/// Normalizes a Chilean postal code.
+///
+/// # Arguments
+/// * `code` - A reference to a string slice containing the postal code.
+///
+/// # Returns
+/// * `Ok(String)` - The 7 digits of the postal code.
+/// * `Err(PostalError::InvalidPostalCode)` - If the code, without spaces
+///   nor hyphens, does not have exactly 7 digits or starts with `'0'`.
+///
+/// # Examples
+/// ```
+/// use territory::postal::normalize_postal_code;
+///
+/// assert_eq!(normalize_postal_code(" 832 0000 ").unwrap(), "8320000");
+/// assert!(normalize_postal_code("832000").is_err());
+/// assert!(normalize_postal_code("0320000").is_err());
+/// ```
+pub fn normalize_postal_code(code: &str) -> Result<String, PostalError> {
+    Ok(parse_postal_code(code)?.to_string())
+}
+
+
+/// Validates a Chilean postal code. See [`normalize_postal_code`].
+///
+/// # Examples
+/// ```
+/// use territory::postal::validate_postal_code;
+///
+/// assert!(validate_postal_code("7500000"));
+/// assert!(!validate_postal_code("75000A0"));
+/// ```
+pub fn validate_postal_code(code: &str) -> bool {
+    parse_postal_code(code).is_ok()
+}
+
+
+/// Gets the comuna of a postal code from the embedded dataset, see
+/// [`POSTAL_RANGES`].
+///
+/// # Arguments
+/// * `code` - A reference to a string slice containing the postal code.
+///
+/// # Returns
+/// * `Ok(&Comuna)` - The comuna of the postal code.
+/// * `Err(PostalError)` - See [`PostalDirectory::lookup`].
+///
+/// # Examples
+/// ```
+/// use territory::postal::get_postal_comuna;
+///
+/// let comuna = get_postal_comuna("2520000").unwrap();
+/// assert_eq!(comuna.code, "05109");
+/// assert_eq!(comuna.name, "Viña del Mar");
+/// assert!(get_postal_comuna("9990000").is_err());
+/// ```
+pub fn get_postal_comuna(code: &str) -> Result<&'static Comuna, PostalError> {
+    find_comuna(&POSTAL_RANGES, code)
+}
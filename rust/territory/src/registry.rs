@@ -0,0 +1,343 @@
+use std::cmp::Ordering;
+
+use crate::errors::TerritoryError;
+use crate::utils::similarity;
+use crate::utils::normalize_name;
+use crate::constants::REGIONS;
+use crate::constants::COMUNAS;
+use crate::constants::PROVINCES;
+
+
+/// Represents a region of Chile.
+///
+/// Regions are ordered by their geographic position, from north to south,
+/// and not by their CUT code.
+///
+/// # Examples
+/// ```
+/// use territory::registry::get_region;
+///
+/// let region = get_region("RM").unwrap();
+/// assert_eq!(region.code, "13");
+/// assert_eq!(region.name, "Metropolitana de Santiago");
+/// assert_eq!(region.provinces().len(), 6);
+/// assert_eq!(region.comunas().len(), 52);
+///
+/// // Arica y Parinacota (15) is north of Tarapacá (01).
+/// assert!(get_region("15").unwrap() < get_region("01").unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region {
+    /// The CUT code of the region, e.g. `"13"`.
+    pub code: &'static str,
+    /// The roman numeral of the region, e.g. `"XV"`, or `"RM"` for the
+    /// Metropolitan region.
+    pub roman: &'static str,
+    /// The official name of the region.
+    pub name: &'static str,
+    /// The geographic position of the region, from `1` (north) to `16`
+    /// (south).
+    pub order: u8,
+}
+
+/// Implements methods for the [`Region`] struct.
+impl Region {
+    /// Returns the provinces of the region, by CUT code.
+    pub fn provinces(&self) -> Vec<&'static Province> {
+        PROVINCES
+            .iter()
+            .filter(|p| p.code.starts_with(self.code))
+            .collect()
+    }
+
+    /// Returns the comunas of the region, by CUT code.
+    pub fn comunas(&self) -> Vec<&'static Comuna> {
+        COMUNAS
+            .iter()
+            .filter(|c| c.code.starts_with(self.code))
+            .collect()
+    }
+}
+
+impl PartialOrd for Region {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Region {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.order.cmp(&other.order)
+    }
+}
+
+
+/// Represents a province of Chile.
+///
+/// # Examples
+/// ```
+/// use territory::registry::get_province;
+///
+/// let province = get_province("Cordillera").unwrap();
+/// assert_eq!(province.code, "132");
+/// assert_eq!(province.region().code, "13");
+/// assert_eq!(province.comunas().len(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Province {
+    /// The CUT code of the province, e.g. `"132"`.
+    pub code: &'static str,
+    /// The official name of the province.
+    pub name: &'static str,
+}
+
+/// Implements methods for the [`Province`] struct.
+impl Province {
+    /// Returns the region of the province.
+    pub fn region(&self) -> &'static Region {
+        // Every province code starts with the code of an embedded region.
+        REGIONS.iter().find(|r| self.code.starts_with(r.code)).unwrap()
+    }
+
+    /// Returns the comunas of the province, by CUT code.
+    pub fn comunas(&self) -> Vec<&'static Comuna> {
+        COMUNAS
+            .iter()
+            .filter(|c| c.code.starts_with(self.code))
+            .collect()
+    }
+}
+
+
+/// Represents a comuna of Chile.
+///
+/// # Examples
+/// ```
+/// use territory::registry::get_comuna;
+///
+/// let comuna = get_comuna("nunoa").unwrap();
+/// assert_eq!(comuna.code, "13120");
+/// assert_eq!(comuna.name, "Ñuñoa");
+/// assert_eq!(comuna.province().name, "Santiago");
+/// assert_eq!(comuna.region().roman, "RM");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Comuna {
+    /// The CUT code of the comuna, e.g. `"13120"`.
+    pub code: &'static str,
+    /// The official name of the comuna.
+    pub name: &'static str,
+}
+
+/// Implements methods for the [`Comuna`] struct.
+impl Comuna {
+    /// Returns the province of the comuna.
+    pub fn province(&self) -> &'static Province {
+        // Every comuna code starts with the code of an embedded province.
+        PROVINCES.iter().find(|p| self.code.starts_with(p.code)).unwrap()
+    }
+
+    /// Returns the region of the comuna.
+    pub fn region(&self) -> &'static Region {
+        self.province().region()
+    }
+}
+
+
+/// Left-pads a numeric CUT code with zeros, since codes are often stored
+/// as integers (e.g. `1101` for Iquique, `"01101"`).
+fn pad_code(query: &str, width: usize) -> Option<String> {
+    if query.is_empty() || query.len() > width || !query.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(format!("{:0>width$}", query))
+}
+
+
+/// Gets all the regions of Chile, from north to south.
+///
+/// # Examples
+/// ```
+/// use territory::registry::get_regions;
+///
+/// let regions = get_regions();
+/// assert_eq!(regions.len(), 16);
+/// assert_eq!(regions[0].name, "Arica y Parinacota");
+/// assert_eq!(regions[15].roman, "XII");
+/// ```
+pub fn get_regions() -> Vec<&'static Region> {
+    let mut regions: Vec<_> = REGIONS.iter().collect();
+    regions.sort();
+    regions
+}
+
+
+/// Gets a region by CUT code, roman numeral or name.
+///
+/// # Arguments
+/// * `query` - A reference to a string slice containing the CUT code
+///   (e.g. `"5"` or `"05"`), the roman numeral (e.g. `"V"`, or `"RM"` for
+///   the Metropolitan region) or the official name of the region.
+///
+/// # Returns
+/// * `Ok(&Region)` - The matching region.
+/// * `Err(TerritoryError::UnknownRegion)` - If no region matches.
+///
+/// # Behavior
+/// Roman numerals and names are compared with [`normalize_name`], so case
+/// and accents are ignored.
+///
+/// # Examples
+/// ```
+/// use territory::registry::get_region;
+///
+/// assert_eq!(get_region("5").unwrap().name, "Valparaíso");
+/// assert_eq!(get_region("xvi").unwrap().name, "Ñuble");
+/// assert_eq!(get_region("LOS RIOS").unwrap().code, "14");
+/// assert!(get_region("17").is_err());
+/// ```
+pub fn get_region(query: &str) -> Result<&'static Region, TerritoryError> {
+    let query = query.trim();
+    let code = pad_code(query, 2);
+    let name = normalize_name(query);
+
+    REGIONS
+        .iter()
+        .find(|r| {
+            code.as_deref() == Some(r.code)
+                || normalize_name(r.roman) == name
+                || normalize_name(r.name) == name
+        })
+        .ok_or_else(|| TerritoryError::UnknownRegion { query: query.to_string() })
+}
+
+
+/// Gets a province by CUT code or name.
+///
+/// # Arguments
+/// * `query` - A reference to a string slice containing the CUT code
+///   (e.g. `"51"` or `"051"`) or the name of the province.
+///
+/// # Returns
+/// * `Ok(&Province)` - The matching province.
+/// * `Err(TerritoryError::UnknownProvince)` - If no province matches.
+///
+/// # Examples
+/// ```
+/// use territory::registry::get_province;
+///
+/// assert_eq!(get_province("51").unwrap().name, "Valparaíso");
+/// assert_eq!(get_province("ultima esperanza").unwrap().code, "124");
+/// assert!(get_province("Ñuble").is_err());
+/// ```
+pub fn get_province(query: &str) -> Result<&'static Province, TerritoryError> {
+    let query = query.trim();
+    let code = pad_code(query, 3);
+    let name = normalize_name(query);
+
+    PROVINCES
+        .iter()
+        .find(|p| code.as_deref() == Some(p.code) || normalize_name(p.name) == name)
+        .ok_or_else(|| TerritoryError::UnknownProvince { query: query.to_string() })
+}
+
+
+/// Gets a comuna by CUT code or name.
+///
+/// # Arguments
+/// * `query` - A reference to a string slice containing the CUT code
+///   (e.g. `"1101"` or `"01101"`) or the name of the comuna.
+///
+/// # Returns
+/// * `Ok(&Comuna)` - The matching comuna.
+/// * `Err(TerritoryError::UnknownComuna)` - If no comuna matches.
+///
+/// # Behavior
+/// Names are compared with [`normalize_name`]. For misspelled names, see
+/// [`match_comuna`].
+///
+/// # Examples
+/// ```
+/// use territory::registry::get_comuna;
+///
+/// assert_eq!(get_comuna("1101").unwrap().name, "Iquique");
+/// assert_eq!(get_comuna("VIÑA DEL MAR").unwrap().code, "05109");
+/// assert!(get_comuna("Vina del Mal").is_err());
+/// ```
+pub fn get_comuna(query: &str) -> Result<&'static Comuna, TerritoryError> {
+    let query = query.trim();
+    let code = pad_code(query, 5);
+    let name = normalize_name(query);
+
+    COMUNAS
+        .iter()
+        .find(|c| code.as_deref() == Some(c.code) || normalize_name(c.name) == name)
+        .ok_or_else(|| TerritoryError::UnknownComuna { query: query.to_string() })
+}
+
+
+/// Searches the comunas whose names are most similar to the given one.
+///
+/// # Arguments
+/// * `query` - A reference to a string slice containing the name.
+/// * `limit` - The maximum number of results.
+///
+/// # Returns
+/// Up to `limit` comunas with their [`similarity`] score, from the most to
+/// the least similar. Ties are sorted by CUT code.
+///
+/// # Examples
+/// ```
+/// use territory::registry::search_comunas;
+///
+/// let results = search_comunas("Pealolen", 3);
+/// assert_eq!(results.len(), 3);
+/// assert_eq!(results[0].0.name, "Peñalolén");
+/// assert!(results[0].1 > results[1].1);
+/// ```
+pub fn search_comunas(query: &str, limit: usize) -> Vec<(&'static Comuna, f64)> {
+    let mut results: Vec<_> = COMUNAS
+        .iter()
+        .map(|c| (c, similarity(query, c.name)))
+        .collect();
+
+    results.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.code.cmp(b.0.code)));
+    results.truncate(limit);
+    results
+}
+
+
+/// Matches a possibly misspelled comuna name, or a CUT code.
+///
+/// # Arguments
+/// * `query` - A reference to a string slice containing the CUT code or
+///   the name of the comuna.
+/// * `min_score` - The minimum [`similarity`] score, from `0.0` to `1.0`,
+///   for a name to match.
+///
+/// # Returns
+/// * `Ok(&Comuna)` - The comuna returned by [`get_comuna`] or, otherwise,
+///   the most similar one according to [`search_comunas`].
+/// * `Err(TerritoryError::UnknownComuna)` - If no comuna reaches
+///   `min_score`.
+///
+/// # Examples
+/// ```
+/// use territory::registry::match_comuna;
+///
+/// assert_eq!(match_comuna("Consepcion", 0.8).unwrap().code, "08101");
+/// assert_eq!(match_comuna("13101", 0.8).unwrap().name, "Santiago");
+/// assert!(match_comuna("Buenos Aires", 0.8).is_err());
+/// ```
+pub fn match_comuna(query: &str, min_score: f64) -> Result<&'static Comuna, TerritoryError> {
+    if let Ok(comuna) = get_comuna(query) {
+        return Ok(comuna);
+    }
+
+    search_comunas(query, 1)
+        .into_iter()
+        .find(|(_, score)| *score >= min_score)
+        .map(|(comuna, _)| comuna)
+        .ok_or_else(|| TerritoryError::UnknownComuna { query: query.trim().to_string() })
+}
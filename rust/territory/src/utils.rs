@@ -0,0 +1,100 @@
+/// Removes the diacritics used in Spanish from a lowercase character.
+fn strip_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'ä' | 'â' => 'a',
+        'é' | 'è' | 'ë' | 'ê' => 'e',
+        'í' | 'ì' | 'ï' | 'î' => 'i',
+        'ó' | 'ò' | 'ö' | 'ô' => 'o',
+        'ú' | 'ù' | 'ü' | 'û' => 'u',
+        'ñ' => 'n',
+        _ => c,
+    }
+}
+
+
+/// Normalizes a territory name for accent and case-insensitive comparison.
+///
+/// # Arguments
+/// * `name` - A reference to a string slice containing the name.
+///
+/// # Returns
+/// The name in lowercase, without diacritics nor apostrophes, with hyphens
+/// and other punctuation replaced by spaces, and whitespaces collapsed.
+///
+/// # Examples
+/// ```
+/// use territory::utils::normalize_name;
+///
+/// assert_eq!(normalize_name("  Ñuñoa "), "nunoa");
+/// assert_eq!(normalize_name("VIÑA  DEL MAR"), "vina del mar");
+/// assert_eq!(normalize_name("O'Higgins"), "ohiggins");
+/// assert_eq!(normalize_name("Llay-Llay"), "llay llay");
+/// ```
+pub fn normalize_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(strip_accent)
+        .filter(|c| !matches!(c, '\'' | '’' | '´' | '`'))
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+
+/// Computes the Levenshtein (edit) distance between two strings, in
+/// characters.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+
+/// Computes the similarity between two territory names.
+///
+/// # Arguments
+/// * `a` - A reference to a string slice containing the first name.
+/// * `b` - A reference to a string slice containing the second name.
+///
+/// # Returns
+/// A score from `0.0` (completely different) to `1.0` (equal after
+/// [`normalize_name`]).
+///
+/// # Algorithm
+/// Both names are normalized with [`normalize_name`]. The score is
+/// `1 - d / n`, where `d` is the Levenshtein distance between them and `n`
+/// the length of the longest one.
+///
+/// # Examples
+/// ```
+/// use territory::utils::similarity;
+///
+/// assert_eq!(similarity("Peñalolén", "penalolen"), 1.0);
+/// assert_eq!(similarity("Concepción", "Consepcion"), 0.9);
+/// assert_eq!(similarity("Arica", ""), 0.0);
+/// ```
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let a = normalize_name(a);
+    let b = normalize_name(b);
+    let len = a.chars().count().max(b.chars().count());
+
+    if len == 0 {
+        return 1.0;
+    }
+
+    1.0 - levenshtein(&a, &b) as f64 / len as f64
+}
@@ -0,0 +1,48 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use verify::utils;
+
+
+/// One PPU per supported format, as read from a camera feed.
+const PPUS: [&str; 4] = ["PHZ55", "PHZ123", "PHZF55", "BR1234"];
+
+
+fn bench_ppu_to_numeric(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ppu_to_numeric");
+
+    for ppu in PPUS {
+        group.bench_function(ppu, |b| {
+            b.iter(|| utils::ppu_to_numeric(black_box(ppu)))
+        });
+    }
+
+    group.finish();
+}
+
+
+fn bench_lookups(c: &mut Criterion) {
+    c.bench_function("get_letter_value", |b| {
+        b.iter(|| utils::get_letter_value(black_box("Z")))
+    });
+
+    // "DW" is the last entry of DIGRAPH_MAP.
+    c.bench_function("get_digraph_value", |b| {
+        b.iter(|| utils::get_digraph_value(black_box("DW")))
+    });
+}
+
+
+fn bench_verify(c: &mut Criterion) {
+    c.bench_function("ppu_verifier", |b| {
+        b.iter(|| {
+            let numeric = utils::ppu_to_numeric(black_box("PHZF55")).unwrap();
+            utils::calculate_verifier(numeric)
+        })
+    });
+}
+
+
+criterion_group!(benches, bench_ppu_to_numeric, bench_lookups, bench_verify);
+criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,155 @@
+use crate::utils::Rut;
+use crate::utils::calculate_verifier;
+use crate::errors::BankError;
+use crate::constants::BANKS;
+use crate::structs::Bank;
+
+
+/// Institution code of BancoEstado, the only bank offering CuentaRUT.
+pub const BANCO_ESTADO_CODE: &str = "012";
+
+
+/// Gets all the banks, by institution code.
+///
+/// # Examples
+/// ```
+/// use verify::banks::get_banks;
+///
+/// let banks = get_banks();
+/// assert_eq!(banks[0].name, "Banco de Chile");
+/// assert!(banks.windows(2).all(|w| w[0].code < w[1].code));
+/// ```
+pub fn get_banks() -> &'static [Bank] {
+    &BANKS
+}
+
+
+/// Gets a bank by institution code or name.
+///
+/// # Arguments
+/// * `query` - A reference to a string slice containing the institution
+///   code (e.g. `"1"` or `"001"`), the legal name or the commercial name of
+///   the bank.
+///
+/// # Returns
+/// * `Ok(&Bank)` - The matching bank.
+/// * `Err(BankError::UnknownBank)` - If no bank matches.
+///
+/// # Behavior
+/// Names are compared case-insensitively.
+///
+/// # Examples
+/// ```
+/// use verify::banks::get_bank;
+///
+/// assert_eq!(get_bank("1").unwrap().name, "Banco de Chile");
+/// assert_eq!(get_bank("bancoestado").unwrap().code, "012");
+/// assert_eq!(get_bank("Banco Santander-Chile").unwrap().short_name, "Santander");
+/// assert!(get_bank("999").is_err());
+/// ```
+pub fn get_bank(query: &str) -> Result<&'static Bank, BankError> {
+    let query = query.trim();
+    let is_code = !query.is_empty() && query.len() <= 3 && query.bytes().all(|b| b.is_ascii_digit());
+    let code = format!("{:0>3}", query);
+    let name = query.to_lowercase();
+
+    BANKS
+        .iter()
+        .find(|b| {
+            (is_code && b.code == code)
+                || b.name.to_lowercase() == name
+                || b.short_name.to_lowercase() == name
+        })
+        .ok_or_else(|| BankError::UnknownBank { query: query.to_string() })
+}
+
+
+/// Normalizes a bank account number.
+///
+/// # Arguments
+/// * `account` - A reference to a string slice containing the account
+///   number.
+///
+/// # Returns
+/// * `Ok(String)` - The digits of the account number, without separators
+///   (spaces, `-` and `.`).
+/// * `Err(BankError)`:
+///   - [`BankError::EmptyAccount`] - If the account number has no digits.
+///   - [`BankError::InvalidAccountCharacter`] - If the account number has
+///     characters other than digits and separators.
+///
+/// # Examples
+/// ```
+/// use verify::banks::normalize_account_number;
+///
+/// assert_eq!(normalize_account_number(" 00-123-45678-9 ").unwrap(), "00123456789");
+/// assert!(normalize_account_number("--").is_err());
+/// assert!(normalize_account_number("123A").is_err());
+/// ```
+pub fn normalize_account_number(account: &str) -> Result<String, BankError> {
+    let account = account.trim();
+    let mut digits = String::with_capacity(account.len());
+
+    for (i, c) in account.chars().enumerate() {
+        match c {
+            '0'..='9' => digits.push(c),
+            ' ' | '-' | '.' => {}
+            _ => return Err(BankError::InvalidAccountCharacter { character: c, position: i + 1 }),
+        }
+    }
+
+    if digits.is_empty() {
+        return Err(BankError::EmptyAccount);
+    }
+
+    Ok(digits)
+}
+
+
+/// Checks whether an account number is the CuentaRUT of a RUT.
+///
+/// # Arguments
+/// * `account` - A reference to a string slice containing the account
+///   number.
+/// * `rut` - A reference to the [`Rut`] of the account holder.
+///
+/// # Returns
+/// * `Ok(true)` - If the account number, without leading zeros, is the RUT
+///   without verifier.
+/// * `Ok(false)` - Otherwise.
+/// * `Err(BankError)`:
+///   - [`BankError::InvalidRut`] - If the verifier of `rut` is invalid.
+///   - [`BankError::Verifier`] - If the verifier cannot be computed.
+///   - See [`normalize_account_number`].
+///
+/// # Notes
+/// CuentaRUT accounts are only offered by BancoEstado (code
+/// [`BANCO_ESTADO_CODE`]).
+///
+/// # Examples
+/// ```
+/// use verify::utils::Rut;
+/// use verify::banks::validate_cuenta_rut;
+///
+/// let rut = Rut::new(12345678).unwrap();
+/// assert!(validate_cuenta_rut("12.345.678", &rut).unwrap());
+/// assert!(validate_cuenta_rut("0012345678", &rut).unwrap());
+/// assert!(!validate_cuenta_rut("12345679", &rut).unwrap());
+///
+/// let rut = Rut { correlative: 12345678, verifier: 'K' };
+/// assert!(validate_cuenta_rut("12345678", &rut).is_err());
+/// ```
+pub fn validate_cuenta_rut(account: &str, rut: &Rut) -> Result<bool, BankError> {
+    let expected = calculate_verifier(rut.correlative)?;
+
+    if expected != rut.verifier.to_ascii_uppercase() {
+        return Err(BankError::InvalidRut {
+            correlative: rut.correlative,
+            expected,
+            actual: rut.verifier,
+        });
+    }
+
+    let account = normalize_account_number(account)?;
+    Ok(account.trim_start_matches('0') == rut.correlative.to_string())
+}
@@ -1,3 +1,10 @@
+use chrono::Weekday;
+
+use crate::enums::PhoneKind;
+use crate::structs::Bank;
+use crate::structs::NumberingPlanEntry;
+use crate::structs::RestrictionRule;
+
 
 pub const LETTER_MAP: [(&str, &str); 18] = [
     ("B", "1"),
@@ -21,7 +28,7 @@ pub const LETTER_MAP: [(&str, &str); 18] = [
 ];
 
 
-pub const DIGRAPH_MAP: [(&str, &str); 582] = [
+pub static DIGRAPH_MAP: [(&str, &str); 582] = [
     ("AA", "001"),
     ("EA" ,"004"),
     ("HA", "007"),
@@ -604,4 +611,360 @@ pub const DIGRAPH_MAP: [(&str, &str); 582] = [
     ("CM", "576"),
     ("DC", "579"),
     ("DW", "582")
+];
+
+
+
+/// Sentinel of [`LETTER_INDEX`] and [`DIGRAPH_INDEX`] for entries out of
+/// the mapping.
+pub const NO_INDEX: u16 = u16::MAX;
+
+
+/// Position in [`LETTER_MAP`] of each letter, indexed by `letter - 'A'`.
+///
+/// Built at compile time so that letter lookups are a single array access.
+///
+/// # Examples
+/// ```
+/// use verify::constants::LETTER_MAP;
+/// use verify::constants::LETTER_INDEX;
+/// use verify::constants::NO_INDEX;
+///
+/// for (i, (letter, _)) in LETTER_MAP.iter().enumerate() {
+///     let key = letter.as_bytes()[0];
+///     assert_eq!(LETTER_INDEX[(key - b'A') as usize] as usize, i, "{letter}");
+/// }
+///
+/// let indexed = LETTER_INDEX.iter().filter(|&&i| i != NO_INDEX).count();
+/// assert_eq!(indexed, LETTER_MAP.len());
+/// ```
+pub static LETTER_INDEX: [u16; 26] = {
+    let mut table = [NO_INDEX; 26];
+    let mut i = 0;
+    while i < LETTER_MAP.len() {
+        let key = LETTER_MAP[i].0.as_bytes();
+        table[(key[0] - b'A') as usize] = i as u16;
+        i += 1;
+    }
+    table
+};
+
+
+/// Position in [`DIGRAPH_MAP`] of each digraph, indexed by
+/// `(first - 'A') * 26 + (second - 'A')`.
+///
+/// Built at compile time so that digraph lookups are a single array access.
+///
+/// # Examples
+/// ```
+/// use verify::constants::DIGRAPH_MAP;
+/// use verify::constants::DIGRAPH_INDEX;
+/// use verify::constants::NO_INDEX;
+///
+/// for (i, (digraph, _)) in DIGRAPH_MAP.iter().enumerate() {
+///     let key = digraph.as_bytes();
+///     let position = (key[0] - b'A') as usize * 26 + (key[1] - b'A') as usize;
+///     assert_eq!(DIGRAPH_INDEX[position] as usize, i, "{digraph}");
+/// }
+///
+/// let indexed = DIGRAPH_INDEX.iter().filter(|&&i| i != NO_INDEX).count();
+/// assert_eq!(indexed, DIGRAPH_MAP.len());
+/// ```
+pub static DIGRAPH_INDEX: [u16; 676] = {
+    let mut table = [NO_INDEX; 676];
+    let mut i = 0;
+    while i < DIGRAPH_MAP.len() {
+        let key = DIGRAPH_MAP[i].0.as_bytes();
+        table[(key[0] - b'A') as usize * 26 + (key[1] - b'A') as usize] = i as u16;
+        i += 1;
+    }
+    table
+};
+
+
+/// Position in [`DIGRAPH_MAP`] of each digraph value, indexed by the
+/// numeric value (e.g. `"001"` is index `1`).
+///
+/// Built at compile time so that numeric to digraph lookups are a single
+/// array access.
+pub static DIGRAPH_VALUE_INDEX: [u16; 1000] = {
+    let mut table = [NO_INDEX; 1000];
+    let mut i = 0;
+    while i < DIGRAPH_MAP.len() {
+        let value = DIGRAPH_MAP[i].1.as_bytes();
+        let mut numeric = 0;
+        let mut j = 0;
+        while j < value.len() {
+            numeric = numeric * 10 + (value[j] - b'0') as usize;
+            j += 1;
+        }
+        table[numeric] = i as u16;
+        i += 1;
+    }
+    table
+};
+
+
+/// Month (1-12) of the periodic technical inspection ("revisión técnica")
+/// for private vehicles, keyed by the last digit of the PPU.
+pub const INSPECTION_MONTH_MAP: [(u8, u32); 10] = [
+    (9, 1),
+    (0, 2),
+    (1, 4),
+    (2, 5),
+    (3, 6),
+    (4, 7),
+    (5, 8),
+    (6, 9),
+    (7, 10),
+    (8, 11),
+];
+
+
+/// Permanent driving restriction ("restricción vehicular") for vehicles
+/// without green seal in Santiago. Digits rotate every month on a four
+/// month cycle and apply from Monday to Friday.
+pub const RESTRICTION_RULES: [RestrictionRule; 20] = [
+    RestrictionRule { months: &[1, 5, 9], weekday: Weekday::Mon, digits: &[1, 2] },
+    RestrictionRule { months: &[1, 5, 9], weekday: Weekday::Tue, digits: &[3, 4] },
+    RestrictionRule { months: &[1, 5, 9], weekday: Weekday::Wed, digits: &[5, 6] },
+    RestrictionRule { months: &[1, 5, 9], weekday: Weekday::Thu, digits: &[7, 8] },
+    RestrictionRule { months: &[1, 5, 9], weekday: Weekday::Fri, digits: &[9, 0] },
+    RestrictionRule { months: &[2, 6, 10], weekday: Weekday::Mon, digits: &[3, 4] },
+    RestrictionRule { months: &[2, 6, 10], weekday: Weekday::Tue, digits: &[5, 6] },
+    RestrictionRule { months: &[2, 6, 10], weekday: Weekday::Wed, digits: &[7, 8] },
+    RestrictionRule { months: &[2, 6, 10], weekday: Weekday::Thu, digits: &[9, 0] },
+    RestrictionRule { months: &[2, 6, 10], weekday: Weekday::Fri, digits: &[1, 2] },
+    RestrictionRule { months: &[3, 7, 11], weekday: Weekday::Mon, digits: &[5, 6] },
+    RestrictionRule { months: &[3, 7, 11], weekday: Weekday::Tue, digits: &[7, 8] },
+    RestrictionRule { months: &[3, 7, 11], weekday: Weekday::Wed, digits: &[9, 0] },
+    RestrictionRule { months: &[3, 7, 11], weekday: Weekday::Thu, digits: &[1, 2] },
+    RestrictionRule { months: &[3, 7, 11], weekday: Weekday::Fri, digits: &[3, 4] },
+    RestrictionRule { months: &[4, 8, 12], weekday: Weekday::Mon, digits: &[7, 8] },
+    RestrictionRule { months: &[4, 8, 12], weekday: Weekday::Tue, digits: &[9, 0] },
+    RestrictionRule { months: &[4, 8, 12], weekday: Weekday::Wed, digits: &[1, 2] },
+    RestrictionRule { months: &[4, 8, 12], weekday: Weekday::Thu, digits: &[3, 4] },
+    RestrictionRule { months: &[4, 8, 12], weekday: Weekday::Fri, digits: &[5, 6] },
+];
+
+
+/// Manufacturer of common World Manufacturer Identifiers (WMI), the first
+/// three characters of a VIN.
+pub const WMI_MAP: [(&str, &str); 87] = [
+    ("1FA", "Ford"),
+    ("1FT", "Ford"),
+    ("1G1", "Chevrolet"),
+    ("1GC", "Chevrolet"),
+    ("1GN", "Chevrolet"),
+    ("1HG", "Honda"),
+    ("1J4", "Jeep"),
+    ("1N4", "Nissan"),
+    ("1VW", "Volkswagen"),
+    ("2HG", "Honda"),
+    ("2T1", "Toyota"),
+    ("3FA", "Ford"),
+    ("3N1", "Nissan"),
+    ("3VW", "Volkswagen"),
+    ("4T1", "Toyota"),
+    ("5NP", "Hyundai"),
+    ("5YJ", "Tesla"),
+    ("8A1", "Renault"),
+    ("8AF", "Ford"),
+    ("8AG", "Chevrolet"),
+    ("8AJ", "Toyota"),
+    ("8AP", "Fiat"),
+    ("935", "Citroën"),
+    ("936", "Peugeot"),
+    ("93H", "Honda"),
+    ("93Y", "Renault"),
+    ("9BD", "Fiat"),
+    ("9BG", "Chevrolet"),
+    ("9BH", "Hyundai"),
+    ("9BR", "Toyota"),
+    ("9BW", "Volkswagen"),
+    ("JF1", "Subaru"),
+    ("JHM", "Honda"),
+    ("JM1", "Mazda"),
+    ("JMB", "Mitsubishi"),
+    ("JN1", "Nissan"),
+    ("JS2", "Suzuki"),
+    ("JS3", "Suzuki"),
+    ("JT2", "Toyota"),
+    ("JTD", "Toyota"),
+    ("JTE", "Toyota"),
+    ("KL1", "Chevrolet"),
+    ("KLA", "Daewoo"),
+    ("KMH", "Hyundai"),
+    ("KNA", "Kia"),
+    ("KNM", "Renault Samsung"),
+    ("KPT", "SsangYong"),
+    ("LB3", "Geely"),
+    ("LGW", "Great Wall"),
+    ("LGX", "BYD"),
+    ("LRW", "Tesla"),
+    ("LS5", "Changan"),
+    ("LSJ", "MG"),
+    ("LVV", "Chery"),
+    ("LZW", "SAIC-GM-Wuling"),
+    ("MA1", "Mahindra"),
+    ("MA3", "Suzuki"),
+    ("MAL", "Hyundai"),
+    ("MAT", "Tata"),
+    ("MMB", "Mitsubishi"),
+    ("MNT", "Nissan"),
+    ("MR0", "Toyota"),
+    ("SAJ", "Jaguar"),
+    ("SAL", "Land Rover"),
+    ("TMB", "Škoda"),
+    ("TRU", "Audi"),
+    ("VF1", "Renault"),
+    ("VF3", "Peugeot"),
+    ("VF7", "Citroën"),
+    ("VNK", "Toyota"),
+    ("VSS", "SEAT"),
+    ("WAU", "Audi"),
+    ("WBA", "BMW"),
+    ("WDB", "Mercedes-Benz"),
+    ("WDD", "Mercedes-Benz"),
+    ("WF0", "Ford"),
+    ("WMW", "MINI"),
+    ("WP0", "Porsche"),
+    ("WV1", "Volkswagen"),
+    ("WV2", "Volkswagen"),
+    ("WVW", "Volkswagen"),
+    ("YS3", "Saab"),
+    ("YV1", "Volvo"),
+    ("ZAR", "Alfa Romeo"),
+    ("ZFA", "Fiat"),
+    ("ZFF", "Ferrari"),
+    ("ZHW", "Lamborghini"),
+];
+
+
+/// First model year (1980-2009) of each VIN model year character, the
+/// tenth character of a VIN. Codes repeat every 30 years.
+pub const MODEL_YEAR_MAP: [(char, u32); 30] = [
+    ('A', 1980),
+    ('B', 1981),
+    ('C', 1982),
+    ('D', 1983),
+    ('E', 1984),
+    ('F', 1985),
+    ('G', 1986),
+    ('H', 1987),
+    ('J', 1988),
+    ('K', 1989),
+    ('L', 1990),
+    ('M', 1991),
+    ('N', 1992),
+    ('P', 1993),
+    ('R', 1994),
+    ('S', 1995),
+    ('T', 1996),
+    ('V', 1997),
+    ('W', 1998),
+    ('X', 1999),
+    ('Y', 2000),
+    ('1', 2001),
+    ('2', 2002),
+    ('3', 2003),
+    ('4', 2004),
+    ('5', 2005),
+    ('6', 2006),
+    ('7', 2007),
+    ('8', 2008),
+    ('9', 2009),
+];
+
+
+/// Numeric value of each VIN letter used by the ISO 3779 check digit.
+pub const VIN_LETTER_MAP: [(char, u32); 23] = [
+    ('A', 1),
+    ('B', 2),
+    ('C', 3),
+    ('D', 4),
+    ('E', 5),
+    ('F', 6),
+    ('G', 7),
+    ('H', 8),
+    ('J', 1),
+    ('K', 2),
+    ('L', 3),
+    ('M', 4),
+    ('N', 5),
+    ('P', 7),
+    ('R', 9),
+    ('S', 2),
+    ('T', 3),
+    ('U', 4),
+    ('V', 5),
+    ('W', 6),
+    ('X', 7),
+    ('Y', 8),
+    ('Z', 9),
+];
+
+
+/// Weight of each VIN position used by the ISO 3779 check digit.
+pub const VIN_WEIGHTS: [u32; 17] = [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2];
+
+
+/// Chilean numbering plan, as published by Subtel. Since 2016, every
+/// number but special services has 9 digits, dialed without trunk prefix.
+/// Fixed numbers start with the area code of their zone.
+pub const NUMBERING_PLAN: [NumberingPlanEntry; 28] = [
+    NumberingPlanEntry { prefix: "9", kind: PhoneKind::Mobile, length: 9, description: "Mobile" },
+    NumberingPlanEntry { prefix: "2", kind: PhoneKind::Fixed, length: 9, description: "Santiago" },
+    NumberingPlanEntry { prefix: "32", kind: PhoneKind::Fixed, length: 9, description: "Valparaíso" },
+    NumberingPlanEntry { prefix: "33", kind: PhoneKind::Fixed, length: 9, description: "Quillota" },
+    NumberingPlanEntry { prefix: "34", kind: PhoneKind::Fixed, length: 9, description: "San Felipe" },
+    NumberingPlanEntry { prefix: "35", kind: PhoneKind::Fixed, length: 9, description: "San Antonio" },
+    NumberingPlanEntry { prefix: "41", kind: PhoneKind::Fixed, length: 9, description: "Concepción" },
+    NumberingPlanEntry { prefix: "42", kind: PhoneKind::Fixed, length: 9, description: "Chillán" },
+    NumberingPlanEntry { prefix: "43", kind: PhoneKind::Fixed, length: 9, description: "Los Ángeles" },
+    NumberingPlanEntry { prefix: "45", kind: PhoneKind::Fixed, length: 9, description: "Temuco" },
+    NumberingPlanEntry { prefix: "51", kind: PhoneKind::Fixed, length: 9, description: "La Serena" },
+    NumberingPlanEntry { prefix: "52", kind: PhoneKind::Fixed, length: 9, description: "Copiapó" },
+    NumberingPlanEntry { prefix: "53", kind: PhoneKind::Fixed, length: 9, description: "Ovalle" },
+    NumberingPlanEntry { prefix: "55", kind: PhoneKind::Fixed, length: 9, description: "Antofagasta" },
+    NumberingPlanEntry { prefix: "57", kind: PhoneKind::Fixed, length: 9, description: "Iquique" },
+    NumberingPlanEntry { prefix: "58", kind: PhoneKind::Fixed, length: 9, description: "Arica" },
+    NumberingPlanEntry { prefix: "61", kind: PhoneKind::Fixed, length: 9, description: "Punta Arenas" },
+    NumberingPlanEntry { prefix: "63", kind: PhoneKind::Fixed, length: 9, description: "Valdivia" },
+    NumberingPlanEntry { prefix: "64", kind: PhoneKind::Fixed, length: 9, description: "Osorno" },
+    NumberingPlanEntry { prefix: "65", kind: PhoneKind::Fixed, length: 9, description: "Puerto Montt" },
+    NumberingPlanEntry { prefix: "67", kind: PhoneKind::Fixed, length: 9, description: "Coyhaique" },
+    NumberingPlanEntry { prefix: "71", kind: PhoneKind::Fixed, length: 9, description: "Talca" },
+    NumberingPlanEntry { prefix: "72", kind: PhoneKind::Fixed, length: 9, description: "Rancagua" },
+    NumberingPlanEntry { prefix: "73", kind: PhoneKind::Fixed, length: 9, description: "Linares" },
+    NumberingPlanEntry { prefix: "75", kind: PhoneKind::Fixed, length: 9, description: "Curicó" },
+    NumberingPlanEntry { prefix: "44", kind: PhoneKind::Special, length: 9, description: "Voice over IP" },
+    NumberingPlanEntry { prefix: "600", kind: PhoneKind::Special, length: 10, description: "Shared cost" },
+    NumberingPlanEntry { prefix: "800", kind: PhoneKind::Special, length: 9, description: "Toll free" },
+];
+
+
+/// Banks operating in Chile, by institution code as assigned by the CMF
+/// (formerly SBIF).
+pub const BANKS: [Bank; 20] = [
+    Bank { code: "001", name: "Banco de Chile", short_name: "Banco de Chile" },
+    Bank { code: "009", name: "Banco Internacional", short_name: "Internacional" },
+    Bank { code: "012", name: "Banco del Estado de Chile", short_name: "BancoEstado" },
+    Bank { code: "014", name: "Scotiabank Chile", short_name: "Scotiabank" },
+    Bank { code: "016", name: "Banco de Crédito e Inversiones", short_name: "BCI" },
+    Bank { code: "017", name: "Banco do Brasil S.A.", short_name: "Banco do Brasil" },
+    Bank { code: "028", name: "Banco BICE", short_name: "BICE" },
+    Bank { code: "031", name: "HSBC Bank (Chile)", short_name: "HSBC" },
+    Bank { code: "037", name: "Banco Santander-Chile", short_name: "Santander" },
+    Bank { code: "039", name: "Banco Itaú Chile", short_name: "Itaú" },
+    Bank { code: "041", name: "JP Morgan Chase Bank, N.A.", short_name: "JP Morgan" },
+    Bank { code: "043", name: "Banco de la Nación Argentina", short_name: "Nación Argentina" },
+    Bank { code: "045", name: "MUFG Bank, Ltd.", short_name: "MUFG" },
+    Bank { code: "049", name: "Banco Security", short_name: "Security" },
+    Bank { code: "051", name: "Banco Falabella", short_name: "Falabella" },
+    Bank { code: "053", name: "Banco Ripley", short_name: "Ripley" },
+    Bank { code: "055", name: "Banco Consorcio", short_name: "Consorcio" },
+    Bank { code: "059", name: "Banco BTG Pactual Chile", short_name: "BTG Pactual" },
+    Bank { code: "060", name: "China Construction Bank, agencia en Chile", short_name: "China Construction Bank" },
+    Bank { code: "061", name: "Bank of China, agencia en Chile", short_name: "Bank of China" },
 ];
\ No newline at end of file
@@ -1,3 +1,6 @@
+use crate::errors::PlateError;
+use crate::errors::RoleError;
+
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PpuFormat {
@@ -17,4 +20,316 @@ impl PpuFormat {
             PpuFormat::LLNNNN => "LLNNNN",
         }
     }
-}
\ No newline at end of file
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Country {
+    Chile,
+    Argentina,
+    Brazil,
+    Peru,
+}
+
+
+impl Country {
+    pub const ALL: &'static [Self] = &[
+        Self::Chile,
+        Self::Argentina,
+        Self::Brazil,
+        Self::Peru,
+    ];
+
+    /// Returns the ISO 3166-1 alpha-2 code of the country.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Country::Chile => "CL",
+            Country::Argentina => "AR",
+            Country::Brazil => "BR",
+            Country::Peru => "PE",
+        }
+    }
+
+    /// Returns string with enum values separated by comma.
+    pub fn values() -> String {
+        Self::ALL
+            .iter()
+            .map(|v| format!("'{}'", v.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+
+impl TryFrom<&str> for Country {
+    type Error = PlateError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "CL" => Ok(Country::Chile),
+            "AR" => Ok(Country::Argentina),
+            "BR" => Ok(Country::Brazil),
+            "PE" => Ok(Country::Peru),
+            _ => Err(PlateError::UnknownCountry {
+                expected: Self::values(),
+                actual: s.to_string(),
+            }),
+        }
+    }
+}
+
+
+/// Known vehicle license plate formats.
+///
+/// Where:
+/// - `L` = ASCII uppercase letter (`A`–`Z`)
+/// - `N` = ASCII digit (`0`–`9`)
+/// - `X` = ASCII uppercase letter or digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlateFormat {
+    /// Chilean PPU, see [`PpuFormat`].
+    Chile(PpuFormat),
+    /// Argentine plate issued between 1995 and 2016 (`LLLNNN`).
+    ArgentinaLegacy,
+    /// Argentine Mercosur plate (`LLNNNLL`).
+    ArgentinaMercosur,
+    /// Brazilian plate issued before 2018 (`LLLNNNN`).
+    BrazilLegacy,
+    /// Brazilian Mercosur plate (`LLLNLNN`).
+    BrazilMercosur,
+    /// Peruvian plate (`LXXNNN`).
+    Peru,
+}
+
+
+impl PlateFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlateFormat::Chile(fmt) => fmt.as_str(),
+            PlateFormat::ArgentinaLegacy => "LLLNNN",
+            PlateFormat::ArgentinaMercosur => "LLNNNLL",
+            PlateFormat::BrazilLegacy => "LLLNNNN",
+            PlateFormat::BrazilMercosur => "LLLNLNN",
+            PlateFormat::Peru => "LXXNNN",
+        }
+    }
+
+    pub fn country(&self) -> Country {
+        match self {
+            PlateFormat::Chile(_) => Country::Chile,
+            PlateFormat::ArgentinaLegacy
+            | PlateFormat::ArgentinaMercosur => Country::Argentina,
+            PlateFormat::BrazilLegacy
+            | PlateFormat::BrazilMercosur => Country::Brazil,
+            PlateFormat::Peru => Country::Peru,
+        }
+    }
+}
+
+
+/// Generation of a Chilean identity card ("cédula de identidad").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdCardKind {
+    /// Cards issued before 2013, with a letter-prefixed serial number.
+    Old,
+    /// Cards issued since 2013, with a 9-digit document number.
+    New,
+}
+
+
+impl IdCardKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdCardKind::Old => "old",
+            IdCardKind::New => "new",
+        }
+    }
+}
+
+
+/// Kind of a Chilean phone number, as assigned by the numbering plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhoneKind {
+    /// Mobile numbers, starting with `9`.
+    Mobile,
+    /// Fixed (landline) numbers, starting with an area code.
+    Fixed,
+    /// Special service numbers, such as toll-free (`800`) and shared cost
+    /// (`600`) numbers.
+    Special,
+}
+
+
+impl PhoneKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhoneKind::Mobile => "mobile",
+            PhoneKind::Fixed => "fixed",
+            PhoneKind::Special => "special",
+        }
+    }
+}
+
+
+/// Tribunal of a case of the Poder Judicial, told by the prefix of its
+/// role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tribunal {
+    /// First-instance courts (juzgados), whose roles start with a letter.
+    Juzgado,
+    /// Cortes de Apelaciones, whose roles start with the book (libro) of
+    /// the case.
+    CorteApelaciones,
+    /// Corte Suprema, whose roles have no prefix.
+    CorteSuprema,
+}
+
+
+impl Tribunal {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Tribunal::Juzgado => "juzgado",
+            Tribunal::CorteApelaciones => "corte_apelaciones",
+            Tribunal::CorteSuprema => "corte_suprema",
+        }
+    }
+}
+
+
+/// Type of a case of the Poder Judicial, given by the prefix of its role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseType {
+    /// Contentious civil or family case (`C`).
+    Contencioso,
+    /// Voluntary civil or family case (`V`).
+    Voluntario,
+    /// Letter rogatory from another court (`E`).
+    Exhorto,
+    /// Ordinary labor case (`O`).
+    Ordinario,
+    /// Labor case for the protection of fundamental rights (`T`).
+    Tutela,
+    /// Monitory labor case (`M`).
+    Monitorio,
+    /// Civil book of a Corte de Apelaciones.
+    Civil,
+    /// Criminal book of a Corte de Apelaciones.
+    Penal,
+    /// Family book of a Corte de Apelaciones.
+    Familia,
+    /// Labor and social security collection book of a Corte de
+    /// Apelaciones.
+    LaboralCobranza,
+    /// Recurso de protección, in a Corte de Apelaciones.
+    Proteccion,
+    /// Recurso de amparo, in a Corte de Apelaciones.
+    Amparo,
+    /// Administrative litigation book of a Corte de Apelaciones.
+    ContenciosoAdministrativo,
+    /// Local police court appeals book of a Corte de Apelaciones.
+    PoliciaLocal,
+}
+
+
+impl CaseType {
+    pub const ALL: &'static [Self] = &[
+        Self::Contencioso,
+        Self::Voluntario,
+        Self::Exhorto,
+        Self::Ordinario,
+        Self::Tutela,
+        Self::Monitorio,
+        Self::Civil,
+        Self::Penal,
+        Self::Familia,
+        Self::LaboralCobranza,
+        Self::Proteccion,
+        Self::Amparo,
+        Self::ContenciosoAdministrativo,
+        Self::PoliciaLocal,
+    ];
+
+    /// Returns the prefix of the roles of the case type, as written by the
+    /// Poder Judicial.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CaseType::Contencioso => "C",
+            CaseType::Voluntario => "V",
+            CaseType::Exhorto => "E",
+            CaseType::Ordinario => "O",
+            CaseType::Tutela => "T",
+            CaseType::Monitorio => "M",
+            CaseType::Civil => "Civil",
+            CaseType::Penal => "Penal",
+            CaseType::Familia => "Familia",
+            CaseType::LaboralCobranza => "Laboral-Cobranza",
+            CaseType::Proteccion => "Protección",
+            CaseType::Amparo => "Amparo",
+            CaseType::ContenciosoAdministrativo => "Contencioso Administrativo",
+            CaseType::PoliciaLocal => "Policía Local",
+        }
+    }
+
+    /// Returns the tribunal of the cases of the type.
+    pub fn tribunal(&self) -> Tribunal {
+        match self {
+            CaseType::Contencioso
+            | CaseType::Voluntario
+            | CaseType::Exhorto
+            | CaseType::Ordinario
+            | CaseType::Tutela
+            | CaseType::Monitorio => Tribunal::Juzgado,
+            _ => Tribunal::CorteApelaciones,
+        }
+    }
+
+    /// Returns string with enum values separated by comma.
+    pub fn values() -> String {
+        Self::ALL
+            .iter()
+            .map(|v| format!("'{}'", v.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+
+/// Folds a case type prefix for comparison: uppercase, without accents,
+/// and with its words separated by single spaces.
+fn fold_case_type(prefix: &str) -> String {
+    prefix
+        .chars()
+        .map(|c| match c {
+            'á' | 'Á' => 'A',
+            'é' | 'É' => 'E',
+            'í' | 'Í' => 'I',
+            'ó' | 'Ó' => 'O',
+            'ú' | 'Ú' => 'U',
+            '-' => ' ',
+            c => c.to_ascii_uppercase(),
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+
+impl TryFrom<&str> for CaseType {
+    type Error = RoleError;
+
+    /// Parses a case type prefix, ignoring case and accents, and with its
+    /// words separated by `'-'` or whitespace, e.g. `"proteccion"`.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let prefix = fold_case_type(s);
+
+        Self::ALL
+            .iter()
+            .find(|t| fold_case_type(t.as_str()) == prefix)
+            .copied()
+            .ok_or_else(|| RoleError::UnknownCaseType {
+                expected: Self::values(),
+                actual: s.trim().to_string(),
+            })
+    }
+}
@@ -23,6 +23,75 @@ pub enum PpuError {
 
     #[error("Unexpected parsing error: {0}")]
     ParsingError(#[from] std::num::ParseIntError),
+
+    #[error("Numeric '{numeric}' does not match any digraph-based PPU.")]
+    InvalidNumeric { numeric: u32 },
+
+    #[error("Verifier of PPU '{ppu}' must be '{expected}', but '{actual}' was given.")]
+    VerifierMismatch { ppu: String, expected: char, actual: String },
+
+    #[error("Expected a PPU and its verifier separated by '-': '{complete}'.")]
+    MissingVerifier { complete: String },
+}
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum PlateError {
+    #[error("Plate does not match any known format: '{plate}'.")]
+    UnknownFormat { plate: String },
+
+    #[error("Plate '{plate}' does not match any known format of '{country}'.")]
+    CountryMismatch { plate: String, country: String },
+
+    #[error("Unknown country. Expected {expected}, but '{actual}' was given.")]
+    UnknownCountry { expected: String, actual: String },
+}
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum VinError {
+    #[error("VIN cannot be empty.")]
+    EmptyVin,
+
+    #[error("VIN must have 17 characters, but got '{actual}' ('{vin}').")]
+    InvalidLength { actual: usize, vin: String },
+
+    #[error("Invalid VIN character '{character}' at position {position}.")]
+    InvalidCharacter { character: char, position: usize },
+}
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum IdCardError {
+    #[error("MRZ must have {expected} characters, but got '{actual}'.")]
+    InvalidMrzLength { expected: usize, actual: usize },
+
+    #[error("Invalid MRZ character '{character}' at position {position}.")]
+    InvalidMrzCharacter { character: char, position: usize },
+
+    #[error("Unsupported document '{code}' issued by '{state}'.")]
+    UnsupportedDocument { code: String, state: String },
+
+    #[error("Check digit of '{field}' must be '{expected}', but '{actual}' was given.")]
+    InvalidCheckDigit { field: String, expected: char, actual: char },
+
+    #[error("Invalid date in '{field}': '{value}'.")]
+    InvalidDate { field: String, value: String },
+
+    #[error("Invalid document number: '{number}'.")]
+    InvalidDocumentNumber { number: String },
+
+    #[error("Invalid RUN: '{run}'.")]
+    InvalidRun { run: String },
+
+    #[error("Invalid QR code URL: '{url}'.")]
+    InvalidQrUrl { url: String },
+
+    #[error("Missing QR code parameter: '{param}'.")]
+    MissingQrParameter { param: String },
+
+    #[error("Serial '{serial}' does not match the MRZ document number '{number}'.")]
+    SerialMismatch { serial: String, number: String },
 }
 
 
@@ -52,4 +121,58 @@ pub enum GenerateError{
 
     #[error("Unexpected error during RUT generation: {0}")]
     UnexpectedGeneration(#[from] VerifierError),
-}
\ No newline at end of file
+}
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum PhoneError {
+    #[error("Phone number cannot be empty.")]
+    EmptyPhone,
+
+    #[error("Invalid phone number character '{character}' at position {position}.")]
+    InvalidCharacter { character: char, position: usize },
+
+    #[error("Unknown phone number prefix: '{phone}'.")]
+    UnknownPrefix { phone: String },
+
+    #[error("Phone number must have {expected} digits, but got '{actual}' ('{phone}').")]
+    InvalidLength { expected: usize, actual: usize, phone: String },
+}
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum BankError {
+    #[error("Unknown bank: '{query}'.")]
+    UnknownBank { query: String },
+
+    #[error("Account number cannot be empty.")]
+    EmptyAccount,
+
+    #[error("Invalid account number character '{character}' at position {position}.")]
+    InvalidAccountCharacter { character: char, position: usize },
+
+    #[error("Verifier of RUT '{correlative}' must be '{expected}', but '{actual}' was given.")]
+    InvalidRut { correlative: u32, expected: char, actual: char },
+
+    #[error(transparent)]
+    Verifier(#[from] VerifierError),
+}
+
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum RoleError {
+    #[error("Role cannot be empty.")]
+    EmptyRole,
+
+    #[error("Invalid role format: '{role}'.")]
+    InvalidFormat { role: String },
+
+    #[error("Unknown case type. Expected {expected}, but '{actual}' was given.")]
+    UnknownCaseType { expected: String, actual: String },
+
+    #[error("Role year must be between {min} and {max}, but got '{year}'.")]
+    InvalidYear { year: i32, min: i32, max: i32 },
+
+    #[error("Invalid comuna code: '{comuna}'.")]
+    InvalidComuna { comuna: u32 },
+}
@@ -0,0 +1,536 @@
+use chrono::NaiveDate;
+use chrono::Utc;
+
+use crate::enums::IdCardKind;
+use crate::errors::IdCardError;
+use crate::utils::Rut;
+use crate::utils::calculate_verifier;
+
+
+/// Length of each line of an ICAO TD1 machine-readable zone.
+const MRZ_LINE_LENGTH: usize = 30;
+
+/// Weights of the ICAO 9303 check digit, repeated along the field.
+const MRZ_WEIGHTS: [u32; 3] = [7, 3, 1];
+
+/// Host serving the document status page linked by the ID card QR code.
+const QR_HOST: &str = "portal.sidiv.registrocivil.cl";
+
+/// Length of the `mrz` parameter of the QR code URL: document number,
+/// birth date and expiry date, each followed by its check digit.
+const QR_MRZ_LENGTH: usize = 24;
+
+
+/// Represents the machine-readable zone (MRZ) of a Chilean identity card,
+/// as defined by ICAO 9303 for TD1 documents.
+///
+/// # Examples
+/// ```
+/// use verify::idcard::parse_mrz;
+///
+/// let mrz = parse_mrz(
+///     "INCHL1001234562<<<<<<<<<<<<<<<\
+///      8501019M3001019CHL12345678<5<1\
+///      GONZALEZ<PEREZ<<JUAN<PABLO<<<<",
+/// ).unwrap();
+/// assert_eq!(mrz.document_number, "100123456");
+/// assert_eq!(mrz.surnames, "GONZALEZ PEREZ");
+/// assert_eq!(mrz.given_names, "JUAN PABLO");
+/// assert_eq!(mrz.run.unwrap().correlative, 12345678);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IdCardMrz {
+    /// The document code, e.g. `"IN"` or `"ID"`.
+    pub document_code: String,
+    /// The issuing state, always `"CHL"`.
+    pub issuing_state: String,
+    /// The document number (or serial number for old cards).
+    pub document_number: String,
+    /// The optional data of the first line, without fillers.
+    pub optional_data: String,
+    /// The holder's date of birth.
+    pub birth_date: NaiveDate,
+    /// The holder's sex: `'M'`, `'F'` or `'<'` if unspecified.
+    pub sex: char,
+    /// The expiry date of the card.
+    pub expiry_date: NaiveDate,
+    /// The holder's nationality, as an ISO 3166-1 alpha-3 code.
+    pub nationality: String,
+    /// The optional data of the second line, without fillers.
+    pub optional_data_2: String,
+    /// The holder's surnames.
+    pub surnames: String,
+    /// The holder's given names.
+    pub given_names: String,
+    /// The holder's RUN, if present in the optional data.
+    pub run: Option<Rut>,
+}
+
+/// Implements methods for the [`IdCardMrz`] struct.
+impl IdCardMrz {
+    /// Returns the card generation, based on its document number.
+    pub fn kind(&self) -> IdCardKind {
+        // The document number was already validated.
+        validate_document_number(&self.document_number).unwrap()
+    }
+}
+
+
+/// Represents the payload of the QR code printed on Chilean identity cards
+/// issued since 2013.
+///
+/// # Examples
+/// ```
+/// use verify::idcard::parse_qr_url;
+///
+/// let qr = parse_qr_url(
+///     "https://portal.sidiv.registrocivil.cl/docstatus?\
+///      RUN=12345678-5&type=CEDULA&serial=100123456&mrz=100123456285010193001019",
+/// ).unwrap();
+/// assert_eq!(qr.run.correlative, 12345678);
+/// assert_eq!(qr.serial, "100123456");
+/// assert_eq!(qr.document_type, "CEDULA");
+/// ```
+#[derive(Debug, Clone)]
+pub struct IdCardQr {
+    /// The holder's RUN.
+    pub run: Rut,
+    /// The document type, e.g. `"CEDULA"`.
+    pub document_type: String,
+    /// The document number.
+    pub serial: String,
+    /// The holder's date of birth.
+    pub birth_date: NaiveDate,
+    /// The expiry date of the card.
+    pub expiry_date: NaiveDate,
+}
+
+
+/// Gets the numeric value of an MRZ character used by the check digit.
+fn get_mrz_char_value(c: char) -> Option<u32> {
+    match c {
+        '0'..='9' => c.to_digit(10),
+        'A'..='Z' => Some(c as u32 - 'A' as u32 + 10),
+        '<' => Some(0),
+        _ => None,
+    }
+}
+
+
+/// Calculates the ICAO 9303 check digit of an MRZ field.
+///
+/// # Arguments
+/// * `field` - A reference to a string slice containing the field.
+///
+/// # Returns
+/// * `Ok(char)` - The check digit, from `'0'` to `'9'`.
+/// * `Err(IdCardError::InvalidMrzCharacter)` - If the field has characters
+///   other than uppercase letters, digits and `'<'`.
+///
+/// # Algorithm
+/// 1. Each character is transliterated to a number: digits keep their
+///    value, letters `A`–`Z` map to `10`–`35` and the filler `'<'` to `0`.
+/// 2. Compute the sum of each value multiplied by the weights `7`, `3`,
+///    `1`, repeated along the field.
+/// 3. The check digit is the remainder of the sum (mod 10).
+///
+/// # Examples
+/// ```
+/// use verify::idcard::calculate_mrz_check_digit;
+///
+/// assert_eq!(calculate_mrz_check_digit("100123456").unwrap(), '2');
+/// assert_eq!(calculate_mrz_check_digit("850101").unwrap(), '9');
+/// assert!(calculate_mrz_check_digit("85-01-01").is_err());
+/// ```
+pub fn calculate_mrz_check_digit(field: &str) -> Result<char, IdCardError> {
+    let mut sum: u32 = 0;
+
+    for (i, c) in field.chars().enumerate() {
+        let value = get_mrz_char_value(c)
+            .ok_or(IdCardError::InvalidMrzCharacter { character: c, position: i + 1 })?;
+        sum += value * MRZ_WEIGHTS[i % 3];
+    }
+
+    Ok(char::from_digit(sum % 10, 10).unwrap())
+}
+
+
+/// Validates the check digit of an MRZ field.
+fn check_field(name: &str, field: &str, check_digit: char) -> Result<(), IdCardError> {
+    let expected = calculate_mrz_check_digit(field)?;
+
+    if expected != check_digit {
+        return Err(IdCardError::InvalidCheckDigit {
+            field: name.to_string(),
+            expected,
+            actual: check_digit,
+        });
+    }
+
+    Ok(())
+}
+
+
+/// Parses an MRZ `YYMMDD` date. Expiry dates are always in the 2000s; birth
+/// dates are in the 2000s unless that would be in the future.
+fn parse_mrz_date(name: &str, value: &str, is_birth: bool) -> Result<NaiveDate, IdCardError> {
+    let invalid = || IdCardError::InvalidDate {
+        field: name.to_string(),
+        value: value.to_string(),
+    };
+
+    if value.len() != 6 || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let year: i32 = value[..2].parse().map_err(|_| invalid())?;
+    let month: u32 = value[2..4].parse().map_err(|_| invalid())?;
+    let day: u32 = value[4..].parse().map_err(|_| invalid())?;
+
+    let date = NaiveDate::from_ymd_opt(2000 + year, month, day)
+        .ok_or_else(invalid)?;
+
+    if is_birth && date > Utc::now().date_naive() {
+        return NaiveDate::from_ymd_opt(1900 + year, month, day).ok_or_else(invalid);
+    }
+
+    Ok(date)
+}
+
+
+/// Finds a RUN written as `<digits>'<'<verifier>` in MRZ optional data, and
+/// cross-checks its verifier with [`calculate_verifier`].
+fn find_run(data: &str) -> Result<Option<Rut>, IdCardError> {
+    let Some((digits, verifier)) = data.rsplit_once('<') else {
+        return Ok(None);
+    };
+
+    if digits.is_empty()
+        || digits.len() > 8
+        || !digits.bytes().all(|b| b.is_ascii_digit())
+        || verifier.len() != 1
+    {
+        return Ok(None);
+    }
+
+    // At most 8 digits, so parsing cannot fail.
+    let correlative: u32 = digits.parse().unwrap();
+    let run = Rut::new(correlative)
+        .map_err(|_| IdCardError::InvalidRun { run: data.to_string() })?;
+
+    if run.verifier.to_string() != verifier {
+        return Err(IdCardError::InvalidRun { run: format!("{}-{}", digits, verifier) });
+    }
+
+    Ok(Some(run))
+}
+
+
+/// Validates the document number of a Chilean identity card.
+///
+/// # Arguments
+/// * `number` - A reference to a string slice containing the document
+///   number. Dots are ignored (e.g. `"100.123.456"`).
+///
+/// # Returns
+/// * `Ok(IdCardKind)` - The card generation that matches the number:
+///   - [`IdCardKind::New`] - 9 digits, not starting with `'0'`.
+///   - [`IdCardKind::Old`] - A letter followed by 9 digits.
+/// * `Err(IdCardError::InvalidDocumentNumber)` - If the number does not
+///   match any generation.
+///
+/// # Examples
+/// ```
+/// use verify::idcard::validate_document_number;
+/// use verify::enums::IdCardKind;
+///
+/// assert_eq!(validate_document_number("100.123.456").unwrap(), IdCardKind::New);
+/// assert_eq!(validate_document_number("a012345678").unwrap(), IdCardKind::Old);
+/// assert!(validate_document_number("012345678").is_err());
+/// ```
+pub fn validate_document_number(number: &str) -> Result<IdCardKind, IdCardError> {
+    let normalized = number.trim().replace('.', "").to_ascii_uppercase();
+    let bytes = normalized.as_bytes();
+
+    match bytes {
+        [first, rest @ ..] if rest.len() == 8
+            && *first != b'0'
+            && bytes.iter().all(u8::is_ascii_digit) => Ok(IdCardKind::New),
+        [first, rest @ ..] if rest.len() == 9
+            && first.is_ascii_uppercase()
+            && rest.iter().all(u8::is_ascii_digit) => Ok(IdCardKind::Old),
+        _ => Err(IdCardError::InvalidDocumentNumber { number: number.to_string() }),
+    }
+}
+
+
+/// Parses and validates the machine-readable zone (MRZ) of a Chilean
+/// identity card.
+///
+/// # Arguments
+/// * `mrz` - A reference to a string slice containing the three lines of
+///   the MRZ. Whitespaces and line breaks are ignored.
+///
+/// # Returns
+/// * `Ok(IdCardMrz)` - The parsed MRZ.
+/// * `Err(IdCardError)`:
+///   - [`IdCardError::InvalidMrzLength`] - If the MRZ does not have 90
+///     characters.
+///   - [`IdCardError::InvalidMrzCharacter`] - If the MRZ has characters
+///     other than uppercase letters, digits and `'<'`.
+///   - [`IdCardError::UnsupportedDocument`] - If the document is not an
+///     identity card issued by Chile.
+///   - [`IdCardError::InvalidCheckDigit`] - If any check digit is invalid.
+///   - [`IdCardError::InvalidDate`] - If the birth or expiry date is invalid.
+///   - [`IdCardError::InvalidDocumentNumber`] - See
+///     [`validate_document_number`].
+///   - [`IdCardError::InvalidRun`] - If the RUN found in the optional data
+///     does not match its verifier.
+///
+/// # Behavior
+/// Every ICAO check digit is validated: document number, birth date, expiry
+/// date and the composite check digit of the second line. Document numbers
+/// longer than 9 characters (old cards) continue in the optional data, as
+/// defined by ICAO 9303.
+///
+/// The RUN is searched in the optional data of the second line, then of the
+/// first line, written as digits, a `'<'` filler and the verifier (e.g.
+/// `12345678<5`).
+///
+/// # Examples
+/// ```
+/// use verify::idcard::parse_mrz;
+/// use verify::enums::IdCardKind;
+///
+/// let mrz = parse_mrz(
+///     "INCHL1001234562<<<<<<<<<<<<<<<
+///      8501019M3001019CHL12345678<5<1
+///      GONZALEZ<PEREZ<<JUAN<PABLO<<<<",
+/// ).unwrap();
+/// assert_eq!(mrz.kind(), IdCardKind::New);
+/// assert_eq!(mrz.birth_date.to_string(), "1985-01-01");
+/// assert_eq!(mrz.expiry_date.to_string(), "2030-01-01");
+///
+/// // Old cards
+/// let mrz = parse_mrz(
+///     "IDCHLA01234567<84<<<<<<<<<<<<<
+///      8501019M3001019CHL12345678<5<1
+///      GONZALEZ<PEREZ<<JUAN<PABLO<<<<",
+/// ).unwrap();
+/// assert_eq!(mrz.kind(), IdCardKind::Old);
+/// assert_eq!(mrz.document_number, "A012345678");
+///
+/// // Invalid birth date check digit
+/// assert!(parse_mrz(
+///     "INCHL1001234562<<<<<<<<<<<<<<<
+///      8501018M3001019CHL12345678<5<1
+///      GONZALEZ<PEREZ<<JUAN<PABLO<<<<",
+/// ).is_err());
+/// ```
+pub fn parse_mrz(mrz: &str) -> Result<IdCardMrz, IdCardError> {
+    let mrz: String = mrz
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_uppercase();
+
+    if mrz.len() != MRZ_LINE_LENGTH * 3 {
+        return Err(IdCardError::InvalidMrzLength {
+            expected: MRZ_LINE_LENGTH * 3,
+            actual: mrz.chars().count(),
+        });
+    }
+
+    if let Some((i, c)) = mrz
+        .chars()
+        .enumerate()
+        .find(|(_, c)| get_mrz_char_value(*c).is_none())
+    {
+        return Err(IdCardError::InvalidMrzCharacter { character: c, position: i + 1 });
+    }
+
+    let (line1, rest) = mrz.split_at(MRZ_LINE_LENGTH);
+    let (line2, line3) = rest.split_at(MRZ_LINE_LENGTH);
+    let char_at = |line: &str, i: usize| line.as_bytes()[i] as char;
+
+    // First line: document code, issuing state, document number and
+    // optional data.
+    let document_code = line1[..2].trim_end_matches('<').to_string();
+    let issuing_state = line1[2..5].to_string();
+
+    if !document_code.starts_with('I') || issuing_state != "CHL" {
+        return Err(IdCardError::UnsupportedDocument {
+            code: document_code,
+            state: issuing_state,
+        });
+    }
+
+    let (document_number, optional_data) = if char_at(line1, 14) == '<' {
+        // The document number overflows into the optional data, followed
+        // by its check digit and a '<' filler.
+        let (overflow, optional) = line1[15..]
+            .split_once('<')
+            .unwrap_or((&line1[15..], ""));
+        let Some((overflow, check_digit)) = overflow
+            .char_indices()
+            .last()
+            .map(|(i, c)| (&overflow[..i], c))
+        else {
+            return Err(IdCardError::InvalidDocumentNumber { number: line1[5..14].to_string() });
+        };
+        let number = format!("{}{}", &line1[5..14], overflow);
+        check_field("document_number", &number, check_digit)?;
+        (number, optional)
+    } else {
+        let number = line1[5..14].trim_end_matches('<').to_string();
+        check_field("document_number", &line1[5..14], char_at(line1, 14))?;
+        (number, &line1[15..])
+    };
+
+    validate_document_number(&document_number)?;
+
+    // Second line: dates, sex, nationality, optional data and composite
+    // check digit.
+    check_field("birth_date", &line2[..6], char_at(line2, 6))?;
+    check_field("expiry_date", &line2[8..14], char_at(line2, 14))?;
+
+    let composite = format!("{}{}{}{}", &line1[5..], &line2[..7], &line2[8..15], &line2[18..29]);
+    check_field("composite", &composite, char_at(line2, 29))?;
+
+    let birth_date = parse_mrz_date("birth_date", &line2[..6], true)?;
+    let expiry_date = parse_mrz_date("expiry_date", &line2[8..14], false)?;
+    let optional_data = optional_data.trim_end_matches('<');
+    let optional_data_2 = line2[18..29].trim_end_matches('<');
+
+    let run = match find_run(optional_data_2)? {
+        Some(run) => Some(run),
+        None => find_run(optional_data)?,
+    };
+
+    // Third line: surnames and given names, separated by '<<'.
+    let name = line3.trim_end_matches('<');
+    let (surnames, given_names) = name.split_once("<<").unwrap_or((name, ""));
+
+    Ok(IdCardMrz {
+        document_code,
+        issuing_state,
+        document_number,
+        optional_data: optional_data.to_string(),
+        birth_date,
+        sex: char_at(line2, 7),
+        expiry_date,
+        nationality: line2[15..18].trim_end_matches('<').to_string(),
+        optional_data_2: optional_data_2.to_string(),
+        surnames: surnames.replace('<', " "),
+        given_names: given_names.replace('<', " "),
+        run,
+    })
+}
+
+
+/// Parses and validates the URL encoded in the QR code of Chilean identity
+/// cards issued since 2013.
+///
+/// # Arguments
+/// * `url` - A reference to a string slice containing the URL, e.g.
+///   `https://portal.sidiv.registrocivil.cl/docstatus?RUN=...&type=...&serial=...&mrz=...`
+///
+/// # Returns
+/// * `Ok(IdCardQr)` - The parsed payload.
+/// * `Err(IdCardError)`:
+///   - [`IdCardError::InvalidQrUrl`] - If the URL does not point to the
+///     Civil Registry document status page, or its `mrz` parameter is
+///     malformed.
+///   - [`IdCardError::MissingQrParameter`] - If any of the `RUN`, `type`,
+///     `serial` or `mrz` parameters is missing.
+///   - [`IdCardError::InvalidRun`] - If the RUN does not match its verifier.
+///   - [`IdCardError::InvalidCheckDigit`] - If any check digit of the `mrz`
+///     parameter is invalid.
+///   - [`IdCardError::SerialMismatch`] - If `serial` does not match the
+///     document number of the `mrz` parameter.
+///
+/// # Behavior
+/// The `mrz` parameter holds the document number, birth date and expiry
+/// date of the card's MRZ, each followed by its ICAO check digit.
+///
+/// # Examples
+/// ```
+/// use verify::idcard::parse_qr_url;
+///
+/// let url = "https://portal.sidiv.registrocivil.cl/docstatus?\
+///            RUN=12345678-5&type=CEDULA&serial=100123456&mrz=100123456285010193001019";
+/// let qr = parse_qr_url(url).unwrap();
+/// assert_eq!(qr.birth_date.to_string(), "1985-01-01");
+///
+/// // RUN verifier mismatch
+/// assert!(parse_qr_url(&url.replace("12345678-5", "12345678-K")).is_err());
+///
+/// // Other hosts are rejected
+/// assert!(parse_qr_url(&url.replace("registrocivil", "example")).is_err());
+/// ```
+pub fn parse_qr_url(url: &str) -> Result<IdCardQr, IdCardError> {
+    let url = url.trim();
+    let invalid_url = || IdCardError::InvalidQrUrl { url: url.to_string() };
+
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(invalid_url)?;
+    let (location, query) = rest.split_once('?').ok_or_else(invalid_url)?;
+    let host = location.split('/').next().unwrap_or_default();
+
+    if !host.eq_ignore_ascii_case(QR_HOST) {
+        return Err(invalid_url());
+    }
+
+    let param = |name: &str| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.trim())
+            .ok_or(IdCardError::MissingQrParameter { param: name.to_string() })
+    };
+
+    let run = param("RUN")?;
+    let document_type = param("type")?;
+    let serial = param("serial")?;
+    let mrz = param("mrz")?;
+
+    // RUN is written as '<digits>-<verifier>'.
+    let invalid_run = || IdCardError::InvalidRun { run: run.to_string() };
+    let (digits, verifier) = run.split_once('-').ok_or_else(invalid_run)?;
+    let correlative: u32 = digits.replace('.', "").parse().map_err(|_| invalid_run())?;
+    let expected = calculate_verifier(correlative).map_err(|_| invalid_run())?;
+
+    if !verifier.eq_ignore_ascii_case(&expected.to_string()) {
+        return Err(invalid_run());
+    }
+
+    if mrz.len() != QR_MRZ_LENGTH || !mrz.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'<') {
+        return Err(invalid_url());
+    }
+
+    let char_at = |i: usize| mrz.as_bytes()[i] as char;
+
+    check_field("document_number", &mrz[..9], char_at(9))?;
+    check_field("birth_date", &mrz[10..16], char_at(16))?;
+    check_field("expiry_date", &mrz[17..23], char_at(23))?;
+
+    let number = mrz[..9].trim_end_matches('<');
+
+    if !serial.replace('.', "").eq_ignore_ascii_case(number) {
+        return Err(IdCardError::SerialMismatch {
+            serial: serial.to_string(),
+            number: number.to_string(),
+        });
+    }
+
+    Ok(IdCardQr {
+        run: Rut { correlative, verifier: expected },
+        document_type: document_type.to_string(),
+        serial: number.to_string(),
+        birth_date: parse_mrz_date("birth_date", &mrz[10..16], true)?,
+        expiry_date: parse_mrz_date("expiry_date", &mrz[17..23], false)?,
+    })
+}
\ No newline at end of file
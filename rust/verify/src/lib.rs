@@ -1,16 +1,32 @@
 pub mod enums;
+pub mod structs;
 pub mod constants;
 pub mod utils;
 pub mod errors;
+pub mod schedule;
+pub mod plates;
+pub mod vin;
+pub mod idcard;
+pub mod phone;
+pub mod banks;
+pub mod roles;
 
+use chrono::NaiveDate;
 use pyo3::prelude::*;
 use pyo3::create_exception;
 use pyo3::types::{PyDict, PyList};
 use pyo3::exceptions::PyException;
 
 use crate::errors::PpuError;
+use crate::errors::PlateError;
+use crate::errors::VinError;
+use crate::errors::IdCardError;
+use crate::errors::PhoneError;
+use crate::errors::BankError;
+use crate::errors::RoleError;
 use crate::errors::VerifierError;
 use crate::errors::GenerateError;
+use crate::constants::RESTRICTION_RULES;
 
 
 create_exception!(
@@ -45,6 +61,164 @@ create_exception!(
     rs_verify, ParsingError, PpuException,
     "Raised when failed to create the numeric representation of the given PPU."
 );
+create_exception!(
+    rs_verify, InvalidNumeric, PpuException,
+    "Raised when the given numeric representation does not match any PPU."
+);
+create_exception!(
+    rs_verify, VerifierMismatch, PpuException,
+    "Raised when the given PPU verifier does not match the computed one."
+);
+create_exception!(
+    rs_verify, MissingVerifier, PpuException,
+    "Raised when the given complete PPU has no '-' before its verifier."
+);
+
+create_exception!(
+    rs_verify, PlateException, PyException,
+    "Base class for all exceptions raised by the plate parser."
+);
+create_exception!(
+    rs_verify, UnknownPlateFormat, PlateException,
+    "Raised when the given plate does not match any known format."
+);
+create_exception!(
+    rs_verify, CountryMismatch, PlateException,
+    "Raised when the given plate does not match any format of the given country."
+);
+create_exception!(
+    rs_verify, UnknownCountry, PlateException,
+    "Raised when the given country is not supported."
+);
+
+create_exception!(
+    rs_verify, VinException, PyException,
+    "Base class for all exceptions raised by the VIN parser."
+);
+create_exception!(
+    rs_verify, EmptyVin, VinException,
+    "Raised when the given VIN is empty."
+);
+create_exception!(
+    rs_verify, InvalidVinLength, VinException,
+    "Raised when the given VIN does not have 17 characters."
+);
+create_exception!(
+    rs_verify, InvalidVinCharacter, VinException,
+    "Raised when the given VIN has characters out of the VIN alphabet."
+);
+
+create_exception!(
+    rs_verify, IdCardException, PyException,
+    "Base class for all exceptions raised by the identity card parser."
+);
+create_exception!(
+    rs_verify, InvalidMrzLength, IdCardException,
+    "Raised when the given MRZ has an invalid length."
+);
+create_exception!(
+    rs_verify, InvalidMrzCharacter, IdCardException,
+    "Raised when the given MRZ has characters out of the MRZ alphabet."
+);
+create_exception!(
+    rs_verify, UnsupportedDocument, IdCardException,
+    "Raised when the given MRZ is not from a Chilean identity card."
+);
+create_exception!(
+    rs_verify, InvalidCheckDigit, IdCardException,
+    "Raised when an ICAO check digit is invalid."
+);
+create_exception!(
+    rs_verify, InvalidDate, IdCardException,
+    "Raised when a date of the identity card is invalid."
+);
+create_exception!(
+    rs_verify, InvalidDocumentNumber, IdCardException,
+    "Raised when the identity card document number is invalid."
+);
+create_exception!(
+    rs_verify, InvalidRun, IdCardException,
+    "Raised when the RUN of the identity card does not match its verifier."
+);
+create_exception!(
+    rs_verify, InvalidQrUrl, IdCardException,
+    "Raised when the given QR code URL is invalid."
+);
+create_exception!(
+    rs_verify, MissingQrParameter, IdCardException,
+    "Raised when the given QR code URL lacks a required parameter."
+);
+create_exception!(
+    rs_verify, SerialMismatch, IdCardException,
+    "Raised when the QR code serial does not match its MRZ document number."
+);
+
+create_exception!(
+    rs_verify, PhoneException, PyException,
+    "Base class for all exceptions raised by the phone number parser."
+);
+create_exception!(
+    rs_verify, EmptyPhone, PhoneException,
+    "Raised when the given phone number is empty."
+);
+create_exception!(
+    rs_verify, InvalidPhoneCharacter, PhoneException,
+    "Raised when the given phone number has invalid characters."
+);
+create_exception!(
+    rs_verify, UnknownPhonePrefix, PhoneException,
+    "Raised when the given phone number has an unknown prefix."
+);
+create_exception!(
+    rs_verify, InvalidPhoneLength, PhoneException,
+    "Raised when the given phone number has an invalid length."
+);
+
+create_exception!(
+    rs_verify, BankException, PyException,
+    "Base class for all exceptions raised by the bank registry."
+);
+create_exception!(
+    rs_verify, UnknownBank, BankException,
+    "Raised when no bank matches the given query."
+);
+create_exception!(
+    rs_verify, EmptyAccount, BankException,
+    "Raised when the given account number is empty."
+);
+create_exception!(
+    rs_verify, InvalidAccountCharacter, BankException,
+    "Raised when the given account number has invalid characters."
+);
+create_exception!(
+    rs_verify, InvalidAccountRut, BankException,
+    "Raised when the RUT of a CuentaRUT check has an invalid verifier."
+);
+
+create_exception!(
+    rs_verify, RoleException, PyException,
+    "Base class for all exceptions raised by the case and property role parsers."
+);
+create_exception!(
+    rs_verify, EmptyRole, RoleException,
+    "Raised when the given role is empty."
+);
+create_exception!(
+    rs_verify, InvalidRoleFormat, RoleException,
+    "Raised when the given role does not match the expected format."
+);
+create_exception!(
+    rs_verify, UnknownCaseType, RoleException,
+    "Raised when the prefix of the given case role is not a known case type."
+);
+create_exception!(
+    rs_verify, InvalidRoleYear, RoleException,
+    "Raised when the year of the given case role is out of range."
+);
+create_exception!(
+    rs_verify, InvalidComunaCode, RoleException,
+    "Raised when the comuna code of a property role is out of range."
+);
 
 create_exception!(
     rs_verify, VerifierException, PyException,
@@ -94,6 +268,81 @@ impl From<PpuError> for PyErr {
             PpuError::UnknownDigraph { .. } => UnknownDigraph::new_err(err.to_string()),
             PpuError::EmptyDigraph => EmptyDigraph::new_err(err.to_string()),
             PpuError::ParsingError(_) => ParsingError::new_err(err.to_string()),
+            PpuError::InvalidNumeric { .. } => InvalidNumeric::new_err(err.to_string()),
+            PpuError::VerifierMismatch { .. } => VerifierMismatch::new_err(err.to_string()),
+            PpuError::MissingVerifier { .. } => MissingVerifier::new_err(err.to_string()),
+        }
+    }
+}
+
+impl From<PlateError> for PyErr {
+    fn from(err: PlateError) -> PyErr {
+        match err {
+            PlateError::UnknownFormat { .. } => UnknownPlateFormat::new_err(err.to_string()),
+            PlateError::CountryMismatch { .. } => CountryMismatch::new_err(err.to_string()),
+            PlateError::UnknownCountry { .. } => UnknownCountry::new_err(err.to_string()),
+        }
+    }
+}
+
+impl From<VinError> for PyErr {
+    fn from(err: VinError) -> PyErr {
+        match err {
+            VinError::EmptyVin => EmptyVin::new_err(err.to_string()),
+            VinError::InvalidLength { .. } => InvalidVinLength::new_err(err.to_string()),
+            VinError::InvalidCharacter { .. } => InvalidVinCharacter::new_err(err.to_string()),
+        }
+    }
+}
+
+impl From<IdCardError> for PyErr {
+    fn from(err: IdCardError) -> PyErr {
+        match err {
+            IdCardError::InvalidMrzLength { .. } => InvalidMrzLength::new_err(err.to_string()),
+            IdCardError::InvalidMrzCharacter { .. } => InvalidMrzCharacter::new_err(err.to_string()),
+            IdCardError::UnsupportedDocument { .. } => UnsupportedDocument::new_err(err.to_string()),
+            IdCardError::InvalidCheckDigit { .. } => InvalidCheckDigit::new_err(err.to_string()),
+            IdCardError::InvalidDate { .. } => InvalidDate::new_err(err.to_string()),
+            IdCardError::InvalidDocumentNumber { .. } => InvalidDocumentNumber::new_err(err.to_string()),
+            IdCardError::InvalidRun { .. } => InvalidRun::new_err(err.to_string()),
+            IdCardError::InvalidQrUrl { .. } => InvalidQrUrl::new_err(err.to_string()),
+            IdCardError::MissingQrParameter { .. } => MissingQrParameter::new_err(err.to_string()),
+            IdCardError::SerialMismatch { .. } => SerialMismatch::new_err(err.to_string()),
+        }
+    }
+}
+
+impl From<PhoneError> for PyErr {
+    fn from(err: PhoneError) -> PyErr {
+        match err {
+            PhoneError::EmptyPhone => EmptyPhone::new_err(err.to_string()),
+            PhoneError::InvalidCharacter { .. } => InvalidPhoneCharacter::new_err(err.to_string()),
+            PhoneError::UnknownPrefix { .. } => UnknownPhonePrefix::new_err(err.to_string()),
+            PhoneError::InvalidLength { .. } => InvalidPhoneLength::new_err(err.to_string()),
+        }
+    }
+}
+
+impl From<BankError> for PyErr {
+    fn from(err: BankError) -> PyErr {
+        match err {
+            BankError::UnknownBank { .. } => UnknownBank::new_err(err.to_string()),
+            BankError::EmptyAccount => EmptyAccount::new_err(err.to_string()),
+            BankError::InvalidAccountCharacter { .. } => InvalidAccountCharacter::new_err(err.to_string()),
+            BankError::InvalidRut { .. } => InvalidAccountRut::new_err(err.to_string()),
+            BankError::Verifier(e) => e.into(),
+        }
+    }
+}
+
+impl From<RoleError> for PyErr {
+    fn from(err: RoleError) -> PyErr {
+        match err {
+            RoleError::EmptyRole => EmptyRole::new_err(err.to_string()),
+            RoleError::InvalidFormat { .. } => InvalidRoleFormat::new_err(err.to_string()),
+            RoleError::UnknownCaseType { .. } => UnknownCaseType::new_err(err.to_string()),
+            RoleError::InvalidYear { .. } => InvalidRoleYear::new_err(err.to_string()),
+            RoleError::InvalidComuna { .. } => InvalidComunaCode::new_err(err.to_string()),
         }
     }
 }
@@ -120,7 +369,9 @@ impl From<GenerateError> for PyErr {
 }
 
 
-#[pyclass(frozen)]
+// The module is the one registered in `sys.modules`, so that instances
+// can be pickled.
+#[pyclass(frozen, eq, ord, hash, module = "cl_forge.core.impl.rs_cl_forge.rs_verify")]
 struct Ppu {
     #[pyo3(get)]
     raw: String,
@@ -133,19 +384,71 @@ struct Ppu {
     format: enums::PpuFormat,
 }
 
+// Value semantics are based on the normalized PPU only, so that e.g.
+// `Ppu("BBC12") == Ppu("bbc012")`. Every other field derives from it.
+impl PartialEq for Ppu {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized == other.normalized
+    }
+}
+
+impl Eq for Ppu {}
+
+impl std::hash::Hash for Ppu {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.normalized.hash(state);
+    }
+}
+
+impl PartialOrd for Ppu {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ppu {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.normalized.cmp(&other.normalized)
+    }
+}
+
 #[pymethods]
 impl Ppu {
     /// Create a new `Ppu` instance.
     #[new]
     fn new(ppu: &str) -> PyResult<Self> {
         let raw = ppu;
-        let normalized = utils::normalize_ppu(&raw)?;
-        let format = utils::get_ppu_format(&raw).unwrap();
+        let normalized = utils::normalize_ppu(raw)?;
+        let format = utils::get_ppu_format(raw).unwrap();
         let numeric = utils::ppu_to_numeric(&normalized)?;
         let verifier = utils::calculate_verifier(numeric)?;
         Ok(Self { raw: ppu.to_string(), numeric, normalized, verifier, format })
     }
 
+    /// Create a new `Ppu` instance from its numeric representation.
+    #[staticmethod]
+    fn from_numeric(numeric: u32) -> PyResult<Self> {
+        let ppu = utils::numeric_to_ppu(numeric)?;
+        Self::new(&ppu)
+    }
+
+    /// Create a new `Ppu` instance from a PPU followed by its verifier.
+    ///
+    /// `raw` is the PPU without its verifier, so that `__reduce__` can
+    /// pass it back to `__new__`.
+    #[staticmethod]
+    fn from_complete(complete: &str) -> PyResult<Self> {
+        let ppu = utils::parse_complete_ppu(complete)?;
+        Self::new(ppu)
+    }
+
+    fn __reduce__<'py>(
+            &self,
+            py: Python<'py>
+    ) -> (Bound<'py, pyo3::types::PyType>, (String,)) {
+        (py.get_type::<Self>(), (self.raw.clone(),))
+    }
+
     #[getter]
     fn format(&self) -> String {
         self.format.as_str().to_string()
@@ -156,6 +459,15 @@ impl Ppu {
         format!("{}-{}", self.normalized, self.verifier)
     }
 
+    #[getter]
+    fn inspection_month(&self) -> PyResult<u32> {
+        Ok(schedule::get_inspection_month(&self.normalized)?)
+    }
+
+    fn is_restricted(&self, date: NaiveDate) -> PyResult<bool> {
+        Ok(schedule::is_restricted(&self.normalized, date, &RESTRICTION_RULES)?)
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "Ppu(\
@@ -176,6 +488,593 @@ impl Ppu {
 }
 
 
+#[pyclass(frozen)]
+struct Plate {
+    #[pyo3(get)]
+    raw: String,
+    #[pyo3(get)]
+    normalized: String,
+    format: enums::PlateFormat,
+}
+
+#[pymethods]
+impl Plate {
+    /// Create a new `Plate` instance.
+    #[new]
+    #[pyo3(signature = (plate, country=None))]
+    fn new(plate: &str, country: Option<&str>) -> PyResult<Self> {
+        let country = country
+            .map(enums::Country::try_from)
+            .transpose()?;
+        let parsed = plates::parse_plate(plate, country)?;
+        Ok(Self {
+            raw: plate.to_string(),
+            normalized: parsed.normalized,
+            format: parsed.format,
+        })
+    }
+
+    #[getter]
+    fn format(&self) -> String {
+        self.format.as_str().to_string()
+    }
+
+    #[getter]
+    fn country(&self) -> String {
+        self.format.country().as_str().to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Plate(\
+                raw='{}', \
+                normalized='{}', \
+                format='{}', \
+                country='{}')",
+            self.raw,
+            self.normalized,
+            self.format(),
+            self.country()
+        )
+    }
+}
+
+
+#[pyclass(frozen)]
+struct Vin {
+    #[pyo3(get)]
+    raw: String,
+    inner: vin::Vin,
+}
+
+#[pymethods]
+impl Vin {
+    /// Create a new `Vin` instance.
+    #[new]
+    fn new(vin: &str) -> PyResult<Self> {
+        let inner = vin::Vin::new(vin)?;
+        Ok(Self { raw: vin.to_string(), inner })
+    }
+
+    #[getter]
+    fn normalized(&self) -> String {
+        self.inner.normalized.clone()
+    }
+
+    #[getter]
+    fn wmi(&self) -> String {
+        self.inner.wmi().to_string()
+    }
+
+    #[getter]
+    fn vds(&self) -> String {
+        self.inner.vds().to_string()
+    }
+
+    #[getter]
+    fn vis(&self) -> String {
+        self.inner.vis().to_string()
+    }
+
+    #[getter]
+    fn check_digit(&self) -> char {
+        self.inner.check_digit()
+    }
+
+    #[getter]
+    fn is_valid(&self) -> bool {
+        self.inner.has_valid_check_digit()
+    }
+
+    #[getter]
+    fn manufacturer(&self) -> Option<&'static str> {
+        self.inner.manufacturer()
+    }
+
+    #[getter]
+    fn region(&self) -> Option<&'static str> {
+        self.inner.region()
+    }
+
+    #[getter]
+    fn model_year(&self) -> Option<u32> {
+        self.inner.model_year()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Vin(\
+                raw='{}', \
+                normalized='{}', \
+                check_digit='{}', \
+                manufacturer='{}', \
+                model_year='{}')",
+            self.raw,
+            self.inner.normalized,
+            self.check_digit(),
+            self.manufacturer().unwrap_or("None"),
+            self.model_year().map_or("None".to_string(), |y| y.to_string()),
+        )
+    }
+}
+
+
+#[pyclass(frozen)]
+struct IdCardMrz {
+    inner: idcard::IdCardMrz,
+}
+
+#[pymethods]
+impl IdCardMrz {
+    /// Create a new `IdCardMrz` instance.
+    #[new]
+    fn new(mrz: &str) -> PyResult<Self> {
+        let inner = idcard::parse_mrz(mrz)?;
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.inner.kind().as_str()
+    }
+
+    #[getter]
+    fn document_code(&self) -> String {
+        self.inner.document_code.clone()
+    }
+
+    #[getter]
+    fn document_number(&self) -> String {
+        self.inner.document_number.clone()
+    }
+
+    #[getter]
+    fn birth_date(&self) -> NaiveDate {
+        self.inner.birth_date
+    }
+
+    #[getter]
+    fn sex(&self) -> char {
+        self.inner.sex
+    }
+
+    #[getter]
+    fn expiry_date(&self) -> NaiveDate {
+        self.inner.expiry_date
+    }
+
+    #[getter]
+    fn nationality(&self) -> String {
+        self.inner.nationality.clone()
+    }
+
+    #[getter]
+    fn optional_data(&self) -> String {
+        self.inner.optional_data.clone()
+    }
+
+    #[getter]
+    fn optional_data_2(&self) -> String {
+        self.inner.optional_data_2.clone()
+    }
+
+    #[getter]
+    fn surnames(&self) -> String {
+        self.inner.surnames.clone()
+    }
+
+    #[getter]
+    fn given_names(&self) -> String {
+        self.inner.given_names.clone()
+    }
+
+    #[getter]
+    fn run(&self) -> Option<String> {
+        self.inner.run
+            .as_ref()
+            .map(|r| format!("{}-{}", r.correlative, r.verifier))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "IdCardMrz(\
+                document_number='{}', \
+                kind='{}', \
+                run='{}', \
+                expiry_date='{}')",
+            self.inner.document_number,
+            self.kind(),
+            self.run().unwrap_or("None".to_string()),
+            self.inner.expiry_date,
+        )
+    }
+}
+
+
+#[pyclass(frozen)]
+struct IdCardQr {
+    #[pyo3(get)]
+    url: String,
+    inner: idcard::IdCardQr,
+}
+
+#[pymethods]
+impl IdCardQr {
+    /// Create a new `IdCardQr` instance.
+    #[new]
+    fn new(url: &str) -> PyResult<Self> {
+        let inner = idcard::parse_qr_url(url)?;
+        Ok(Self { url: url.to_string(), inner })
+    }
+
+    #[getter]
+    fn run(&self) -> String {
+        format!("{}-{}", self.inner.run.correlative, self.inner.run.verifier)
+    }
+
+    #[getter]
+    fn document_type(&self) -> String {
+        self.inner.document_type.clone()
+    }
+
+    #[getter]
+    fn serial(&self) -> String {
+        self.inner.serial.clone()
+    }
+
+    #[getter]
+    fn birth_date(&self) -> NaiveDate {
+        self.inner.birth_date
+    }
+
+    #[getter]
+    fn expiry_date(&self) -> NaiveDate {
+        self.inner.expiry_date
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "IdCardQr(\
+                run='{}', \
+                document_type='{}', \
+                serial='{}', \
+                expiry_date='{}')",
+            self.run(),
+            self.inner.document_type,
+            self.inner.serial,
+            self.inner.expiry_date,
+        )
+    }
+}
+
+
+#[pyfunction]
+fn validate_document_number(number: &str) -> PyResult<&'static str> {
+    match idcard::validate_document_number(number) {
+        Ok(kind) => Ok(kind.as_str()),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn calculate_mrz_check_digit(field: &str) -> PyResult<String> {
+    match idcard::calculate_mrz_check_digit(field) {
+        Ok(digit) => Ok(digit.to_string()),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyclass(frozen)]
+struct Phone {
+    #[pyo3(get)]
+    raw: String,
+    inner: phone::PhoneNumber,
+}
+
+#[pymethods]
+impl Phone {
+    /// Create a new `Phone` instance.
+    #[new]
+    fn new(phone: &str) -> PyResult<Self> {
+        let inner = phone::parse_phone(phone)?;
+        Ok(Self { raw: phone.to_string(), inner })
+    }
+
+    #[getter]
+    fn national(&self) -> String {
+        self.inner.national.clone()
+    }
+
+    #[getter]
+    fn e164(&self) -> String {
+        self.inner.e164()
+    }
+
+    #[getter]
+    fn international(&self) -> String {
+        self.inner.international()
+    }
+
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.inner.kind.as_str()
+    }
+
+    #[getter]
+    fn prefix(&self) -> &'static str {
+        self.inner.prefix
+    }
+
+    #[getter]
+    fn description(&self) -> &'static str {
+        self.inner.description
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Phone(\
+                raw='{}', \
+                e164='{}', \
+                kind='{}', \
+                description='{}')",
+            self.raw,
+            self.inner.e164(),
+            self.kind(),
+            self.inner.description,
+        )
+    }
+}
+
+
+#[pyfunction]
+fn normalize_phone(phone: &str) -> PyResult<String> {
+    match phone::normalize_phone(phone) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn validate_phone(phone: &str) -> bool {
+    phone::validate_phone(phone)
+}
+
+
+#[pyclass(frozen, eq, hash)]
+#[derive(PartialEq, Eq, Hash)]
+struct Bank {
+    inner: &'static structs::Bank,
+}
+
+#[pymethods]
+impl Bank {
+    /// Create a new `Bank` instance.
+    #[new]
+    fn new(query: &str) -> PyResult<Self> {
+        let inner = banks::get_bank(query)?;
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn code(&self) -> &'static str {
+        self.inner.code
+    }
+
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.inner.name
+    }
+
+    #[getter]
+    fn short_name(&self) -> &'static str {
+        self.inner.short_name
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Bank(code='{}', name='{}')", self.inner.code, self.inner.name)
+    }
+}
+
+
+#[pyfunction]
+fn get_banks() -> Vec<Bank> {
+    banks::get_banks()
+        .iter()
+        .map(|inner| Bank { inner })
+        .collect()
+}
+
+
+#[pyfunction]
+fn normalize_account_number(account: &str) -> PyResult<String> {
+    match banks::normalize_account_number(account) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn validate_cuenta_rut(account: &str, digits: u32, verifier: &str) -> PyResult<bool> {
+    // Checks the verifier format, as `validate_rut` does.
+    utils::validate_rut(digits, verifier)?;
+
+    let rut = utils::Rut {
+        correlative: digits,
+        verifier: verifier.trim().chars().next().unwrap_or_default(),
+    };
+
+    match banks::validate_cuenta_rut(account, &rut) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyclass(frozen, eq, hash)]
+#[derive(PartialEq, Eq, Hash)]
+struct CaseRole {
+    inner: roles::CaseRole,
+}
+
+#[pymethods]
+impl CaseRole {
+    /// Create a new `CaseRole` instance.
+    #[new]
+    fn new(role: &str) -> PyResult<Self> {
+        let inner = roles::parse_case_role(role)?;
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn case_type(&self) -> Option<&'static str> {
+        self.inner.case_type.map(|t| t.as_str())
+    }
+
+    #[getter]
+    fn tribunal(&self) -> &'static str {
+        self.inner.tribunal().as_str()
+    }
+
+    #[getter]
+    fn number(&self) -> u32 {
+        self.inner.number
+    }
+
+    #[getter]
+    fn year(&self) -> i32 {
+        self.inner.year
+    }
+
+    #[getter]
+    fn normalized(&self) -> String {
+        self.inner.normalized()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CaseRole('{}')", self.inner.normalized())
+    }
+}
+
+
+#[pyclass(frozen, eq, hash)]
+#[derive(PartialEq, Eq, Hash)]
+struct PropertyRole {
+    inner: roles::PropertyRole,
+}
+
+#[pymethods]
+impl PropertyRole {
+    /// Create a new `PropertyRole` instance.
+    #[new]
+    fn new(role: &str, comuna: u32) -> PyResult<Self> {
+        let inner = roles::parse_property_role(role, comuna)?;
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn comuna(&self) -> u32 {
+        self.inner.comuna
+    }
+
+    #[getter]
+    fn block(&self) -> u32 {
+        self.inner.block
+    }
+
+    #[getter]
+    fn lot(&self) -> u32 {
+        self.inner.lot
+    }
+
+    #[getter]
+    fn normalized(&self) -> String {
+        self.inner.normalized()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PropertyRole('{}', comuna={})", self.inner.normalized(), self.inner.comuna)
+    }
+}
+
+
+#[pyfunction]
+fn normalize_case_role(role: &str) -> PyResult<String> {
+    match roles::normalize_case_role(role) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn validate_case_role(role: &str) -> bool {
+    roles::validate_case_role(role)
+}
+
+
+#[pyfunction]
+fn normalize_property_role(role: &str) -> PyResult<String> {
+    match roles::normalize_property_role(role) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn validate_vin(vin: &str) -> PyResult<bool> {
+    match vin::validate_vin(vin) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn calculate_vin_check_digit(vin: &str) -> PyResult<String> {
+    match vin::calculate_vin_check_digit(vin) {
+        Ok(digit) => Ok(digit.to_string()),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn get_plate_formats(plate: &str) -> Vec<(String, String)> {
+    plates::get_plate_formats(plate)
+        .iter()
+        .map(|fmt| (
+            fmt.country().as_str().to_string(),
+            fmt.as_str().to_string()
+        ))
+        .collect()
+}
+
+
 #[pyfunction]
 fn normalize_ppu(ppu: &str) -> PyResult<String> {
     match utils::normalize_ppu(ppu) {
@@ -211,6 +1110,34 @@ fn validate_rut(digits: u32, verifier: &str) -> PyResult<bool> {
 }
 
 
+#[pyfunction]
+fn get_inspection_month(ppu: &str) -> PyResult<u32> {
+    match schedule::get_inspection_month(ppu) {
+        Ok(month) => Ok(month),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
+#[pyfunction]
+fn get_restricted_digits(date: NaiveDate) -> Vec<u32> {
+    // `Vec<u8>` would be converted to `bytes` instead of a list.
+    schedule::get_restricted_digits(date, &RESTRICTION_RULES)
+        .into_iter()
+        .map(u32::from)
+        .collect()
+}
+
+
+#[pyfunction]
+fn is_restricted(ppu: &str, date: NaiveDate) -> PyResult<bool> {
+    match schedule::is_restricted(ppu, date, &RESTRICTION_RULES) {
+        Ok(result) => Ok(result),
+        Err(msg) => Err(msg.into()),
+    }
+}
+
+
 #[pyfunction]
 #[pyo3(signature = (n, min, max, seed=None))]
 fn generate(
@@ -243,7 +1170,31 @@ pub fn rs_verify(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(normalize_ppu, m)?)?;
     m.add_function(wrap_pyfunction!(validate_rut, m)?)?;
     m.add_function(wrap_pyfunction!(generate, m)?)?;
+    m.add_function(wrap_pyfunction!(get_inspection_month, m)?)?;
+    m.add_function(wrap_pyfunction!(get_restricted_digits, m)?)?;
+    m.add_function(wrap_pyfunction!(is_restricted, m)?)?;
+    m.add_function(wrap_pyfunction!(get_plate_formats, m)?)?;
     m.add_class::<Ppu>()?;
+    m.add_function(wrap_pyfunction!(validate_vin, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_vin_check_digit, m)?)?;
+    m.add_class::<Plate>()?;
+    m.add_class::<Vin>()?;
+    m.add_function(wrap_pyfunction!(validate_document_number, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_mrz_check_digit, m)?)?;
+    m.add_class::<IdCardMrz>()?;
+    m.add_class::<IdCardQr>()?;
+    m.add_function(wrap_pyfunction!(normalize_phone, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_phone, m)?)?;
+    m.add_class::<Phone>()?;
+    m.add_function(wrap_pyfunction!(get_banks, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_account_number, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_cuenta_rut, m)?)?;
+    m.add_class::<Bank>()?;
+    m.add_function(wrap_pyfunction!(normalize_case_role, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_case_role, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_property_role, m)?)?;
+    m.add_class::<CaseRole>()?;
+    m.add_class::<PropertyRole>()?;
 
     m.add("PpuException", m.py().get_type::<PpuException>())?;
     m.add("UnknownFormat", m.py().get_type::<UnknownFormat>())?;
@@ -253,6 +1204,50 @@ pub fn rs_verify(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("UnknownDigraph", m.py().get_type::<UnknownDigraph>())?;
     m.add("EmptyDigraph", m.py().get_type::<EmptyDigraph>())?;
     m.add("ParsingError", m.py().get_type::<ParsingError>())?;
+    m.add("InvalidNumeric", m.py().get_type::<InvalidNumeric>())?;
+    m.add("VerifierMismatch", m.py().get_type::<VerifierMismatch>())?;
+    m.add("MissingVerifier", m.py().get_type::<MissingVerifier>())?;
+
+    m.add("PlateException", m.py().get_type::<PlateException>())?;
+    m.add("UnknownPlateFormat", m.py().get_type::<UnknownPlateFormat>())?;
+    m.add("CountryMismatch", m.py().get_type::<CountryMismatch>())?;
+    m.add("UnknownCountry", m.py().get_type::<UnknownCountry>())?;
+
+    m.add("VinException", m.py().get_type::<VinException>())?;
+    m.add("EmptyVin", m.py().get_type::<EmptyVin>())?;
+    m.add("InvalidVinLength", m.py().get_type::<InvalidVinLength>())?;
+    m.add("InvalidVinCharacter", m.py().get_type::<InvalidVinCharacter>())?;
+
+    m.add("IdCardException", m.py().get_type::<IdCardException>())?;
+    m.add("InvalidMrzLength", m.py().get_type::<InvalidMrzLength>())?;
+    m.add("InvalidMrzCharacter", m.py().get_type::<InvalidMrzCharacter>())?;
+    m.add("UnsupportedDocument", m.py().get_type::<UnsupportedDocument>())?;
+    m.add("InvalidCheckDigit", m.py().get_type::<InvalidCheckDigit>())?;
+    m.add("InvalidDate", m.py().get_type::<InvalidDate>())?;
+    m.add("InvalidDocumentNumber", m.py().get_type::<InvalidDocumentNumber>())?;
+    m.add("InvalidRun", m.py().get_type::<InvalidRun>())?;
+    m.add("InvalidQrUrl", m.py().get_type::<InvalidQrUrl>())?;
+    m.add("MissingQrParameter", m.py().get_type::<MissingQrParameter>())?;
+    m.add("SerialMismatch", m.py().get_type::<SerialMismatch>())?;
+
+    m.add("PhoneException", m.py().get_type::<PhoneException>())?;
+    m.add("EmptyPhone", m.py().get_type::<EmptyPhone>())?;
+    m.add("InvalidPhoneCharacter", m.py().get_type::<InvalidPhoneCharacter>())?;
+    m.add("UnknownPhonePrefix", m.py().get_type::<UnknownPhonePrefix>())?;
+    m.add("InvalidPhoneLength", m.py().get_type::<InvalidPhoneLength>())?;
+
+    m.add("BankException", m.py().get_type::<BankException>())?;
+    m.add("UnknownBank", m.py().get_type::<UnknownBank>())?;
+    m.add("EmptyAccount", m.py().get_type::<EmptyAccount>())?;
+    m.add("InvalidAccountCharacter", m.py().get_type::<InvalidAccountCharacter>())?;
+    m.add("InvalidAccountRut", m.py().get_type::<InvalidAccountRut>())?;
+
+    m.add("RoleException", m.py().get_type::<RoleException>())?;
+    m.add("EmptyRole", m.py().get_type::<EmptyRole>())?;
+    m.add("InvalidRoleFormat", m.py().get_type::<InvalidRoleFormat>())?;
+    m.add("UnknownCaseType", m.py().get_type::<UnknownCaseType>())?;
+    m.add("InvalidRoleYear", m.py().get_type::<InvalidRoleYear>())?;
+    m.add("InvalidComunaCode", m.py().get_type::<InvalidComunaCode>())?;
 
     m.add("VerifierException", m.py().get_type::<VerifierException>())?;
     m.add("EmptyVerifier", m.py().get_type::<EmptyVerifier>())?;
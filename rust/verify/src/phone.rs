@@ -0,0 +1,217 @@
+use crate::enums::PhoneKind;
+use crate::errors::PhoneError;
+use crate::constants::NUMBERING_PLAN;
+
+
+/// Country calling code of Chile.
+const COUNTRY_CODE: &str = "56";
+
+
+/// Separators commonly found in written phone numbers, removed before
+/// parsing (e.g. `"(2) 2345-6789"`, `"9.1234.5678"`).
+const SEPARATORS: [char; 5] = [' ', '-', '.', '(', ')'];
+
+
+/// Represents a Chilean phone number.
+///
+/// # Examples
+/// ```
+/// use verify::phone::parse_phone;
+/// use verify::enums::PhoneKind;
+///
+/// let phone = parse_phone("(32) 212 3456").unwrap();
+/// assert_eq!(phone.national, "322123456");
+/// assert_eq!(phone.kind, PhoneKind::Fixed);
+/// assert_eq!(phone.prefix, "32");
+/// assert_eq!(phone.description, "Valparaíso");
+/// assert_eq!(phone.e164(), "+56322123456");
+/// assert_eq!(phone.international(), "+56 32 212 3456");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumber {
+    /// The national number: digits only, without country code nor trunk
+    /// prefix.
+    pub national: String,
+    /// The kind of the number.
+    pub kind: PhoneKind,
+    /// The area code, `9` for mobile numbers or the service code.
+    pub prefix: &'static str,
+    /// The zone of fixed numbers, or the name of the service.
+    pub description: &'static str,
+}
+
+/// Implements methods for the [`PhoneNumber`] struct.
+impl PhoneNumber {
+    /// Returns the number in E.164 format, e.g. `"+56912345678"`.
+    pub fn e164(&self) -> String {
+        format!("+{}{}", COUNTRY_CODE, self.national)
+    }
+
+    /// Returns the number in international format, with the prefix and
+    /// the subscriber number grouped by spaces, e.g. `"+56 9 1234 5678"`.
+    pub fn international(&self) -> String {
+        let subscriber = &self.national[self.prefix.len()..];
+        let split = match subscriber.len() {
+            6 => 3,
+            n => n - 4,
+        };
+
+        format!(
+            "+{} {} {} {}",
+            COUNTRY_CODE,
+            self.prefix,
+            &subscriber[..split],
+            &subscriber[split..],
+        )
+    }
+}
+
+
+/// Removes separators and checks the alphabet of a phone number.
+///
+/// Returns the digits and whether the number had a leading `'+'`.
+fn clean_phone(phone: &str) -> Result<(String, bool), PhoneError> {
+    let phone = phone.trim();
+    let plus = phone.starts_with('+');
+    let mut digits = String::with_capacity(phone.len());
+
+    for (i, c) in phone.chars().enumerate() {
+        match c {
+            '0'..='9' => digits.push(c),
+            '+' if i == 0 => {}
+            c if SEPARATORS.contains(&c) => {}
+            _ => return Err(PhoneError::InvalidCharacter { character: c, position: i + 1 }),
+        }
+    }
+
+    Ok((digits, plus))
+}
+
+
+/// Parses a Chilean phone number and classifies it using [`NUMBERING_PLAN`].
+///
+/// # Arguments
+/// * `phone` - A reference to a string slice containing the phone number.
+///
+/// # Returns
+/// * `Ok(PhoneNumber)` - The parsed phone number.
+/// * `Err(PhoneError)`:
+///   - [`PhoneError::EmptyPhone`] - If the number has no digits.
+///   - [`PhoneError::InvalidCharacter`] - If the number has characters
+///     other than digits, separators (spaces, `-`, `.`, `(` and `)`) and
+///     a leading `+`.
+///   - [`PhoneError::UnknownPrefix`] - If the number is not Chilean, or
+///     does not start with any prefix of the numbering plan.
+///   - [`PhoneError::InvalidLength`] - If the number does not have the
+///     length its prefix requires.
+///
+/// # Behavior
+/// After removing separators, the country code (`+56`, `56` or `0056`)
+/// is removed. Otherwise, the trunk prefix `0` used before 2016 (e.g.
+/// `"02 2345 6789"`) is removed. The remaining digits are matched against
+/// [`NUMBERING_PLAN`].
+///
+/// # Examples
+/// ```
+/// use verify::phone::parse_phone;
+/// use verify::enums::PhoneKind;
+///
+/// let phone = parse_phone("9 1234 5678").unwrap();
+/// assert_eq!(phone.kind, PhoneKind::Mobile);
+/// assert_eq!(phone.e164(), "+56912345678");
+///
+/// let phone = parse_phone("0221234567").unwrap();
+/// assert_eq!(phone.description, "Santiago");
+/// assert_eq!(phone.international(), "+56 2 2123 4567");
+///
+/// let phone = parse_phone("600 360 7777").unwrap();
+/// assert_eq!(phone.kind, PhoneKind::Special);
+///
+/// assert!(parse_phone("+54 11 2345 6789").is_err());
+/// assert!(parse_phone("9 1234 567").is_err());
+/// assert!(parse_phone("9 1234 567a").is_err());
+/// ```
+pub fn parse_phone(phone: &str) -> Result<PhoneNumber, PhoneError> {
+    let (digits, plus) = clean_phone(phone)?;
+
+    if digits.is_empty() {
+        return Err(PhoneError::EmptyPhone);
+    }
+
+    let unknown_prefix = || PhoneError::UnknownPrefix { phone: phone.to_string() };
+
+    let national = if plus {
+        digits.strip_prefix(COUNTRY_CODE).ok_or_else(unknown_prefix)?
+    } else if let Some(rest) = digits.strip_prefix("00") {
+        rest.strip_prefix(COUNTRY_CODE).ok_or_else(unknown_prefix)?
+    } else if let Some(rest) = digits.strip_prefix(COUNTRY_CODE) {
+        // No national number starts with 56, so the country code is
+        // unambiguous even without a leading '+'.
+        rest
+    } else {
+        digits.strip_prefix('0').unwrap_or(&digits)
+    };
+
+    let entry = NUMBERING_PLAN
+        .iter()
+        .find(|e| national.starts_with(e.prefix))
+        .ok_or_else(unknown_prefix)?;
+
+    if national.len() != entry.length {
+        return Err(PhoneError::InvalidLength {
+            expected: entry.length,
+            actual: national.len(),
+            phone: phone.to_string(),
+        });
+    }
+
+    Ok(PhoneNumber {
+        national: national.to_string(),
+        kind: entry.kind,
+        prefix: entry.prefix,
+        description: entry.description,
+    })
+}
+
+
+/// Normalizes a Chilean phone number to E.164 format.
+///
+/// # Arguments
+/// * `phone` - A reference to a string slice containing the phone number.
+///
+/// # Returns
+/// * `Ok(String)` - The number in E.164 format, e.g. `"+56912345678"`.
+/// * `Err(PhoneError)` - See [`parse_phone`].
+///
+/// # Examples
+/// ```
+/// use verify::phone::normalize_phone;
+///
+/// assert_eq!(normalize_phone("+56 2 2345 6789").unwrap(), "+56223456789");
+/// assert_eq!(normalize_phone("0056-9-1234-5678").unwrap(), "+56912345678");
+/// assert!(normalize_phone("").is_err());
+/// ```
+pub fn normalize_phone(phone: &str) -> Result<String, PhoneError> {
+    Ok(parse_phone(phone)?.e164())
+}
+
+
+/// Validates a Chilean phone number.
+///
+/// # Arguments
+/// * `phone` - A reference to a string slice containing the phone number.
+///
+/// # Returns
+/// `true` if the number can be parsed with [`parse_phone`], `false`
+/// otherwise.
+///
+/// # Examples
+/// ```
+/// use verify::phone::validate_phone;
+///
+/// assert!(validate_phone("+56912345678"));
+/// assert!(!validate_phone("+56 1 2345 6789"));
+/// ```
+pub fn validate_phone(phone: &str) -> bool {
+    parse_phone(phone).is_ok()
+}
@@ -0,0 +1,190 @@
+use crate::enums::Country;
+use crate::enums::PlateFormat;
+use crate::errors::PlateError;
+use crate::utils::get_ppu_format;
+use crate::utils::normalize_ppu;
+
+
+/// Separators commonly found in printed or typed plates, removed before
+/// format detection (e.g. `"AB 123 CD"`, `"ABC-1234"`, `"BB·CD·12"`).
+const SEPARATORS: [char; 4] = [' ', '-', '.', '·'];
+
+
+/// Foreign formats in detection priority, see [`get_plate_formats`].
+const FOREIGN_FORMATS: [PlateFormat; 5] = [
+    PlateFormat::ArgentinaLegacy,
+    PlateFormat::ArgentinaMercosur,
+    PlateFormat::BrazilLegacy,
+    PlateFormat::BrazilMercosur,
+    PlateFormat::Peru,
+];
+
+
+/// Represents a vehicle license plate with its detected format.
+///
+/// # Examples
+/// ```
+/// use verify::plates::parse_plate;
+/// use verify::enums::{Country, PlateFormat};
+///
+/// let plate = parse_plate("ab 123 cd", None).unwrap();
+/// assert_eq!(plate.normalized, "AB123CD");
+/// assert_eq!(plate.format, PlateFormat::ArgentinaMercosur);
+/// assert_eq!(plate.country(), Country::Argentina);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plate {
+    /// The normalized plate: uppercase and without separators.
+    pub normalized: String,
+    /// The detected format of the plate.
+    pub format: PlateFormat,
+}
+
+/// Implements methods for the [`Plate`] struct.
+impl Plate {
+    /// Returns the country that issued the plate.
+    pub fn country(&self) -> Country {
+        self.format.country()
+    }
+}
+
+
+/// Checks whether a plate matches a `L`/`N`/`X` pattern, as documented in
+/// [`PlateFormat`].
+fn matches_pattern(plate: &[u8], pattern: &str) -> bool {
+    plate.len() == pattern.len()
+        && plate.iter().zip(pattern.bytes()).all(|(c, p)| match p {
+            b'L' => c.is_ascii_uppercase(),
+            b'N' => c.is_ascii_digit(),
+            _ => c.is_ascii_uppercase() || c.is_ascii_digit(),
+        })
+}
+
+
+/// Removes separators and converts the plate to uppercase ASCII.
+fn clean_plate(plate: &str) -> String {
+    plate
+        .trim()
+        .chars()
+        .filter(|c| !SEPARATORS.contains(c))
+        .collect::<String>()
+        .to_ascii_uppercase()
+}
+
+
+/// Detects every known format a vehicle license plate matches.
+///
+/// # Arguments
+/// * `plate` - A reference to a string slice containing the plate.
+///
+/// # Returns
+/// The matching formats in detection priority: Chile first, then
+/// Argentina, Brazil and Peru. The vector is empty if the plate does not
+/// match any known format.
+///
+/// # Behavior
+/// The input is first cleaned by trimming whitespaces, removing common
+/// separators (spaces, `-`, `.` and `·`) and converting it to uppercase
+/// ASCII. Chilean formats are detected with [`get_ppu_format`].
+///
+/// # Notes
+/// Some plates are valid in more than one country. For instance, `ABC123`
+/// matches the Chilean `LLLNNN`, the Argentine legacy and the Peruvian
+/// formats.
+///
+/// # Examples
+/// ```
+/// use verify::plates::get_plate_formats;
+/// use verify::enums::{PlateFormat, PpuFormat};
+///
+/// assert_eq!(get_plate_formats("ABC1D23"), vec![PlateFormat::BrazilMercosur]);
+/// assert_eq!(
+///     get_plate_formats("ABC-123"),
+///     vec![
+///         PlateFormat::Chile(PpuFormat::LLLNNN),
+///         PlateFormat::ArgentinaLegacy,
+///         PlateFormat::Peru,
+///     ]
+/// );
+/// assert!(get_plate_formats("12345").is_empty());
+/// ```
+pub fn get_plate_formats(plate: &str) -> Vec<PlateFormat> {
+    let plate = clean_plate(plate);
+    let mut formats = Vec::new();
+
+    if let Some(fmt) = get_ppu_format(&plate) {
+        formats.push(PlateFormat::Chile(fmt));
+    }
+
+    formats.extend(
+        FOREIGN_FORMATS
+            .iter()
+            .filter(|fmt| matches_pattern(plate.as_bytes(), fmt.as_str()))
+    );
+
+    formats
+}
+
+
+/// Parses a vehicle license plate, detecting its format and country.
+///
+/// # Arguments
+/// * `plate` - A reference to a string slice containing the plate.
+/// * `country` - An optional country to restrict the detection to.
+///
+/// # Returns
+/// * `Ok(Plate)` - The normalized plate and its detected format.
+/// * `Err(PlateError)`:
+///   - [`PlateError::UnknownFormat`] - If the plate does not match any
+///     known format.
+///   - [`PlateError::CountryMismatch`] - If the plate does not match any
+///     known format of the given `country`.
+///
+/// # Behavior
+/// When `country` is `None`, the first format returned by
+/// [`get_plate_formats`] is used. Chilean plates are normalized with
+/// [`normalize_ppu`], so `LLLNN` plates become `LLL0NN` exactly as in the
+/// `Ppu` class; other plates are only cleaned.
+///
+/// # Examples
+/// ```
+/// use verify::plates::parse_plate;
+/// use verify::enums::{Country, PlateFormat};
+///
+/// // Ambiguous plates default to Chile.
+/// let plate = parse_plate("ABC123", None).unwrap();
+/// assert_eq!(plate.country(), Country::Chile);
+///
+/// // But can be parsed for a given country.
+/// let plate = parse_plate("ABC123", Some(Country::Peru)).unwrap();
+/// assert_eq!(plate.format, PlateFormat::Peru);
+///
+/// // Chilean plates are normalized as PPUs.
+/// let plate = parse_plate("bbc-12", None).unwrap();
+/// assert_eq!(plate.normalized, "BBC012");
+///
+/// assert!(parse_plate("ABC1D23", Some(Country::Chile)).is_err());
+/// assert!(parse_plate("INVALID", None).is_err());
+/// ```
+pub fn parse_plate(plate: &str, country: Option<Country>) -> Result<Plate, PlateError> {
+    let cleaned = clean_plate(plate);
+
+    let format = get_plate_formats(&cleaned)
+        .into_iter()
+        .find(|fmt| country.is_none_or(|c| fmt.country() == c))
+        .ok_or_else(|| match country {
+            Some(c) => PlateError::CountryMismatch {
+                plate: plate.to_string(),
+                country: c.as_str().to_string(),
+            },
+            None => PlateError::UnknownFormat { plate: plate.to_string() },
+        })?;
+
+    let normalized = match format {
+        // Format was already detected, so normalization cannot fail.
+        PlateFormat::Chile(_) => normalize_ppu(&cleaned).unwrap(),
+        _ => cleaned,
+    };
+
+    Ok(Plate { normalized, format })
+}
\ No newline at end of file
@@ -0,0 +1,322 @@
+use chrono::Datelike;
+use chrono::Utc;
+
+use crate::enums::CaseType;
+use crate::enums::Tribunal;
+use crate::errors::RoleError;
+
+
+/// First year accepted in case roles.
+const MIN_CASE_YEAR: i32 = 1900;
+
+
+/// Maximum number of digits of the block and lot of a property role.
+const MAX_PROPERTY_DIGITS: usize = 5;
+
+
+/// Highest comuna code accepted in property roles.
+const MAX_COMUNA_CODE: u32 = 99_999;
+
+
+/// Represents the role (rol) of a case of the Poder Judicial.
+///
+/// # Examples
+/// ```
+/// use verify::enums::CaseType;
+/// use verify::enums::Tribunal;
+/// use verify::roles::parse_case_role;
+///
+/// let role = parse_case_role("c-01234-2023").unwrap();
+/// assert_eq!(role.case_type, Some(CaseType::Contencioso));
+/// assert_eq!(role.tribunal(), Tribunal::Juzgado);
+/// assert_eq!(role.number, 1234);
+/// assert_eq!(role.year, 2023);
+/// assert_eq!(role.normalized(), "C-1234-2023");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CaseRole {
+    /// The type of the case, given by the prefix of the role. Roles of the
+    /// Corte Suprema have no prefix.
+    pub case_type: Option<CaseType>,
+    /// The correlative number of the case in the year.
+    pub number: u32,
+    /// The year the case was filed.
+    pub year: i32,
+}
+
+/// Implements methods for the [`CaseRole`] struct.
+impl CaseRole {
+    /// Returns the tribunal of the case, told by the prefix of the role.
+    pub fn tribunal(&self) -> Tribunal {
+        self.case_type.map_or(Tribunal::CorteSuprema, |t| t.tribunal())
+    }
+
+    /// Returns the role as written by the Poder Judicial, e.g.
+    /// `"C-1234-2023"` or `"Protección-1234-2023"`, or `"1234-2023"`
+    /// without a case type.
+    pub fn normalized(&self) -> String {
+        match self.case_type {
+            Some(case_type) => format!("{}-{}-{}", case_type.as_str(), self.number, self.year),
+            None => format!("{}-{}", self.number, self.year),
+        }
+    }
+}
+
+
+/// Represents the role (rol de avalúo) of a property in the SII, which
+/// identifies it within a comuna.
+///
+/// # Examples
+/// ```
+/// use verify::roles::parse_property_role;
+///
+/// let role = parse_property_role("01234-056", 13101).unwrap();
+/// assert_eq!(role.comuna, 13101);
+/// assert_eq!(role.block, 1234);
+/// assert_eq!(role.lot, 56);
+/// assert_eq!(role.normalized(), "1234-56");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PropertyRole {
+    /// The code of the comuna where the property is located.
+    pub comuna: u32,
+    /// The block (manzana) number.
+    pub block: u32,
+    /// The lot (predio) number within the block.
+    pub lot: u32,
+}
+
+/// Implements methods for the [`PropertyRole`] struct.
+impl PropertyRole {
+    /// Returns the role as written by the SII, e.g. `"1234-56"`.
+    pub fn normalized(&self) -> String {
+        format!("{}-{}", self.block, self.lot)
+    }
+}
+
+
+/// Splits a role into its parts, separated by `'-'` or whitespace, in
+/// uppercase.
+fn split_role(role: &str) -> Result<Vec<String>, RoleError> {
+    let role = role.trim().to_ascii_uppercase();
+
+    if role.is_empty() {
+        return Err(RoleError::EmptyRole);
+    }
+
+    Ok(role
+        .split(|c: char| c == '-' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+
+/// Parses a positive number of at most `max_digits` digits.
+fn parse_part(part: &str, max_digits: usize) -> Option<u32> {
+    if part.is_empty() || part.len() > max_digits || !part.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    part.parse().ok().filter(|n| *n > 0)
+}
+
+
+/// Parses the block and lot numbers of a property role.
+fn parse_block_and_lot(role: &str) -> Result<(u32, u32), RoleError> {
+    let parts = split_role(role)?;
+    let invalid = || RoleError::InvalidFormat { role: role.trim().to_string() };
+
+    let [block, lot] = parts.as_slice() else {
+        return Err(invalid());
+    };
+
+    Ok((
+        parse_part(block, MAX_PROPERTY_DIGITS).ok_or_else(invalid)?,
+        parse_part(lot, MAX_PROPERTY_DIGITS).ok_or_else(invalid)?,
+    ))
+}
+
+
+/// Parses the role of a case of the Poder Judicial.
+///
+/// # Arguments
+/// * `role` - A reference to a string slice containing the role, e.g.
+///   `"C-1234-2023"`.
+///
+/// # Returns
+/// * `Ok(CaseRole)` - The parsed role.
+/// * `Err(RoleError)`:
+///   - [`RoleError::EmptyRole`] - If the role is empty.
+///   - [`RoleError::InvalidFormat`] - If the role is not an optional
+///     prefix, a positive number and a four-digit year.
+///   - [`RoleError::UnknownCaseType`] - If the prefix is not one of
+///     [`CaseType`].
+///   - [`RoleError::InvalidYear`] - If the year is before 1900 or after
+///     the current year.
+///
+/// # Behavior
+/// The role is trimmed, and its parts may be separated by `'-'` or
+/// whitespace. The prefix is the letter of the case type in first-instance
+/// courts, or the book of the case in Cortes de Apelaciones, which may span
+/// several words and is matched ignoring case and accents. Leading zeros of
+/// the number are removed.
+///
+/// # Examples
+/// ```
+/// use verify::enums::CaseType;
+/// use verify::enums::Tribunal;
+/// use verify::roles::parse_case_role;
+///
+/// assert_eq!(parse_case_role("T 15 2024").unwrap().normalized(), "T-15-2024");
+/// assert_eq!(parse_case_role("1234-2023").unwrap().tribunal(), Tribunal::CorteSuprema);
+///
+/// let role = parse_case_role("proteccion-1234-2023").unwrap();
+/// assert_eq!(role.case_type, Some(CaseType::Proteccion));
+/// assert_eq!(role.tribunal(), Tribunal::CorteApelaciones);
+/// assert_eq!(role.normalized(), "Protección-1234-2023");
+/// assert_eq!(
+///     parse_case_role("Laboral-Cobranza-87-2019").unwrap().case_type,
+///     Some(CaseType::LaboralCobranza)
+/// );
+///
+/// assert!(parse_case_role("C-1234").is_err());
+/// assert!(parse_case_role("C-1234-23").is_err());
+/// assert!(parse_case_role("CA-1234-2023").is_err());
+/// assert!(parse_case_role("C-0-2023").is_err());
+/// assert!(parse_case_role("C-1234-1899").is_err());
+/// ```
+pub fn parse_case_role(role: &str) -> Result<CaseRole, RoleError> {
+    let parts = split_role(role)?;
+    let invalid = || RoleError::InvalidFormat { role: role.trim().to_string() };
+
+    let [prefix @ .., number, year] = parts.as_slice() else {
+        return Err(invalid());
+    };
+
+    let case_type = match prefix {
+        [] => None,
+        words => Some(CaseType::try_from(words.join(" ").as_str())?),
+    };
+
+    let number = parse_part(number, 9).ok_or_else(invalid)?;
+
+    if year.len() != 4 {
+        return Err(invalid());
+    }
+    let year = parse_part(year, 4).ok_or_else(invalid)? as i32;
+
+    let max = Utc::now().year();
+    if !(MIN_CASE_YEAR..=max).contains(&year) {
+        return Err(RoleError::InvalidYear { year, min: MIN_CASE_YEAR, max });
+    }
+
+    Ok(CaseRole { case_type, number, year })
+}
+
+
+/// Normalizes the role of a case of the Poder Judicial.
+///
+/// # Arguments
+/// * `role` - A reference to a string slice containing the role.
+///
+/// # Returns
+/// * `Ok(String)` - The normalized role, e.g. `"C-1234-2023"`.
+/// * `Err(RoleError)` - See [`parse_case_role`].
+///
+/// # Examples
+/// ```
+/// use verify::roles::normalize_case_role;
+///
+/// assert_eq!(normalize_case_role(" v 00087 2019 ").unwrap(), "V-87-2019");
+/// assert!(normalize_case_role("").is_err());
+/// ```
+pub fn normalize_case_role(role: &str) -> Result<String, RoleError> {
+    Ok(parse_case_role(role)?.normalized())
+}
+
+
+/// Validates the role of a case of the Poder Judicial.
+///
+/// # Returns
+/// `true` if the role can be parsed with [`parse_case_role`], `false`
+/// otherwise.
+///
+/// # Examples
+/// ```
+/// use verify::roles::validate_case_role;
+///
+/// assert!(validate_case_role("C-1234-2023"));
+/// assert!(!validate_case_role("C-1234-3023"));
+/// ```
+pub fn validate_case_role(role: &str) -> bool {
+    parse_case_role(role).is_ok()
+}
+
+
+/// Parses the role of a property in the SII.
+///
+/// # Arguments
+/// * `role` - A reference to a string slice containing the role, e.g.
+///   `"1234-56"`.
+/// * `comuna` - The code of the comuna where the property is located.
+///
+/// # Returns
+/// * `Ok(PropertyRole)` - The parsed role.
+/// * `Err(RoleError)`:
+///   - [`RoleError::EmptyRole`] - If the role is empty.
+///   - [`RoleError::InvalidFormat`] - If the role is not a block and a lot
+///     number, both positive and of at most five digits.
+///   - [`RoleError::InvalidComuna`] - If the comuna code is zero or has
+///     more than five digits.
+///
+/// # Behavior
+/// The block and lot may be separated by `'-'` or whitespace, and their
+/// leading zeros are removed.
+///
+/// # Notes
+/// The SII identifies comunas with its own codes, which differ from the
+/// CUT codes of the `territory` crate. The comuna code is only checked to
+/// be in range.
+///
+/// # Examples
+/// ```
+/// use verify::roles::parse_property_role;
+///
+/// assert_eq!(parse_property_role("1234 56", 15105).unwrap().normalized(), "1234-56");
+///
+/// assert!(parse_property_role("1234", 15105).is_err());
+/// assert!(parse_property_role("1234-0", 15105).is_err());
+/// assert!(parse_property_role("123456-7", 15105).is_err());
+/// assert!(parse_property_role("1234-56", 0).is_err());
+/// ```
+pub fn parse_property_role(role: &str, comuna: u32) -> Result<PropertyRole, RoleError> {
+    if comuna == 0 || comuna > MAX_COMUNA_CODE {
+        return Err(RoleError::InvalidComuna { comuna });
+    }
+
+    let (block, lot) = parse_block_and_lot(role)?;
+    Ok(PropertyRole { comuna, block, lot })
+}
+
+
+/// Normalizes the role of a property in the SII.
+///
+/// # Arguments
+/// * `role` - A reference to a string slice containing the role.
+///
+/// # Returns
+/// * `Ok(String)` - The normalized role, e.g. `"1234-56"`.
+/// * `Err(RoleError)` - See [`parse_property_role`].
+///
+/// # Examples
+/// ```
+/// use verify::roles::normalize_property_role;
+///
+/// assert_eq!(normalize_property_role("00123-045").unwrap(), "123-45");
+/// assert!(normalize_property_role("123-45-6").is_err());
+/// ```
+pub fn normalize_property_role(role: &str) -> Result<String, RoleError> {
+    let (block, lot) = parse_block_and_lot(role)?;
+    Ok(format!("{}-{}", block, lot))
+}
@@ -0,0 +1,152 @@
+use chrono::Datelike;
+use chrono::NaiveDate;
+
+use crate::errors::PpuError;
+use crate::utils::normalize_ppu;
+use crate::constants::INSPECTION_MONTH_MAP;
+use crate::structs::RestrictionRule;
+
+
+/// Gets the last digit of a Chilean PPU (vehicle license plate).
+///
+/// # Arguments
+/// * `ppu` - A reference to a string slice containing the PPU.
+///
+/// # Returns
+/// * `Ok(u8)` - The last digit of the normalized PPU.
+/// * `Err(PpuError::UnknownFormat)` - If the input PPU is not recognized as
+///   one of the supported formats.
+///
+/// # Examples
+/// ```
+/// use verify::schedule::get_last_digit;
+///
+/// assert_eq!(get_last_digit("PHZF55").unwrap(), 5);
+/// assert_eq!(get_last_digit("bbc12").unwrap(), 2);
+/// assert!(get_last_digit("INVALID").is_err());
+/// ```
+pub fn get_last_digit(ppu: &str) -> Result<u8, PpuError> {
+    let normalized = normalize_ppu(ppu)?;
+
+    // Every supported format ends with a digit, so the
+    // last byte of a normalized PPU is always '0'..'9'.
+    Ok(normalized.as_bytes()[normalized.len() - 1] - b'0')
+}
+
+
+/// Gets the month of the periodic technical inspection ("revisión técnica")
+/// of a private vehicle.
+///
+/// # Arguments
+/// * `ppu` - A reference to a string slice containing the PPU.
+///
+/// # Returns
+/// * `Ok(u32)` - The inspection month, from `1` (January) to `12`
+///   (December).
+/// * `Err(PpuError::UnknownFormat)` - If the input PPU is not recognized as
+///   one of the supported formats.
+///
+/// # Behavior
+/// The last digit of the PPU is looked up in [`INSPECTION_MONTH_MAP`]. No
+/// digit is scheduled for March or December.
+///
+/// # Examples
+/// ```
+/// use verify::schedule::get_inspection_month;
+///
+/// assert_eq!(get_inspection_month("PHZF59").unwrap(), 1);
+/// assert_eq!(get_inspection_month("PHZF50").unwrap(), 2);
+/// assert_eq!(get_inspection_month("BR1231").unwrap(), 4);
+/// ```
+pub fn get_inspection_month(ppu: &str) -> Result<u32, PpuError> {
+    let digit = get_last_digit(ppu)?;
+
+    // INSPECTION_MONTH_MAP covers every digit, so the lookup cannot fail.
+    Ok(
+        INSPECTION_MONTH_MAP
+            .iter()
+            .find(|(d, _)| *d == digit)
+            .map(|(_, month)| *month)
+            .unwrap()
+    )
+}
+
+
+/// Gets the last PPU digits restricted on a given date.
+///
+/// # Arguments
+/// * `date` - The date to check.
+/// * `rules` - The restriction table, e.g. [`RESTRICTION_RULES`].
+///
+/// # Returns
+/// The restricted digits in ascending order, or an empty vector if no rule
+/// applies to the given date.
+///
+/// # Notes
+/// - Holidays are not taken into account; rules are matched only by month
+///   and day of the week.
+///
+/// [`RESTRICTION_RULES`]: crate::constants::RESTRICTION_RULES
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use verify::constants::RESTRICTION_RULES;
+/// use verify::schedule::get_restricted_digits;
+///
+/// // Monday, January 6th
+/// let date = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+/// assert_eq!(get_restricted_digits(date, &RESTRICTION_RULES), vec![1, 2]);
+///
+/// // Saturday, January 4th
+/// let date = NaiveDate::from_ymd_opt(2025, 1, 4).unwrap();
+/// assert!(get_restricted_digits(date, &RESTRICTION_RULES).is_empty());
+/// ```
+pub fn get_restricted_digits(date: NaiveDate, rules: &[RestrictionRule]) -> Vec<u8> {
+    let mut digits: Vec<u8> = rules
+        .iter()
+        .filter(|r| r.weekday == date.weekday() && r.months.contains(&date.month()))
+        .flat_map(|r| r.digits.iter().copied())
+        .collect();
+
+    digits.sort_unstable();
+    digits.dedup();
+    digits
+}
+
+
+/// Checks whether a vehicle is under driving restriction on a given date.
+///
+/// # Arguments
+/// * `ppu` - A reference to a string slice containing the PPU.
+/// * `date` - The date to check.
+/// * `rules` - The restriction table, e.g. [`RESTRICTION_RULES`].
+///
+/// # Returns
+/// * `Ok(true)` - If the last digit of the PPU is restricted on `date`.
+/// * `Ok(false)` - Otherwise.
+/// * `Err(PpuError::UnknownFormat)` - If the input PPU is not recognized as
+///   one of the supported formats.
+///
+/// [`RESTRICTION_RULES`]: crate::constants::RESTRICTION_RULES
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use verify::constants::RESTRICTION_RULES;
+/// use verify::schedule::is_restricted;
+///
+/// // Friday, May 9th
+/// let date = NaiveDate::from_ymd_opt(2025, 5, 9).unwrap();
+/// assert!(is_restricted("PHZF50", date, &RESTRICTION_RULES).unwrap());
+/// assert!(!is_restricted("PHZF55", date, &RESTRICTION_RULES).unwrap());
+/// ```
+pub fn is_restricted(
+        ppu: &str,
+        date: NaiveDate,
+        rules: &[RestrictionRule]
+) -> Result<bool, PpuError> {
+    let digit = get_last_digit(ppu)?;
+
+    Ok(get_restricted_digits(date, rules).contains(&digit))
+}
\ No newline at end of file
@@ -0,0 +1,84 @@
+use chrono::Weekday;
+
+use crate::enums::PhoneKind;
+
+
+/// A single entry of a driving restriction table.
+///
+/// A vehicle is restricted on a given date when the date falls in one of
+/// `months`, on `weekday`, and the last digit of its PPU is in `digits`.
+///
+/// # Examples
+/// ```
+/// use chrono::Weekday;
+/// use verify::structs::RestrictionRule;
+///
+/// let rule = RestrictionRule {
+///     months: &[1, 5, 9],
+///     weekday: Weekday::Mon,
+///     digits: &[1, 2],
+/// };
+/// assert!(rule.digits.contains(&2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestrictionRule {
+    /// Months (1-12) in which the rule applies.
+    pub months: &'static [u32],
+    /// Day of the week in which the rule applies.
+    pub weekday: Weekday,
+    /// Last PPU digits restricted by the rule.
+    pub digits: &'static [u8],
+}
+
+
+/// A single entry of the numbering plan.
+///
+/// A national number belongs to the entry when it starts with `prefix` and
+/// has exactly `length` digits.
+///
+/// # Examples
+/// ```
+/// use verify::enums::PhoneKind;
+/// use verify::structs::NumberingPlanEntry;
+///
+/// let entry = NumberingPlanEntry {
+///     prefix: "9",
+///     kind: PhoneKind::Mobile,
+///     length: 9,
+///     description: "Mobile",
+/// };
+/// assert_eq!(entry.kind.as_str(), "mobile");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberingPlanEntry {
+    /// Leading digits of the national number: the area code for fixed
+    /// numbers, `9` for mobile numbers or the service code.
+    pub prefix: &'static str,
+    /// Kind of the numbers in this entry.
+    pub kind: PhoneKind,
+    /// Number of digits of the national number, including the prefix.
+    pub length: usize,
+    /// The zone of fixed numbers, or the name of the service.
+    pub description: &'static str,
+}
+
+
+/// Represents a bank operating in Chile.
+///
+/// # Examples
+/// ```
+/// use verify::banks::get_bank;
+///
+/// let bank = get_bank("16").unwrap();
+/// assert_eq!(bank.code, "016");
+/// assert_eq!(bank.short_name, "BCI");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bank {
+    /// The institution code assigned by the CMF, e.g. `"012"`.
+    pub code: &'static str,
+    /// The legal name of the bank.
+    pub name: &'static str,
+    /// The commercial name of the bank.
+    pub short_name: &'static str,
+}
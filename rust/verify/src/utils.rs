@@ -8,8 +8,12 @@ use crate::enums::PpuFormat;
 use crate::errors::PpuError;
 use crate::errors::VerifierError;
 use crate::errors::GenerateError;
+use crate::constants::NO_INDEX;
 use crate::constants::LETTER_MAP;
 use crate::constants::DIGRAPH_MAP;
+use crate::constants::LETTER_INDEX;
+use crate::constants::DIGRAPH_INDEX;
+use crate::constants::DIGRAPH_VALUE_INDEX;
 
 
 /// Represents a Chilean RUT (Rol Único Tributario) with its correlative number
@@ -79,13 +83,13 @@ impl Rut {
 /// - `N` = ASCII digit (`0`–`9`)
 ///
 /// # Behavior
-/// The input is first normalized by trimming whitespaces and converting it to
-/// uppercase ASCII characters. The function then attempts to match the PPU
-/// against the supported formats based on its length and character position.
+/// The input is first trimmed from whitespaces. The function then attempts
+/// to match the PPU against the supported formats based on its length and
+/// character position. Letters are matched case-insensitively.
 ///
 /// # Notes
-/// This function performs no allocation other than temporary normalization
-/// and does not validate semantic rules beyond format structure.
+/// This function performs no allocation and does not validate semantic rules
+/// beyond format structure.
 ///
 /// # Examples
 /// ```
@@ -101,8 +105,7 @@ impl Rut {
 /// assert_eq!(get_ppu_format("12345"), None);
 /// ```
 pub fn get_ppu_format(ppu: &str) -> Option<PpuFormat> {
-    let ppu = ppu.trim().to_ascii_uppercase();
-    let ppu_bytes = ppu.as_bytes();
+    let ppu_bytes = ppu.trim().as_bytes();
 
     match ppu_bytes.len() {
         5 => {
@@ -146,7 +149,7 @@ pub fn get_ppu_format(ppu: &str) -> Option<PpuFormat> {
 /// # Returns
 /// * `Ok(String)` - The normalized PPU string if the input is valid.
 /// * `Err(PpuError::UnknownFormat)` - If the input PPU is not recognized as
-///    one of the supported formats.
+///   one of the supported formats.
 ///
 /// # Behavior
 /// The function trims leading and trailing whitespace from the input `ppu`
@@ -196,15 +199,56 @@ pub fn normalize_ppu(ppu: &str) -> Result<String, PpuError> {
 }
 
 
+/// Gets the position of a letter in [`LETTER_MAP`], case-insensitively.
+#[inline]
+fn letter_index(letter: u8) -> Option<usize> {
+    if !letter.is_ascii_alphabetic() {
+        return None;
+    }
+
+    match LETTER_INDEX[(letter.to_ascii_uppercase() - b'A') as usize] {
+        NO_INDEX => None,
+        i => Some(i as usize),
+    }
+}
+
+
+/// Gets the position of a digraph in [`DIGRAPH_MAP`], case-insensitively.
+#[inline]
+fn digraph_index(first: u8, second: u8) -> Option<usize> {
+    if !first.is_ascii_alphabetic() || !second.is_ascii_alphabetic() {
+        return None;
+    }
+
+    let first = (first.to_ascii_uppercase() - b'A') as usize;
+    let second = (second.to_ascii_uppercase() - b'A') as usize;
+
+    match DIGRAPH_INDEX[first * 26 + second] {
+        NO_INDEX => None,
+        i => Some(i as usize),
+    }
+}
+
+
+/// Accumulates ASCII digits into `acc`, e.g. `b"123"` with `acc = 4` gives
+/// `4123`.
+#[inline]
+fn push_digits(acc: u32, digits: &[u8]) -> u32 {
+    digits
+        .iter()
+        .fold(acc, |acc, d| acc * 10 + (d - b'0') as u32)
+}
+
+
 /// Gets the value associated with a given letter from the `LETTER_MAP`.
 ///
 /// # Parameters
 /// * `letter` - A string slice representing the letter to look up. It is
-///    expected to be a single-character string.
+///   expected to be a single-character string.
 ///
 /// # Returns
 /// * `Ok(&str)` - The value associated with the letter if it exists in the
-///    `LETTER_MAP`.
+///   `LETTER_MAP`.
 /// * `Err(PpuError)`:
 ///     - [`PpuError::EmptyLetter`] - If the input is an empty string.
 ///     - [`PpuError::InvalidLength`] - If the input is not a
@@ -213,9 +257,9 @@ pub fn normalize_ppu(ppu: &str) -> Result<String, PpuError> {
 ///       `LETTER_MAP`.
 ///
 /// # Behavior
-/// This function converts the input letter to its uppercase ASCII
-/// representation and looks up the corresponding value in the `LETTER_MAP`.
-/// If the letter is not found, it returns an error.
+/// The letter is looked up case-insensitively through [`LETTER_INDEX`], so
+/// the lookup is a single array access. Allocation only happens when
+/// building an error.
 ///
 /// # Examples
 /// ```
@@ -229,26 +273,20 @@ pub fn normalize_ppu(ppu: &str) -> Result<String, PpuError> {
 /// let result = get_letter_value("A");
 /// assert!(result.is_err());
 /// ```
-pub fn get_letter_value(letter: &str) -> Result<&str, PpuError> {
-    let letter = letter.to_ascii_uppercase();
-
-    if letter.is_empty() {
-        return Err(PpuError::EmptyLetter);
-    }
-
-    if letter.len() != 1 {
-        return Err(
+pub fn get_letter_value(letter: &str) -> Result<&'static str, PpuError> {
+    match letter.as_bytes() {
+        [] => Err(PpuError::EmptyLetter),
+        [c] => letter_index(*c)
+            .map(|i| LETTER_MAP[i].1)
+            .ok_or_else(|| PpuError::UnknownLetter {
+                letter: letter.to_ascii_uppercase()
+            }),
+        _ => Err(
             PpuError::InvalidLength {
-                expected: 1, actual: letter.len(), chars: letter
+                expected: 1, actual: letter.len(), chars: letter.to_ascii_uppercase()
             }
-        );
+        ),
     }
-
-    LETTER_MAP
-        .iter()
-        .find(|(k, _)| *k == letter)
-        .map(|(_, value)| *value)
-        .ok_or_else(|| PpuError::UnknownLetter { letter })
 }
 
 
@@ -256,7 +294,7 @@ pub fn get_letter_value(letter: &str) -> Result<&str, PpuError> {
 ///
 /// # Arguments
 /// * `letters` - A string slice representing the two-letter digraph whose
-///    value needs to be retrieved.
+///   value needs to be retrieved.
 ///
 /// # Returns
 /// * `Ok(&str)` - The value associated with the given digraph if it exists.
@@ -268,9 +306,9 @@ pub fn get_letter_value(letter: &str) -> Result<&str, PpuError> {
 ///       `DIGRAPH_MAP`.
 ///
 /// # Behavior
-/// This function converts the input letters to its uppercase ASCII
-/// representation and looks up the corresponding value in the `DIGRAPH_MAP`.
-/// If the letters are not found, it returns an error.
+/// The digraph is looked up case-insensitively through [`DIGRAPH_INDEX`], so
+/// the lookup is a single array access. Allocation only happens when
+/// building an error.
 ///
 /// # Example
 /// ```
@@ -284,26 +322,20 @@ pub fn get_letter_value(letter: &str) -> Result<&str, PpuError> {
 /// let result = verify::utils::get_letter_value("MM");
 /// assert!(result.is_err());
 /// ```
-pub fn get_digraph_value(letters: &str) -> Result<&str, PpuError> {
-    let letters = letters.to_ascii_uppercase();
-
-    if letters.is_empty() {
-        return Err(PpuError::EmptyDigraph);
-    }
-
-    if letters.len() != 2 {
-        return Err(
+pub fn get_digraph_value(letters: &str) -> Result<&'static str, PpuError> {
+    match letters.as_bytes() {
+        [] => Err(PpuError::EmptyDigraph),
+        [a, b] => digraph_index(*a, *b)
+            .map(|i| DIGRAPH_MAP[i].1)
+            .ok_or_else(|| PpuError::UnknownDigraph {
+                letters: letters.to_ascii_uppercase()
+            }),
+        _ => Err(
             PpuError::InvalidLength {
-                expected: 2, actual: letters.len(), chars: letters
+                expected: 2, actual: letters.len(), chars: letters.to_ascii_uppercase()
             }
-        );
+        ),
     }
-
-    DIGRAPH_MAP
-        .iter()
-        .find(|(k, _)| *k == letters)
-        .map(|(_, v)| *v)
-        .ok_or_else(|| PpuError::UnknownDigraph { letters })
 }
 
 
@@ -318,7 +350,6 @@ pub fn get_digraph_value(letters: &str) -> Result<&str, PpuError> {
 ///   is valid and supported.
 /// * `Err(PpuError)`:
 ///   - [`PpuError::UnknownFormat`] - The PPU does not match any known format.
-///   - [`PpuError::ParsingError`] - If for some reason the parsing fails.
 ///
 /// # Behavior
 /// The input PPU is first normalized by trimming whitespace and converting it
@@ -338,8 +369,9 @@ pub fn get_digraph_value(letters: &str) -> Result<&str, PpuError> {
 /// See [`get_ppu_format`] for the list of recognized PPU formats.
 ///
 /// # Notes
-/// - This function performs no heap allocation other than building the output
-///   string.
+/// - This function performs no heap allocation, except when building an
+///   error. The numeric value is accumulated directly from the lookup
+///   tables [`LETTER_INDEX`] and [`DIGRAPH_INDEX`].
 /// - Semantic validation beyond format and mapping rules (e.g., verifier
 ///   digit) is not performed here.
 ///
@@ -357,40 +389,136 @@ pub fn get_digraph_value(letters: &str) -> Result<&str, PpuError> {
 /// assert!(ppu_to_numeric("INVALID").is_err());
 /// ```
 pub fn ppu_to_numeric(ppu: &str) -> Result<u32, PpuError> {
-    let ppu = ppu.trim().to_ascii_uppercase();
+    let ppu = ppu.trim();
+    let ppu_bytes = ppu.as_bytes();
 
-    let fmt = get_ppu_format(&ppu)
-        .ok_or_else(|| PpuError::UnknownFormat { ppu: ppu.clone() })?;
+    let fmt = get_ppu_format(ppu)
+        .ok_or_else(|| PpuError::UnknownFormat { ppu: ppu.to_ascii_uppercase() })?;
 
     if fmt == PpuFormat::LLNNNN {
-        let prefix = &ppu[..2];
-        let mapped = get_digraph_value(prefix)?;
-        let digits = &ppu[2..];
-        let mut output = String::with_capacity(
-            mapped.len() + digits.len()
-        );
-
-        output.push_str(mapped);
-        output.push_str(digits);
+        let mapped = get_digraph_value(&ppu[..2])?;
+        let numeric = push_digits(0, mapped.as_bytes());
 
-        return Ok(
-            output.parse::<u32>()?
-        );
+        return Ok(push_digits(numeric, &ppu_bytes[2..]));
     }
 
-    let mut output = String::with_capacity(ppu.len());
-    for c in ppu.chars() {
-        if c.is_ascii_digit() {
-            output.push(c);
-        } else if c.is_ascii_alphabetic() {
-            let letter = c.to_string();
-            let v = get_letter_value(&letter)?;
-            output.push_str(v);
-        }
+    ppu_bytes
+        .iter()
+        .try_fold(0, |acc, c| {
+            if c.is_ascii_digit() {
+                return Ok(push_digits(acc, &[*c]));
+            }
+
+            let i = letter_index(*c)
+                .ok_or_else(|| PpuError::UnknownLetter {
+                    letter: (*c as char).to_ascii_uppercase().to_string()
+                })?;
+
+            Ok(push_digits(acc, LETTER_MAP[i].1.as_bytes()))
+        })
+}
+
+
+/// Converts a numeric representation back into its Chilean PPU (vehicle
+/// license plate).
+///
+/// # Arguments
+/// * `numeric` - The numeric representation of the PPU, as returned by
+///   [`ppu_to_numeric`].
+///
+/// # Returns
+/// * `Ok(String)` - The normalized PPU in `LLNNNN` format.
+/// * `Err(PpuError::InvalidNumeric)` - If the leading digits do not match
+///   any value of [`DIGRAPH_MAP`].
+///
+/// # Behavior
+/// The last four digits are kept as-is and the leading digits are looked up
+/// through [`DIGRAPH_VALUE_INDEX`].
+///
+/// # Notes
+/// Only the `LLNNNN` format can be reversed: letter-by-letter mappings are
+/// not one-to-one (e.g. both `'C'` and `'R'` map to `'2'`), and leading
+/// zeros are lost in the numeric representation.
+///
+/// # Examples
+/// ```
+/// use verify::utils::{numeric_to_ppu, ppu_to_numeric};
+///
+/// assert_eq!(numeric_to_ppu(871234).unwrap(), "BR1234");
+/// assert_eq!(numeric_to_ppu(ppu_to_numeric("AA0001").unwrap()).unwrap(), "AA0001");
+///
+/// // No digraph maps to '000'
+/// assert!(numeric_to_ppu(1234).is_err());
+/// ```
+pub fn numeric_to_ppu(numeric: u32) -> Result<String, PpuError> {
+    let value = (numeric / 10_000) as usize;
+    let digits = numeric % 10_000;
+
+    let index = DIGRAPH_VALUE_INDEX
+        .get(value)
+        .filter(|i| **i != NO_INDEX)
+        .ok_or(PpuError::InvalidNumeric { numeric })?;
+
+    Ok(format!("{}{:04}", DIGRAPH_MAP[*index as usize].0, digits))
+}
+
+
+/// Parses a complete Chilean PPU (vehicle license plate), i.e. followed by
+/// its verifier digit, and validates the verifier.
+///
+/// # Arguments
+/// * `complete` - A reference to a string slice containing the PPU and its
+///   verifier, separated by `'-'` (e.g. `"PHZF55-K"`).
+///
+/// # Returns
+/// * `Ok(&str)` - The trimmed PPU, without verifier. See [`normalize_ppu`]
+///   to normalize it.
+/// * `Err(PpuError)`:
+///   - [`PpuError::UnknownFormat`] - If the PPU does not match any known
+///     format.
+///   - [`PpuError::VerifierMismatch`] - If the given verifier does not
+///     match the computed one.
+///   - [`PpuError::MissingVerifier`] - If there is no `'-'` before the
+///     verifier.
+///
+/// # Behavior
+/// The separator is required: without it, e.g. `"BBC123"` could be either
+/// `"BBC12"` with verifier `3`, or `"BBC123"` without verifier.
+///
+/// # Examples
+/// ```
+/// use verify::errors::PpuError;
+/// use verify::utils::parse_complete_ppu;
+///
+/// assert_eq!(parse_complete_ppu("PHZF55-K").unwrap(), "PHZF55");
+/// assert_eq!(parse_complete_ppu(" bbc12-3 ").unwrap(), "bbc12");
+///
+/// assert!(matches!(parse_complete_ppu("PHZF55-1"), Err(PpuError::VerifierMismatch { .. })));
+/// assert!(matches!(parse_complete_ppu("PHZF55K"), Err(PpuError::MissingVerifier { .. })));
+/// ```
+pub fn parse_complete_ppu(complete: &str) -> Result<&str, PpuError> {
+    let complete = complete.trim();
+
+    let (ppu, verifier) = complete
+        .rsplit_once('-')
+        .ok_or_else(|| PpuError::MissingVerifier { complete: complete.to_string() })?;
+
+    let ppu = ppu.trim();
+    let normalized = normalize_ppu(ppu)?;
+    let numeric = ppu_to_numeric(&normalized)?;
+
+    // The computation cannot fail, see `calculate_verifier`.
+    let expected = calculate_verifier(numeric).unwrap();
+
+    if !verifier.trim().eq_ignore_ascii_case(expected.encode_utf8(&mut [0; 4])) {
+        return Err(PpuError::VerifierMismatch {
+            ppu: normalized,
+            expected,
+            actual: verifier.trim().to_string(),
+        });
     }
-    Ok(
-        output.parse::<u32>()?
-    )
+
+    Ok(ppu)
 }
 
 
@@ -402,7 +530,7 @@ pub fn ppu_to_numeric(ppu: &str) -> Result<u32, PpuError> {
 ///
 /// # Returns
 /// * `Ok(char)`: The computed verifier digit. This may be a numeric character
-///    (`'0'` to `'9'`) or the character `'K'`.
+///   (`'0'` to `'9'`) or the character `'K'`.
 /// * `Err(VerifierError)`:
 ///   - [`VerifierError::UnexpectedComputation`]: An unexpected branch in
 ///     computation logic (should not occur under normal conditions).
@@ -593,10 +721,10 @@ pub fn generate(
         );
     }
 
-    if !seed.is_none() && seed.unwrap() < 0 {
+    if let Some(seed) = seed && seed < 0 {
         return Err(
             GenerateError::InvalidInput {
-                msg: format!("`seed` must be non-negative: '{}' was given.", seed.unwrap())
+                msg: format!("`seed` must be non-negative: '{}' was given.", seed)
             }
         );
     }
@@ -0,0 +1,306 @@
+use crate::errors::VinError;
+use crate::constants::WMI_MAP;
+use crate::constants::VIN_WEIGHTS;
+use crate::constants::MODEL_YEAR_MAP;
+use crate::constants::VIN_LETTER_MAP;
+
+
+/// Represents a VIN (Vehicle Identification Number) as defined by ISO 3779.
+///
+/// # Examples
+/// ```
+/// use verify::vin::Vin;
+///
+/// let vin = Vin::new("1m8gdm9axkp042788").unwrap();
+/// assert_eq!(vin.normalized, "1M8GDM9AXKP042788");
+/// assert_eq!(vin.wmi(), "1M8");
+/// assert_eq!(vin.check_digit(), 'X');
+/// assert!(vin.has_valid_check_digit());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vin {
+    /// The normalized VIN: trimmed and in uppercase.
+    pub normalized: String,
+}
+
+/// Implements methods for the [`Vin`] struct.
+impl Vin {
+    /// Creates a new `Vin` instance after normalizing and validating the
+    /// length and alphabet of the given VIN.
+    ///
+    /// # Arguments
+    /// * `vin` - A reference to a string slice containing the VIN.
+    ///
+    /// # Returns
+    /// * `Ok(Vin)` - A new `Vin` instance.
+    /// * `Err(VinError)` - See [`normalize_vin`].
+    ///
+    /// # Notes
+    /// The check digit is not validated here, since it is only mandatory
+    /// for vehicles built for North America. See
+    /// [`Vin::has_valid_check_digit`].
+    pub fn new(vin: &str) -> Result<Self, VinError> {
+        let normalized = normalize_vin(vin)?;
+        Ok(Vin { normalized })
+    }
+
+    /// Returns the World Manufacturer Identifier (positions 1 to 3).
+    pub fn wmi(&self) -> &str {
+        &self.normalized[..3]
+    }
+
+    /// Returns the Vehicle Descriptor Section (positions 4 to 9).
+    pub fn vds(&self) -> &str {
+        &self.normalized[3..9]
+    }
+
+    /// Returns the Vehicle Identifier Section (positions 10 to 17).
+    pub fn vis(&self) -> &str {
+        &self.normalized[9..]
+    }
+
+    /// Returns the check digit as written in the VIN (position 9).
+    pub fn check_digit(&self) -> char {
+        self.normalized.as_bytes()[8] as char
+    }
+
+    /// Returns whether the check digit written in the VIN matches the
+    /// computed one.
+    pub fn has_valid_check_digit(&self) -> bool {
+        // The VIN was already validated, so computation cannot fail.
+        calculate_vin_check_digit(&self.normalized).unwrap() == self.check_digit()
+    }
+
+    /// Returns the manufacturer from [`WMI_MAP`], if known.
+    pub fn manufacturer(&self) -> Option<&'static str> {
+        get_manufacturer(self.wmi())
+    }
+
+    /// Returns the region of manufacture, based on the first character.
+    pub fn region(&self) -> Option<&'static str> {
+        get_region(self.normalized.as_bytes()[0] as char)
+    }
+
+    /// Returns the model year, based on the model year character
+    /// (position 10). See [`get_model_year`].
+    pub fn model_year(&self) -> Option<u32> {
+        let bytes = self.normalized.as_bytes();
+        get_model_year(bytes[9] as char, bytes[6] as char)
+    }
+}
+
+
+/// Gets the numeric value of a VIN character used by the check digit.
+fn get_char_value(c: char) -> Option<u32> {
+    if let Some(digit) = c.to_digit(10) {
+        return Some(digit);
+    }
+
+    VIN_LETTER_MAP
+        .iter()
+        .find(|(k, _)| *k == c)
+        .map(|(_, v)| *v)
+}
+
+
+/// Normalizes and validates the length and alphabet of a VIN.
+///
+/// # Arguments
+/// * `vin` - A reference to a string slice containing the VIN.
+///
+/// # Returns
+/// * `Ok(String)` - The VIN trimmed and in uppercase.
+/// * `Err(VinError)`:
+///   - [`VinError::EmptyVin`] - If the VIN is empty.
+///   - [`VinError::InvalidLength`] - If the VIN does not have 17
+///     characters.
+///   - [`VinError::InvalidCharacter`] - If the VIN has a character other
+///     than digits and letters, or any of the letters `I`, `O` and `Q`.
+///
+/// # Examples
+/// ```
+/// use verify::vin::normalize_vin;
+///
+/// assert_eq!(normalize_vin(" wvwzzz1jzxw000001 ").unwrap(), "WVWZZZ1JZXW000001");
+/// assert!(normalize_vin("WVWZZZ1JZXW00000").is_err());
+/// assert!(normalize_vin("WVWZZZ1JZXW00000O").is_err());
+/// ```
+pub fn normalize_vin(vin: &str) -> Result<String, VinError> {
+    let vin = vin.trim().to_ascii_uppercase();
+
+    if vin.is_empty() {
+        return Err(VinError::EmptyVin);
+    }
+
+    if let Some((i, c)) = vin
+        .chars()
+        .enumerate()
+        .find(|(_, c)| get_char_value(*c).is_none())
+    {
+        return Err(VinError::InvalidCharacter { character: c, position: i + 1 });
+    }
+
+    if vin.len() != 17 {
+        return Err(VinError::InvalidLength { actual: vin.len(), vin });
+    }
+
+    Ok(vin)
+}
+
+
+/// Calculates the ISO 3779 check digit of a VIN.
+///
+/// # Arguments
+/// * `vin` - A reference to a string slice containing the VIN.
+///
+/// # Returns
+/// * `Ok(char)` - The computed check digit: `'0'` to `'9'` or `'X'`.
+/// * `Err(VinError)` - See [`normalize_vin`].
+///
+/// # Algorithm
+/// 1. Each character is transliterated to a number: digits keep their
+///    value and letters are mapped using [`VIN_LETTER_MAP`].
+/// 2. Compute the sum of each value multiplied by the weight of its
+///    position, from [`VIN_WEIGHTS`]. The check digit position (9) has
+///    weight 0.
+/// 3. The remainder of the sum (mod 11) is the check digit, where `10` is
+///    written as `'X'`.
+///
+/// # Examples
+/// ```
+/// use verify::vin::calculate_vin_check_digit;
+///
+/// assert_eq!(calculate_vin_check_digit("1M8GDM9AXKP042788").unwrap(), 'X');
+/// assert_eq!(calculate_vin_check_digit("11111111111111111").unwrap(), '1');
+/// ```
+pub fn calculate_vin_check_digit(vin: &str) -> Result<char, VinError> {
+    let vin = normalize_vin(vin)?;
+
+    let sum: u32 = vin
+        .chars()
+        .zip(VIN_WEIGHTS)
+        // Characters were already validated by `normalize_vin`.
+        .map(|(c, w)| get_char_value(c).unwrap() * w)
+        .sum();
+
+    let verifier = match sum % 11 {
+        10 => 'X',
+        n => char::from_digit(n, 10).unwrap(),
+    };
+
+    Ok(verifier)
+}
+
+
+/// Validates a VIN against its ISO 3779 check digit.
+///
+/// # Arguments
+/// * `vin` - A reference to a string slice containing the VIN.
+///
+/// # Returns
+/// * `Ok(true)` - If the check digit (position 9) matches the computed one.
+/// * `Ok(false)` - If the check digit does not match.
+/// * `Err(VinError)` - See [`normalize_vin`].
+///
+/// # Notes
+/// The check digit is mandatory only for vehicles built for North America.
+/// Many European and Asian manufacturers do not use it, so a `false`
+/// result does not necessarily mean the VIN is forged.
+///
+/// # Examples
+/// ```
+/// use verify::vin::validate_vin;
+///
+/// assert!(validate_vin("1M8GDM9AXKP042788").unwrap());
+/// assert!(!validate_vin("1M8GDM9A1KP042788").unwrap());
+/// assert!(validate_vin("INVALID").is_err());
+/// ```
+pub fn validate_vin(vin: &str) -> Result<bool, VinError> {
+    Ok(Vin::new(vin)?.has_valid_check_digit())
+}
+
+
+/// Gets the manufacturer of a World Manufacturer Identifier (WMI).
+///
+/// # Arguments
+/// * `wmi` - The first three characters of a VIN.
+///
+/// # Returns
+/// The manufacturer name if the WMI is in [`WMI_MAP`], `None` otherwise.
+///
+/// # Examples
+/// ```
+/// use verify::vin::get_manufacturer;
+///
+/// assert_eq!(get_manufacturer("9bw"), Some("Volkswagen"));
+/// assert_eq!(get_manufacturer("000"), None);
+/// ```
+pub fn get_manufacturer(wmi: &str) -> Option<&'static str> {
+    let wmi = wmi.trim().to_ascii_uppercase();
+
+    WMI_MAP
+        .iter()
+        .find(|(k, _)| *k == wmi)
+        .map(|(_, v)| *v)
+}
+
+
+/// Gets the region of manufacture from the first character of a VIN.
+///
+/// # Examples
+/// ```
+/// use verify::vin::get_region;
+///
+/// assert_eq!(get_region('9'), Some("South America"));
+/// assert_eq!(get_region('W'), Some("Europe"));
+/// assert_eq!(get_region('0'), None);
+/// ```
+pub fn get_region(c: char) -> Option<&'static str> {
+    match c.to_ascii_uppercase() {
+        'A'..='H' => Some("Africa"),
+        'J'..='R' => Some("Asia"),
+        'S'..='Z' => Some("Europe"),
+        '1'..='5' => Some("North America"),
+        '6' | '7' => Some("Oceania"),
+        '8' | '9' => Some("South America"),
+        _ => None,
+    }
+}
+
+
+/// Gets the model year from the model year character of a VIN.
+///
+/// # Arguments
+/// * `code` - The model year character (position 10).
+/// * `position_7` - The seventh character of the VIN.
+///
+/// # Returns
+/// The model year, or `None` if `code` is not a valid model year character.
+///
+/// # Behavior
+/// Model year characters repeat every 30 years (see [`MODEL_YEAR_MAP`]).
+/// Following the North American convention, a letter in position 7 means
+/// the 2010-2039 cycle, and a digit means the 1980-2009 cycle.
+///
+/// # Examples
+/// ```
+/// use verify::vin::get_model_year;
+///
+/// assert_eq!(get_model_year('K', '9'), Some(1989));
+/// assert_eq!(get_model_year('K', 'A'), Some(2019));
+/// assert_eq!(get_model_year('U', 'A'), None);
+/// ```
+pub fn get_model_year(code: char, position_7: char) -> Option<u32> {
+    let code = code.to_ascii_uppercase();
+
+    let year = MODEL_YEAR_MAP
+        .iter()
+        .find(|(k, _)| *k == code)
+        .map(|(_, v)| *v)?;
+
+    if position_7.is_ascii_alphabetic() {
+        Some(year + 30)
+    } else {
+        Some(year)
+    }
+}
\ No newline at end of file
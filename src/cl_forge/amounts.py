@@ -0,0 +1,23 @@
+from cl_forge.core.impl.rs_cl_forge.rs_amounts import (
+    amount_to_words,
+    format_currency,
+    format_number,
+    number_to_words,
+    parse_currency,
+    parse_number,
+    pesos_to_words,
+    round_decimal,
+    uf_to_words,
+)
+
+__all__ = (
+    "number_to_words",
+    "amount_to_words",
+    "pesos_to_words",
+    "uf_to_words",
+    "parse_number",
+    "parse_currency",
+    "round_decimal",
+    "format_number",
+    "format_currency",
+)
\ No newline at end of file
@@ -0,0 +1,19 @@
+from cl_forge.core.impl.rs_cl_forge.rs_calendar import (
+    Holiday,
+    add_business_days,
+    business_days_between,
+    get_holidays,
+    is_business_day,
+    is_holiday,
+    next_business_day,
+)
+
+__all__ = (
+    "Holiday",
+    "get_holidays",
+    "is_holiday",
+    "is_business_day",
+    "next_business_day",
+    "add_business_days",
+    "business_days_between",
+)
\ No newline at end of file
@@ -1,7 +1,8 @@
 from cl_forge.core.endpoints import Eur, Ipc, Uf, Usd, Utm
-from cl_forge.core.impl.rs_cl_forge.rs_cmf import CmfClient
+from cl_forge.core.impl.rs_cl_forge.rs_cmf import AsyncCmfClient, CmfClient
 
 __all__ = (
+    "AsyncCmfClient",
     "CmfClient",
     "Ipc",
     "Usd",
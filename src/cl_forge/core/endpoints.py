@@ -2,16 +2,20 @@ from __future__ import annotations
 
 from dataclasses import dataclass
 from datetime import datetime
-from functools import lru_cache
 from typing import TypeVar
 
 from pydantic import BaseModel
 
+from cl_forge.core.impl.rs_cl_forge.rs_base import Cache
 from cl_forge.core.impl.rs_cl_forge.rs_cmf import CmfClient
 from cl_forge.core.schemas import EurRecord, IpcRecord, UFRecord, UsdRecord, UTMRecord
 
 T = TypeVar("T", bound=BaseModel)
 
+# Responses shared by all the endpoints, kept for an hour. Indicators are
+# published at most once a day, so refetching them sooner is wasteful.
+_CACHE = Cache(ttl=3600.0)
+
 class CmfEndpoint[T: BaseModel]:
     """
     Base class for CMF API endpoints.
@@ -25,7 +29,7 @@ class CmfEndpoint[T: BaseModel]:
         record_class: type[T],
         root_key: str
     ) -> None:
-        client = CmfClient(api_key=api_key)
+        client = CmfClient(api_key=api_key, cache=_CACHE)
         object.__setattr__(self, "_client", client)
         object.__setattr__(self, "_path", path)
         object.__setattr__(self, "_record_class", record_class)
@@ -49,7 +53,6 @@ class CmfEndpoint[T: BaseModel]:
         )
 
     @staticmethod
-    @lru_cache
     def _fetch_current(
         client: CmfClient,
         path: str,
@@ -60,7 +63,6 @@ class CmfEndpoint[T: BaseModel]:
         return record_class(**raw[root_key][0])
 
     @staticmethod
-    @lru_cache
     def _fetch_year(
         client: CmfClient,
         path: str,
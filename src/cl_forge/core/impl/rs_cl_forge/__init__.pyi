@@ -1,3 +1,12 @@
-from . import rs_base, rs_cmf, rs_market, rs_verify
+from . import rs_amounts, rs_base, rs_calendar, rs_cmf, rs_dte, rs_market, rs_territory, rs_verify
 
-__all__ = ('rs_base', 'rs_cmf', 'rs_market', 'rs_verify',)
\ No newline at end of file
+__all__ = (
+    'rs_amounts',
+    'rs_base',
+    'rs_calendar',
+    'rs_cmf',
+    'rs_dte',
+    'rs_market',
+    'rs_territory',
+    'rs_verify',
+)
\ No newline at end of file
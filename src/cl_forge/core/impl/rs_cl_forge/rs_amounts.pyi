@@ -0,0 +1,306 @@
+from decimal import Decimal
+
+class AmountException(Exception):  # noqa: N818
+    """Base class for all exceptions raised when writing amounts."""
+
+class InvalidAmount(AmountException):
+    """Raised when the given amount is not a valid number."""
+
+class TooManyDecimals(AmountException):
+    """Raised when the given amount has more decimals than allowed."""
+
+
+class LocaleException(Exception):  # noqa: N818
+    """Base class for all exceptions raised when parsing or formatting numbers."""
+
+class EmptyNumber(LocaleException):
+    """Raised when the given number is empty."""
+
+class InvalidNumberCharacter(LocaleException):
+    """Raised when the given number has an invalid character."""
+
+class InvalidGrouping(LocaleException):
+    """Raised when the thousands separators of the given number are misplaced."""
+
+class NumberOutOfRange(LocaleException):
+    """Raised when the given number has too many digits."""
+
+class UnknownCurrency(LocaleException):
+    """Raised when the given currency is unknown."""
+
+class UnknownRounding(LocaleException):
+    """Raised when the given rounding mode is unknown."""
+
+
+def number_to_words(n: int, feminine: bool = False) -> str:
+    """
+    Writes a number in Spanish words.
+
+    Parameters
+    ----------
+    n : int
+        The number to write, non-negative.
+    feminine : bool, optional
+        Whether the noun the number refers to is feminine. Defaults to
+        False.
+
+    Returns
+    -------
+    str
+        The number in lowercase Spanish words, as read on its own, e.g.
+        'veintiuno' or, in feminine, 'veintiuna'.
+
+    Notes
+    -----
+    - Numbers follow the long scale used in Chile: a 'billón' is a million
+      millions (10^12), and 10^9 is 'mil millones'.
+    """
+
+
+def amount_to_words(
+    n: int,
+    singular: str,
+    plural: str,
+    feminine: bool = False,
+) -> str:
+    """
+    Writes an amount of a noun in Spanish words.
+
+    Parameters
+    ----------
+    n : int
+        The amount, non-negative.
+    singular : str
+        The noun in singular, e.g. 'peso'.
+    plural : str
+        The noun in plural, e.g. 'pesos'.
+    feminine : bool, optional
+        Whether the noun is feminine. Defaults to False.
+
+    Returns
+    -------
+    str
+        The amount in lowercase Spanish words, followed by the noun.
+
+    Notes
+    -----
+    - 'uno' is apocopated before masculine nouns ('un peso', 'veintiún
+      pesos').
+    - Round millions take 'de' before the noun ('un millón de pesos'), but
+      not other amounts ('un millón doscientos mil pesos').
+    """
+
+
+def pesos_to_words(n: int) -> str:
+    """
+    Writes an amount of Chilean pesos in Spanish words, as required on
+    cheques and legal documents.
+
+    Parameters
+    ----------
+    n : int
+        The amount of pesos, non-negative.
+
+    Returns
+    -------
+    str
+        The amount in Spanish words, e.g. 'un millón de pesos'.
+    """
+
+
+def uf_to_words(amount: str) -> str:
+    """
+    Writes an amount of UF (Unidades de Fomento) in Spanish words.
+
+    Parameters
+    ----------
+    amount : str
+        The amount, with ',' or '.' as decimal separator and no thousands
+        separators, e.g. '1234,5678'.
+
+    Returns
+    -------
+    str
+        The amount in Spanish words, in feminine, e.g. 'veintiuna coma
+        cero cinco Unidades de Fomento'.
+
+    Raises
+    ------
+    InvalidAmount
+        If the amount is not a non-negative decimal number.
+    TooManyDecimals
+        If the amount has more than 4 decimals.
+
+    Notes
+    -----
+    - Decimals are read after 'coma' as a number, preceded by a 'cero' for
+      each leading zero. Trailing zeros are ignored.
+    """
+
+
+def parse_number(text: str) -> Decimal:
+    """
+    Parses a number written in the Chilean locale.
+
+    Parameters
+    ----------
+    text : str
+        The number, with '.' as thousands separator and ',' as decimal
+        separator, e.g. '38.416,69'.
+
+    Returns
+    -------
+    Decimal
+        The exact value of the number.
+
+    Raises
+    ------
+    EmptyNumber
+        If the number has no digits.
+    InvalidNumberCharacter
+        If the number has characters other than digits, separators and a
+        leading sign.
+    InvalidGrouping
+        If the thousands separators are misplaced.
+    NumberOutOfRange
+        If the number has more than 28 significant digits.
+
+    Notes
+    -----
+    - Surrounding whitespace, and whitespace between the sign and the
+      digits, is ignored (e.g. '- 0,3').
+    - Thousands separators are optional, but must separate groups of 3
+      digits if present.
+    """
+
+
+def parse_currency(text: str) -> tuple[Decimal, str]:
+    """
+    Parses an amount of money written in the Chilean locale.
+
+    Parameters
+    ----------
+    text : str
+        The amount, with the symbol or code of the currency before the
+        number, e.g. '$1.234.567' or 'UF 1.234,5678'.
+
+    Returns
+    -------
+    tuple[Decimal, str]
+        The exact value of the amount and the code of the currency: 'CLP',
+        'UF', 'UTM' or 'USD', in the order of the arguments of
+        :func:`format_currency`.
+
+    Raises
+    ------
+    UnknownCurrency
+        If the amount does not start with a known symbol or code.
+    LocaleException
+        See :func:`parse_number`.
+
+    Notes
+    -----
+    - Symbols and codes are case-insensitive.
+    - The sign can be written either before or after the symbol ('-$1.234'
+      or '$ -1.234').
+    """
+
+
+def round_decimal(
+    value: Decimal | int | str,
+    decimals: int,
+    rounding: str = "half_up",
+) -> Decimal:
+    """
+    Rounds a value to a number of decimals.
+
+    Parameters
+    ----------
+    value : Decimal | int | str
+        The value to round.
+    decimals : int
+        The number of decimals to keep.
+    rounding : str, optional
+        The rounding mode: 'half_up' (half away from zero), 'half_even'
+        (banker's rounding), 'down' (towards zero) or 'up' (away from
+        zero). Defaults to 'half_up'.
+
+    Returns
+    -------
+    Decimal
+        The rounded value.
+
+    Raises
+    ------
+    UnknownRounding
+        If the rounding mode is unknown.
+    """
+
+
+def format_number(
+    value: Decimal | int | str,
+    decimals: int = 0,
+    rounding: str = "half_up",
+) -> str:
+    """
+    Formats a number in the Chilean locale.
+
+    Parameters
+    ----------
+    value : Decimal | int | str
+        The value to format.
+    decimals : int, optional
+        The number of decimals to write. Defaults to 0.
+    rounding : str, optional
+        The rounding mode, see :func:`round_decimal`. Defaults to
+        'half_up'.
+
+    Returns
+    -------
+    str
+        The number with '.' as thousands separator and ',' as decimal
+        separator, e.g. '38.416,69'.
+
+    Raises
+    ------
+    UnknownRounding
+        If the rounding mode is unknown.
+    """
+
+
+def format_currency(
+    value: Decimal | int | str,
+    currency: str = "CLP",
+    decimals: int | None = None,
+    rounding: str = "half_up",
+) -> str:
+    """
+    Formats an amount of money in the Chilean locale.
+
+    Parameters
+    ----------
+    value : Decimal | int | str
+        The amount to format.
+    currency : str, optional
+        The code or symbol of the currency: 'CLP' ('$'), 'UF', 'UTM' or
+        'USD' ('US$'). Defaults to 'CLP'.
+    decimals : int | None, optional
+        The number of decimals to write. Defaults to the usual decimals of
+        the currency: 0 for CLP and UTM, 2 for UF and USD.
+    rounding : str, optional
+        The rounding mode, see :func:`round_decimal`. Defaults to
+        'half_up'.
+
+    Returns
+    -------
+    str
+        The amount preceded by the symbol of the currency, e.g. '$1.234.567'
+        or 'UF 1.234,57'.
+
+    Raises
+    ------
+    UnknownCurrency
+        If the currency is unknown.
+    UnknownRounding
+        If the rounding mode is unknown.
+    """
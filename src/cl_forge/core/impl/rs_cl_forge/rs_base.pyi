@@ -1,5 +1,11 @@
 from __future__ import annotations
 
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from os import PathLike
+    from pathlib import Path
+
 class ClientException(Exception): # noqa: N818
     """Base class for all exceptions raised by API clients."""
 
@@ -21,6 +27,42 @@ class BadStatus(ClientException):
 class UnsupportedFormat(ClientException):
     """Raised when an unsupported format is requested."""
 
+class InvalidRetryPolicy(ClientException):
+    """Raised when a retry policy is inconsistent."""
+
+class InvalidRateLimit(ClientException):
+    """Raised when a rate limit is inconsistent."""
+
+class InvalidQuota(ClientException):
+    """Raised when a quota is inconsistent or its period is unknown."""
+
+class QuotaExceeded(ClientException):
+    """Raised before sending a request when the quota of the API key is exhausted."""
+
+class QuotaStorageError(ClientException):
+    """Raised when the quota file cannot be read or written."""
+
+class InvalidCache(ClientException):
+    """Raised when a response cache is inconsistent."""
+
+class UnsupportedCacheMode(ClientException):
+    """Raised when an unsupported cache mode is requested."""
+
+class CacheStorageError(ClientException):
+    """Raised when the cache directory cannot be created or cleared."""
+
+class UnsupportedCassetteMode(ClientException):
+    """Raised when an unsupported cassette mode is requested."""
+
+class CassetteMiss(ClientException):
+    """Raised when a replayed cassette has no response recorded for a request."""
+
+class CassetteStorageError(ClientException):
+    """Raised when a cassette cannot be read or written."""
+
+class InvalidSettings(ClientException):
+    """Raised when the base URL, timeout, proxy or CA bundles of a client are invalid."""
+
 
 class Token:
     """
@@ -67,6 +109,70 @@ class Token:
         environment variable is missing or empty, this property returns ``None``.
         """
 
+class ClientSettings:
+    """
+    Connection settings of an API client, loaded by `Config`.
+
+    Clients use the settings given to their constructor first, then these
+    ones, then the defaults of their API.
+
+    Attributes
+    ----------
+    base_url : str | None
+        The URL the paths of the requests are appended to.
+    timeout : float | None
+        The timeout of each request, in seconds.
+    user_agent : str | None
+        The value of the ``User-Agent`` header.
+    proxy : str | None
+        The URL of the proxy of the requests.
+    no_proxy : str | None
+        The hosts reached without the proxy.
+    ca_bundles : list[Path]
+        The PEM files with certificate authorities trusted besides the
+        built-in ones.
+    """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def base_url(self) -> str | None:
+        """
+        Returns the base URL, or ``None`` if not set.
+        """
+
+    @property
+    def timeout(self) -> float | None:
+        """
+        Returns the timeout in seconds, or ``None`` if not set or not a
+        positive number.
+        """
+
+    @property
+    def user_agent(self) -> str | None:
+        """
+        Returns the user agent, or ``None`` if not set.
+        """
+
+    @property
+    def proxy(self) -> str | None:
+        """
+        Returns the URL of the proxy, or ``None`` if not set.
+        """
+
+    @property
+    def no_proxy(self) -> str | None:
+        """
+        Returns the hosts reached without the proxy, or ``None`` if not
+        set.
+        """
+
+    @property
+    def ca_bundles(self) -> list[Path]:
+        """
+        Returns the extra CA bundles, empty if not set.
+        """
+
 class Config:
     """
     Configuration container for library settings.
@@ -80,6 +186,13 @@ class Config:
     - Environment variables used:
         - ``CLFORGE_CMF_TOKEN``: CMF token (API key).
         - ``CLFORGE_MARKET_TOKEN``: Market token (ticket).
+        - ``CLFORGE_CMF_BASE_URL``, ``CLFORGE_CMF_TIMEOUT`` (in seconds) and
+          ``CLFORGE_CMF_USER_AGENT``: settings of the CMF client.
+        - ``CLFORGE_MARKET_BASE_URL``, ``CLFORGE_MARKET_TIMEOUT`` and
+          ``CLFORGE_MARKET_USER_AGENT``: settings of the Market client.
+        - ``CLFORGE_PROXY``, ``CLFORGE_NO_PROXY`` and ``CLFORGE_CA_BUNDLE``
+          (several files separated as in ``PATH``): proxy and extra
+          certificate authorities of all the clients.
     """
 
     def __init__(self, dotenv_path: str | None = None) -> None:
@@ -99,4 +212,406 @@ class Config:
     def tokens(self) -> Token:
         """
         Returns the loaded API tokens.
-        """
\ No newline at end of file
+        """
+
+    @property
+    def cmf(self) -> ClientSettings:
+        """
+        Returns the loaded settings of the CMF client.
+        """
+
+    @property
+    def market(self) -> ClientSettings:
+        """
+        Returns the loaded settings of the Market client.
+        """
+
+
+class RetryPolicy:
+    """
+    Policy to retry failed requests of the API clients, with exponential
+    backoff.
+
+    A request is retried when it times out, fails to connect, loses its
+    connection or response body, or gets one of `retry_statuses`, until
+    `max_attempts` requests have been sent. When all the attempts fail, the
+    error of the last one is raised.
+
+    Attributes
+    ----------
+    max_attempts : int
+        The maximum number of requests sent, including the first one.
+    initial_backoff : float
+        The delay before the first retry, in seconds.
+    max_backoff : float
+        The maximum delay between requests, in seconds.
+    multiplier : float
+        The factor the delay is multiplied by after each retry.
+    jitter : bool
+        Whether each delay is randomized between half and all of it.
+    retry_statuses : list[int]
+        The HTTP statuses that are retried.
+    respect_retry_after : bool
+        Whether to wait as long as the ``Retry-After`` header asks.
+    """
+
+    def __init__(
+            self,
+            max_attempts: int = 3,
+            initial_backoff: float = 0.5,
+            max_backoff: float = 30.0,
+            multiplier: float = 2.0,
+            jitter: bool = True,
+            retry_statuses: list[int] | None = None,
+            respect_retry_after: bool = True,
+    ) -> None:
+        """
+        Initializes a new RetryPolicy instance.
+
+        Parameters
+        ----------
+        max_attempts : int, optional
+            The maximum number of requests sent, including the first one.
+            1 disables retries. Defaults to 3.
+        initial_backoff : float, optional
+            The delay before the first retry, in seconds. Defaults to 0.5.
+        max_backoff : float, optional
+            The maximum delay between requests, in seconds. Defaults to 30.
+        multiplier : float, optional
+            The factor the delay is multiplied by after each retry. Defaults
+            to 2.
+        jitter : bool, optional
+            Whether to randomize each delay between half and all of it, so
+            that clients failing together do not retry together. Defaults
+            to True.
+        retry_statuses : list[int] | None, optional
+            The HTTP statuses that are retried. Defaults to None, for 429,
+            500, 502, 503 and 504.
+        respect_retry_after : bool, optional
+            Whether to wait as long as the ``Retry-After`` header of a
+            response asks, instead of the backoff. If it asks for longer
+            than `max_backoff`, the error is raised without retrying.
+            Defaults to True.
+
+        Raises
+        ------
+        InvalidRetryPolicy
+            If `max_attempts` is 0, a delay is negative, `multiplier` is
+            less than 1, or `initial_backoff` is greater than `max_backoff`.
+        """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def max_attempts(self) -> int:
+        """The maximum number of requests sent, including the first one."""
+
+    @property
+    def initial_backoff(self) -> float:
+        """The delay before the first retry, in seconds."""
+
+    @property
+    def max_backoff(self) -> float:
+        """The maximum delay between requests, in seconds."""
+
+    @property
+    def multiplier(self) -> float:
+        """The factor the delay is multiplied by after each retry."""
+
+    @property
+    def jitter(self) -> bool:
+        """Whether each delay is randomized between half and all of it."""
+
+    @property
+    def retry_statuses(self) -> list[int]:
+        """The HTTP statuses that are retried."""
+
+    @property
+    def respect_retry_after(self) -> bool:
+        """Whether to wait as long as the ``Retry-After`` header asks."""
+
+
+class RateLimit:
+    """
+    Maximum number of requests a client sends in a period of time, and in
+    flight at once. Requests over the limit wait until they are allowed.
+
+    Attributes
+    ----------
+    max_requests : int
+        The maximum number of requests started in `period`.
+    period : float
+        The sliding window in which requests are counted, in seconds.
+    max_concurrent : int | None
+        The maximum number of requests in flight at once with the key of
+        the client, or `None` for no limit.
+    """
+
+    def __init__(
+            self,
+            max_requests: int,
+            period: float = 1.0,
+            max_concurrent: int | None = None,
+    ) -> None:
+        """
+        Initializes a new RateLimit instance.
+
+        Parameters
+        ----------
+        max_requests : int
+            The maximum number of requests started in `period`. 1 starts
+            requests one at a time, spaced by `period`, without waiting for
+            the previous response.
+        period : float, optional
+            The sliding window in which requests are counted, in seconds.
+            Defaults to 1.
+        max_concurrent : int | None, optional
+            The maximum number of requests in flight at once with the key
+            of the client, counted across all the clients of the process
+            using the same base URL and key. A request waits until fewer
+            are in flight, and counts until its response arrives. 1 avoids
+            the rejections of simultaneous requests by Mercado Público.
+            Defaults to `None`, for no limit.
+
+        Raises
+        ------
+        InvalidRateLimit
+            If `max_requests` or `max_concurrent` is 0, or `period` is not
+            positive.
+        """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def max_requests(self) -> int:
+        """The maximum number of requests in `period`."""
+
+    @property
+    def period(self) -> float:
+        """The sliding window in which requests are counted, in seconds."""
+
+    @property
+    def max_concurrent(self) -> int | None:
+        """The maximum number of requests in flight at once with the key."""
+
+
+class Quota:
+    """
+    Maximum number of requests sent with an API key in a day or a month,
+    counted in a local file shared by all the processes using it. Requests
+    over the quota raise :class:`QuotaExceeded` without being sent.
+
+    Attributes
+    ----------
+    limit : int
+        The maximum number of requests in a period.
+    period : str
+        The period after which the count is reset: 'daily' or 'monthly'.
+    path : Path
+        The file where requests are counted.
+    """
+
+    def __init__(
+            self,
+            limit: int,
+            period: str = "monthly",
+            path: str | PathLike[str] | None = None,
+    ) -> None:
+        """
+        Initializes a new Quota instance.
+
+        Parameters
+        ----------
+        limit : int
+            The maximum number of requests in a period, e.g. 10000 for the
+            monthly quota of the CMF API.
+        period : str, optional
+            The period after which the count is reset: 'daily' (at 00:00
+            UTC) or 'monthly' (on the 1st at 00:00 UTC). Defaults to
+            'monthly'.
+        path : str | PathLike[str] | None, optional
+            The file where requests are counted. Defaults to None, for
+            ``cl_forge/quota.tsv`` in the user cache directory.
+
+        Raises
+        ------
+        InvalidQuota
+            If `limit` is 0 or `period` is unknown.
+
+        Notes
+        -----
+        - Each request sent counts, including retries. Counts are kept per
+          API key and service, and keys are stored hashed.
+        """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def limit(self) -> int:
+        """The maximum number of requests in a period."""
+
+    @property
+    def period(self) -> str:
+        """The period after which the count is reset."""
+
+    @property
+    def path(self) -> Path:
+        """The file where requests are counted."""
+
+
+class Cache:
+    """
+    Cache of successful API responses, kept in memory or in a directory.
+    A cache can be shared by several clients, each getting only the
+    responses to requests sent with its own API key.
+
+    Attributes
+    ----------
+    ttl : float
+        How long responses are kept, in seconds.
+    ttls : list[tuple[str, float]]
+        The TTLs of the paths starting with a prefix.
+    path : Path | None
+        The directory of the disk cache, or None for a memory cache.
+    max_entries : int | None
+        The maximum number of responses of a memory cache, or None for a
+        disk cache.
+    """
+
+    def __init__(
+            self,
+            ttl: float = 300.0,
+            path: str | PathLike[str] | None = None,
+            ttls: list[tuple[str, float]] | None = None,
+            max_entries: int = 1024,
+    ) -> None:
+        """
+        Initializes a new Cache instance.
+
+        Parameters
+        ----------
+        ttl : float, optional
+            How long responses are kept, in seconds. Defaults to 300.
+        path : str | PathLike[str] | None, optional
+            The directory where responses are stored, one file per
+            response, shared by all the processes using it. Defaults to
+            None, for a memory cache that lives as long as the process.
+        ttls : list[tuple[str, float]] | None, optional
+            The TTLs of the paths starting with a prefix, e.g.
+            ``[("/uf", 3600), ("/ipc", 0)]``. The longest matching prefix
+            wins, and a TTL of 0 disables caching. Defaults to None.
+        max_entries : int, optional
+            The maximum number of responses of a memory cache. When it is
+            full, expired responses are dropped first, and then the ones
+            expiring soonest. Defaults to 1024.
+
+        Raises
+        ------
+        InvalidCache
+            If a TTL is negative or `max_entries` is 0.
+        CacheStorageError
+            If the directory cannot be created.
+
+        Notes
+        -----
+        - Only successful responses are cached. API keys and tickets are
+          not part of the stored keys.
+        - Cache failures never fail a request: unreadable responses are
+          fetched again, and unwritable ones are not cached.
+        """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def ttl(self) -> float:
+        """How long responses are kept, in seconds."""
+
+    @property
+    def ttls(self) -> list[tuple[str, float]]:
+        """The TTLs of the paths starting with a prefix."""
+
+    @property
+    def path(self) -> Path | None:
+        """The directory of the disk cache, or None for a memory cache."""
+
+    @property
+    def max_entries(self) -> int | None:
+        """The maximum number of responses of a memory cache."""
+
+    def clear(self) -> None:
+        """
+        Removes all the cached responses.
+
+        Raises
+        ------
+        CacheStorageError
+            If the files of a disk cache cannot be removed.
+        """
+
+
+class Cassette:
+    """
+    File of recorded API responses, which a client replays instead of
+    sending requests. Useful to test code using the clients without
+    network access or API keys.
+
+    Attributes
+    ----------
+    path : Path
+        The JSON file where responses are recorded.
+    mode : str
+        How the cassette is used: 'auto', 'record' or 'replay'.
+    """
+
+    def __init__(
+            self,
+            path: str | PathLike[str],
+            mode: str = "auto",
+    ) -> None:
+        """
+        Initializes a new Cassette instance, loading its recorded
+        responses.
+
+        Parameters
+        ----------
+        path : str | PathLike[str]
+            The JSON file where responses are recorded. It is created on
+            the first recorded response.
+        mode : str, optional
+            How the cassette is used: 'auto' replays recorded responses
+            and records the missing ones, 'record' sends every request and
+            records its response, and 'replay' only replays recorded
+            responses, raising :class:`CassetteMiss` on the missing ones.
+            Defaults to 'auto'.
+
+        Raises
+        ------
+        UnsupportedCassetteMode
+            If `mode` is not a supported mode.
+        CassetteStorageError
+            If the file cannot be read or parsed, or if it does not exist
+            in 'replay' mode.
+
+        Notes
+        -----
+        - API keys and tickets are replaced with ``REDACTED`` in the
+          recorded query strings, so cassettes can be committed and
+          replayed with any key.
+        - Error statuses are recorded and replayed as :class:`BadStatus`.
+          Connection errors are not recorded.
+        - Replayed responses are not sent, so they neither wait for the
+          rate limit nor count against the quota.
+        """
+
+    def __repr__(self) -> str: ...
+
+    def __len__(self) -> int:
+        """The number of recorded responses."""
+
+    @property
+    def path(self) -> Path:
+        """The JSON file where responses are recorded."""
+
+    @property
+    def mode(self) -> str:
+        """How the cassette is used."""
\ No newline at end of file
@@ -0,0 +1,229 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    import datetime
+    from datetime import date
+
+class CalendarException(Exception):  # noqa: N818
+    """Base class for all exceptions raised by the holiday calendar."""
+
+class UnsupportedYear(CalendarException):
+    """Raised when the given year is out of the supported range."""
+
+
+class Holiday:
+    """
+    Represents a holiday (feriado) of a given year.
+
+    Attributes
+    ----------
+    date : date
+        The date the holiday is observed, after moving it if the law says
+        so.
+    name : str
+        The official name of the holiday.
+    irrenunciable : bool
+        Whether the holiday is mandatory for retail workers (Ley 19.973).
+    scope : str
+        The territory where the holiday is observed: 'national', 'regional'
+        or 'comunal'.
+    """
+
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+    @property
+    def date(self) -> datetime.date:
+        """The date the holiday is observed."""
+
+    @property
+    def name(self) -> str:
+        """The official name of the holiday."""
+
+    @property
+    def irrenunciable(self) -> bool:
+        """Whether the holiday is mandatory for retail workers."""
+
+    @property
+    def scope(self) -> str:
+        """The territory where the holiday is observed."""
+
+
+def get_holidays(year: int, comuna: str | None = None) -> list[Holiday]:
+    """
+    Gets the holidays of a year.
+
+    Parameters
+    ----------
+    year : int
+        The year, between 2000 and 2999.
+    comuna : str | None, optional
+        The CUT code or name of a comuna, to include its regional and
+        comunal holidays (e.g. those of Arica y Parinacota or Chillán).
+        Defaults to None, for national holidays only.
+
+    Returns
+    -------
+    list[Holiday]
+        The holidays of the year, by date.
+
+    Raises
+    ------
+    UnsupportedYear
+        If the year is out of range.
+    UnknownComuna
+        If the comuna is unknown.
+
+    Notes
+    -----
+    - Includes holidays based on Easter, holidays moved to Mondays or
+      Fridays by law, and the Día Nacional de los Pueblos Indígenas on the
+      June solstice.
+    - Holidays declared for a single year, such as election days, and bank
+      holidays (December 31) are not included.
+    """
+
+
+def is_holiday(date: date, comuna: str | None = None) -> bool:
+    """
+    Checks whether a date is a holiday.
+
+    Parameters
+    ----------
+    date : date
+        The date to check.
+    comuna : str | None, optional
+        The CUT code or name of a comuna, see :func:`get_holidays`.
+        Defaults to None.
+
+    Returns
+    -------
+    bool
+        Whether the date is a holiday.
+
+    Raises
+    ------
+    UnsupportedYear
+        If the year is out of range.
+    UnknownComuna
+        If the comuna is unknown.
+    """
+
+
+def is_business_day(date: date, comuna: str | None = None) -> bool:
+    """
+    Checks whether a date is a business day (día hábil), i.e. a weekday
+    that is not a holiday.
+
+    Parameters
+    ----------
+    date : date
+        The date to check.
+    comuna : str | None, optional
+        The CUT code or name of a comuna, see :func:`get_holidays`.
+        Defaults to None.
+
+    Returns
+    -------
+    bool
+        Whether the date is a business day.
+
+    Raises
+    ------
+    UnsupportedYear
+        If the year is out of range.
+    UnknownComuna
+        If the comuna is unknown.
+
+    Notes
+    -----
+    - Saturdays are not business days, even though some legal terms count
+      them as such (días hábiles judiciales).
+    """
+
+
+def next_business_day(date: date, comuna: str | None = None) -> date:
+    """
+    Gets the first business day after a date.
+
+    Parameters
+    ----------
+    date : date
+        The date to start from, excluded.
+    comuna : str | None, optional
+        The CUT code or name of a comuna, see :func:`get_holidays`.
+        Defaults to None.
+
+    Returns
+    -------
+    date
+        The next business day.
+
+    Raises
+    ------
+    UnsupportedYear
+        If a year out of range is reached.
+    UnknownComuna
+        If the comuna is unknown.
+    """
+
+
+def add_business_days(date: date, days: int, comuna: str | None = None) -> date:
+    """
+    Adds a number of business days to a date.
+
+    Parameters
+    ----------
+    date : date
+        The date to start from.
+    days : int
+        The number of business days to add. Negative numbers go backward.
+    comuna : str | None, optional
+        The CUT code or name of a comuna, see :func:`get_holidays`.
+        Defaults to None.
+
+    Returns
+    -------
+    date
+        The business day reached after counting `days` business days,
+        excluding `date`. If `days` is 0, `date` itself.
+
+    Raises
+    ------
+    UnsupportedYear
+        If a year out of range is reached.
+    UnknownComuna
+        If the comuna is unknown.
+    """
+
+
+def business_days_between(start: date, end: date, comuna: str | None = None) -> int:
+    """
+    Counts the business days between two dates.
+
+    Parameters
+    ----------
+    start : date
+        The first date, included.
+    end : date
+        The last date, excluded.
+    comuna : str | None, optional
+        The CUT code or name of a comuna, see :func:`get_holidays`.
+        Defaults to None.
+
+    Returns
+    -------
+    int
+        The number of business days from `start` to `end`, or its negative
+        if `end` is before `start`.
+
+    Raises
+    ------
+    UnsupportedYear
+        If a year is out of range.
+    UnknownComuna
+        If the comuna is unknown.
+    """
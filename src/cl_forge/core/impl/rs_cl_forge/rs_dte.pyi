@@ -0,0 +1,543 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from datetime import date
+    from decimal import Decimal
+
+class DteException(Exception):  # noqa: N818
+    """Base class for all exceptions raised when parsing or verifying DTE documents."""
+
+class InvalidXml(DteException):
+    """Raised when the given XML is malformed."""
+
+class MissingElement(DteException):
+    """Raised when a required element of the document is missing."""
+
+class InvalidElementValue(DteException):
+    """Raised when the value of an element of the document is invalid."""
+
+class UnknownDteType(DteException):
+    """Raised when the document type is unknown."""
+
+class InvalidDteRut(DteException):
+    """Raised when a RUT of the document is malformed or has an invalid verifier."""
+
+class InvalidCafKey(DteException):
+    """Raised when the public key of the CAF is invalid."""
+
+class UnencodableCharacter(DteException):
+    """Raised when the stamp has characters that cannot be encoded in ISO-8859-1."""
+
+class StampMismatch(DteException):
+    """Raised when the electronic stamp does not match the document."""
+
+class FolioOutOfRange(DteException):
+    """Raised when the folio of the document is not authorized by the CAF."""
+
+
+class Emitter:
+    """
+    Represents the emitter of a document.
+
+    Attributes
+    ----------
+    rut : str
+        The RUT of the emitter, e.g. '97975000-5'.
+    business_name : str
+        The business name (razón social).
+    activity : str | None
+        The business activity (giro).
+    activity_codes : list[int]
+        The economic activity codes (Acteco).
+    address : str | None
+        The address of origin.
+    comuna : str | None
+        The comuna of origin.
+    city : str | None
+        The city of origin.
+    """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def rut(self) -> str:
+        """The RUT of the emitter."""
+
+    @property
+    def business_name(self) -> str:
+        """The business name (razón social)."""
+
+    @property
+    def activity(self) -> str | None:
+        """The business activity (giro)."""
+
+    @property
+    def activity_codes(self) -> list[int]:
+        """The economic activity codes (Acteco)."""
+
+    @property
+    def address(self) -> str | None:
+        """The address of origin."""
+
+    @property
+    def comuna(self) -> str | None:
+        """The comuna of origin."""
+
+    @property
+    def city(self) -> str | None:
+        """The city of origin."""
+
+
+class Receiver:
+    """
+    Represents the receiver of a document.
+
+    Attributes
+    ----------
+    rut : str
+        The RUT of the receiver. Boletas to unidentified consumers use
+        '66666666-6'.
+    business_name : str | None
+        The business name (razón social).
+    activity : str | None
+        The business activity (giro).
+    address : str | None
+        The address.
+    comuna : str | None
+        The comuna.
+    city : str | None
+        The city.
+    """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def rut(self) -> str:
+        """The RUT of the receiver."""
+
+    @property
+    def business_name(self) -> str | None:
+        """The business name (razón social)."""
+
+    @property
+    def activity(self) -> str | None:
+        """The business activity (giro)."""
+
+    @property
+    def address(self) -> str | None:
+        """The address."""
+
+    @property
+    def comuna(self) -> str | None:
+        """The comuna."""
+
+    @property
+    def city(self) -> str | None:
+        """The city."""
+
+
+class Totals:
+    """
+    Represents the totals of a document, in pesos.
+
+    Attributes
+    ----------
+    net : int | None
+        The net amount subject to VAT.
+    exempt : int | None
+        The amount exempt from VAT.
+    vat_rate : Decimal | None
+        The VAT rate, in percent.
+    vat : int | None
+        The VAT amount.
+    total : int
+        The total amount.
+    """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def net(self) -> int | None:
+        """The net amount subject to VAT."""
+
+    @property
+    def exempt(self) -> int | None:
+        """The amount exempt from VAT."""
+
+    @property
+    def vat_rate(self) -> Decimal | None:
+        """The VAT rate, in percent."""
+
+    @property
+    def vat(self) -> int | None:
+        """The VAT amount."""
+
+    @property
+    def total(self) -> int:
+        """The total amount."""
+
+
+class Item:
+    """
+    Represents an item (detail line) of a document.
+
+    Attributes
+    ----------
+    line : int
+        The line number, from 1.
+    name : str
+        The name of the item.
+    description : str | None
+        The description of the item.
+    quantity : Decimal | None
+        The quantity.
+    unit : str | None
+        The unit of measure.
+    price : Decimal | None
+        The unit price.
+    discount : int | None
+        The discount amount.
+    amount : int
+        The amount of the line.
+    exempt : bool
+        Whether the item is exempt from VAT (or otherwise not billable).
+    """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def line(self) -> int:
+        """The line number, from 1."""
+
+    @property
+    def name(self) -> str:
+        """The name of the item."""
+
+    @property
+    def description(self) -> str | None:
+        """The description of the item."""
+
+    @property
+    def quantity(self) -> Decimal | None:
+        """The quantity."""
+
+    @property
+    def unit(self) -> str | None:
+        """The unit of measure."""
+
+    @property
+    def price(self) -> Decimal | None:
+        """The unit price."""
+
+    @property
+    def discount(self) -> int | None:
+        """The discount amount."""
+
+    @property
+    def amount(self) -> int:
+        """The amount of the line."""
+
+    @property
+    def exempt(self) -> bool:
+        """Whether the item is exempt from VAT."""
+
+
+class Caf:
+    """
+    Represents a folio authorization code (CAF), issued by the SII to
+    authorize a range of folios of a document type.
+
+    Attributes
+    ----------
+    emitter : str
+        The RUT of the authorized emitter.
+    business_name : str
+        The business name of the authorized emitter.
+    dte_type : int
+        The authorized document type code, e.g. 33.
+    folio_from : int
+        The first authorized folio.
+    folio_to : int
+        The last authorized folio (inclusive).
+    authorized : date
+        The authorization date.
+    key_id : int
+        The identifier of the SII key that signed the CAF.
+    """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def emitter(self) -> str:
+        """The RUT of the authorized emitter."""
+
+    @property
+    def business_name(self) -> str:
+        """The business name of the authorized emitter."""
+
+    @property
+    def dte_type(self) -> int:
+        """The authorized document type code."""
+
+    @property
+    def folio_from(self) -> int:
+        """The first authorized folio."""
+
+    @property
+    def folio_to(self) -> int:
+        """The last authorized folio (inclusive)."""
+
+    @property
+    def authorized(self) -> date:
+        """The authorization date."""
+
+    @property
+    def key_id(self) -> int:
+        """The identifier of the SII key that signed the CAF."""
+
+
+class Stamp:
+    """
+    Represents the electronic stamp (TED, Timbre Electrónico DTE) of a
+    document, printed as a PDF417 barcode on its representation.
+
+    Attributes
+    ----------
+    emitter : str
+        The RUT of the emitter.
+    dte_type : int
+        The document type code.
+    folio : int
+        The folio of the document.
+    issue_date : date
+        The issue date of the document.
+    receiver : str
+        The RUT of the receiver.
+    receiver_name : str
+        The business name of the receiver, up to 40 characters.
+    total : int
+        The total amount of the document.
+    first_item : str
+        The name of the first item, up to 40 characters.
+    caf : Caf
+        The CAF authorizing the folio.
+    timestamp : str
+        The stamp timestamp, e.g. '2025-10-13T09:33:20'.
+    """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def emitter(self) -> str:
+        """The RUT of the emitter."""
+
+    @property
+    def dte_type(self) -> int:
+        """The document type code."""
+
+    @property
+    def folio(self) -> int:
+        """The folio of the document."""
+
+    @property
+    def issue_date(self) -> date:
+        """The issue date of the document."""
+
+    @property
+    def receiver(self) -> str:
+        """The RUT of the receiver."""
+
+    @property
+    def receiver_name(self) -> str:
+        """The business name of the receiver."""
+
+    @property
+    def total(self) -> int:
+        """The total amount of the document."""
+
+    @property
+    def first_item(self) -> str:
+        """The name of the first item."""
+
+    @property
+    def caf(self) -> Caf:
+        """The CAF authorizing the folio."""
+
+    @property
+    def timestamp(self) -> str:
+        """The stamp timestamp."""
+
+    def verify_signature(self) -> bool:
+        """
+        Verifies the signature of the stamp against the public key of the
+        CAF, with RSA and SHA-1 (SHA1withRSA).
+
+        Returns
+        -------
+        bool
+            True if the signature is valid, False if the stamp data was
+            altered or signed with another key.
+
+        Raises
+        ------
+        InvalidCafKey
+            If the CAF public key is invalid.
+
+        Notes
+        -----
+        - The signature of the CAF itself (FRMA), made by the SII, is not
+          verified.
+        """
+
+
+class Dte:
+    """
+    Represents an electronic tax document (DTE) of the SII.
+
+    Parameters
+    ----------
+    xml : str | bytes
+        The XML of a single DTE, or of an envelope (EnvioDTE, EnvioBOLETA),
+        in which case the first document is parsed. Bytes are decoded as
+        ISO-8859-1 when the XML declaration says so, and as UTF-8
+        otherwise.
+
+    Raises
+    ------
+    InvalidXml
+        If the XML is malformed.
+    MissingElement
+        If the XML has no document, or a required element is missing.
+    InvalidElementValue
+        If a value cannot be parsed.
+    UnknownDteType
+        If the document type is unknown.
+    InvalidDteRut
+        If a RUT, including those of the stamp and the CAF, is malformed
+        or its verifier is invalid.
+
+    Attributes
+    ----------
+    dte_type : int
+        The document type code, e.g. 33.
+    dte_type_name : str
+        The official name of the document type, e.g. 'Factura Electrónica'.
+    folio : int
+        The folio (number) of the document.
+    issue_date : date
+        The issue date.
+    emitter : Emitter
+        The emitter.
+    receiver : Receiver
+        The receiver.
+    totals : Totals
+        The totals.
+    items : list[Item]
+        The items, by line number.
+    stamp : Stamp | None
+        The electronic stamp, if any.
+    """
+
+    def __init__(self, xml: str | bytes) -> None: ...
+    def __repr__(self) -> str: ...
+
+    @property
+    def dte_type(self) -> int:
+        """The document type code."""
+
+    @property
+    def dte_type_name(self) -> str:
+        """The official name of the document type."""
+
+    @property
+    def folio(self) -> int:
+        """The folio (number) of the document."""
+
+    @property
+    def issue_date(self) -> date:
+        """The issue date."""
+
+    @property
+    def emitter(self) -> Emitter:
+        """The emitter."""
+
+    @property
+    def receiver(self) -> Receiver:
+        """The receiver."""
+
+    @property
+    def totals(self) -> Totals:
+        """The totals."""
+
+    @property
+    def items(self) -> list[Item]:
+        """The items, by line number."""
+
+    @property
+    def stamp(self) -> Stamp | None:
+        """The electronic stamp, if any."""
+
+    def check_stamp(self) -> str:
+        """
+        Checks that the electronic stamp is consistent with the document
+        and with its CAF, offline.
+
+        Returns
+        -------
+        str
+            'consistent' if the stamp matches the document and its
+            signature is valid for the public key of the CAF, 'invalid' if
+            the signature is invalid.
+
+        Raises
+        ------
+        MissingElement
+            If the document has no stamp.
+        StampMismatch
+            If the emitter, document type, folio, issue date, receiver or
+            total of the stamp or its CAF differ from those of the document.
+        FolioOutOfRange
+            If the folio is not authorized by the CAF.
+        InvalidCafKey
+            If the CAF public key is invalid.
+
+        Notes
+        -----
+        - This is a consistency check, not a proof of authenticity. The
+          signature of the CAF by the SII (FRMA) is not verified, since the
+          public keys of the SII are not bundled, so anyone can make a
+          consistent stamp with a CAF and key of their own.
+        - The XML signature of the whole document is not verified either.
+        """
+
+
+def parse_dtes(xml: str | bytes) -> list[Dte]:
+    """
+    Parses all the documents of a DTE XML, such as a single DTE or an
+    EnvioDTE or EnvioBOLETA with several documents.
+
+    Parameters
+    ----------
+    xml : str | bytes
+        The XML. Bytes are decoded as in :class:`Dte`.
+
+    Returns
+    -------
+    list[Dte]
+        The documents, in order of appearance.
+
+    Raises
+    ------
+    InvalidXml
+        If the XML is malformed.
+    MissingElement
+        If a required element is missing.
+    InvalidElementValue
+        If a value cannot be parsed.
+    UnknownDteType
+        If a document type is unknown.
+    InvalidDteRut
+        If a RUT is malformed or its verifier is invalid.
+    """
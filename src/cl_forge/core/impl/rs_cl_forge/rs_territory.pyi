@@ -0,0 +1,452 @@
+class TerritoryException(Exception):  # noqa: N818
+    """Base class for all exceptions raised by the territory registry."""
+
+class UnknownRegion(TerritoryException):
+    """Raised when no region matches the given query."""
+
+class UnknownProvince(TerritoryException):
+    """Raised when no province matches the given query."""
+
+class UnknownComuna(TerritoryException):
+    """Raised when no comuna matches the given query."""
+
+
+class PostalException(Exception):  # noqa: N818
+    """Base class for all exceptions raised by the postal code directory."""
+
+class InvalidPostalCode(PostalException):
+    """Raised when the given postal code is invalid."""
+
+class UnknownPostalCode(PostalException):
+    """Raised when the given postal code is not in the directory."""
+
+class InvalidPostalRecord(PostalException):
+    """Raised when a record of a postal code dataset is invalid."""
+
+class UnknownPostalComuna(PostalException):
+    """Raised when a record of a postal code dataset has an unknown comuna."""
+
+class PostalReadError(PostalException):
+    """Raised when a postal code dataset cannot be read."""
+
+
+class Region:
+    """
+    Represents a region of Chile.
+
+    Regions support comparison by their geographic position, from north to
+    south, and not by their CUT code.
+
+    Attributes
+    ----------
+    code : str
+        The CUT code of the region, e.g. '13'.
+    roman : str
+        The roman numeral of the region, e.g. 'XV', or 'RM' for the
+        Metropolitan region.
+    name : str
+        The official name of the region.
+    order : int
+        The geographic position of the region, from 1 (north) to 16
+        (south).
+    """
+
+    def __init__(self, query: str) -> None:
+        """
+        Gets a region by CUT code, roman numeral or name.
+
+        Roman numerals and names are compared ignoring case and accents.
+
+        Parameters
+        ----------
+        query : str
+            The CUT code (e.g. '5' or '05'), the roman numeral (e.g. 'V',
+            or 'RM') or the official name of the region.
+
+        Raises
+        ------
+        UnknownRegion
+            If no region matches.
+        """
+
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: Region) -> bool: ...
+    def __le__(self, other: Region) -> bool: ...
+    def __gt__(self, other: Region) -> bool: ...
+    def __ge__(self, other: Region) -> bool: ...
+    def __hash__(self) -> int: ...
+
+    @property
+    def code(self) -> str:
+        """The CUT code of the region, e.g. '13'."""
+
+    @property
+    def roman(self) -> str:
+        """The roman numeral of the region, or 'RM'."""
+
+    @property
+    def name(self) -> str:
+        """The official name of the region."""
+
+    @property
+    def order(self) -> int:
+        """The geographic position of the region, from 1 (north) to 16 (south)."""
+
+    def provinces(self) -> list[Province]:
+        """Returns the provinces of the region, by CUT code."""
+
+    def comunas(self) -> list[Comuna]:
+        """Returns the comunas of the region, by CUT code."""
+
+
+class Province:
+    """
+    Represents a province of Chile.
+
+    Attributes
+    ----------
+    code : str
+        The CUT code of the province, e.g. '132'.
+    name : str
+        The official name of the province.
+    region : Region
+        The region of the province.
+    """
+
+    def __init__(self, query: str) -> None:
+        """
+        Gets a province by CUT code or name.
+
+        Names are compared ignoring case and accents.
+
+        Parameters
+        ----------
+        query : str
+            The CUT code (e.g. '51' or '051') or the name of the province.
+
+        Raises
+        ------
+        UnknownProvince
+            If no province matches.
+        """
+
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+    @property
+    def code(self) -> str:
+        """The CUT code of the province, e.g. '132'."""
+
+    @property
+    def name(self) -> str:
+        """The official name of the province."""
+
+    @property
+    def region(self) -> Region:
+        """The region of the province."""
+
+    def comunas(self) -> list[Comuna]:
+        """Returns the comunas of the province, by CUT code."""
+
+
+class Comuna:
+    """
+    Represents a comuna of Chile.
+
+    Attributes
+    ----------
+    code : str
+        The CUT code of the comuna, e.g. '13120'.
+    name : str
+        The official name of the comuna.
+    province : Province
+        The province of the comuna.
+    region : Region
+        The region of the comuna.
+    """
+
+    def __init__(self, query: str) -> None:
+        """
+        Gets a comuna by CUT code or name.
+
+        Names are compared ignoring case and accents. For misspelled
+        names, see :func:`match_comuna`.
+
+        Parameters
+        ----------
+        query : str
+            The CUT code (e.g. '1101' or '01101') or the name of the
+            comuna.
+
+        Raises
+        ------
+        UnknownComuna
+            If no comuna matches.
+        """
+
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+    @property
+    def code(self) -> str:
+        """The CUT code of the comuna, e.g. '13120'."""
+
+    @property
+    def name(self) -> str:
+        """The official name of the comuna."""
+
+    @property
+    def province(self) -> Province:
+        """The province of the comuna."""
+
+    @property
+    def region(self) -> Region:
+        """The region of the comuna."""
+
+
+def get_regions() -> list[Region]:
+    """
+    Gets all the regions of Chile, from north to south.
+
+    Returns
+    -------
+    list[Region]
+        The 16 regions of Chile.
+    """
+
+
+def search_comunas(query: str, limit: int = 5) -> list[tuple[Comuna, float]]:
+    """
+    Searches the comunas whose names are most similar to the given one.
+
+    Parameters
+    ----------
+    query : str
+        The name of the comuna, possibly misspelled.
+    limit : int, optional
+        The maximum number of results. Defaults to 5.
+
+    Returns
+    -------
+    list[tuple[Comuna, float]]
+        The comunas with their similarity score, from 0.0 to 1.0, from the
+        most to the least similar.
+
+    Notes
+    -----
+    - The score is based on the Levenshtein distance between the names,
+      after :func:`normalize_name`.
+    """
+
+
+def match_comuna(query: str, min_score: float = 0.8) -> Comuna:
+    """
+    Matches a possibly misspelled comuna name, or a CUT code.
+
+    Parameters
+    ----------
+    query : str
+        The CUT code or the name of the comuna.
+    min_score : float, optional
+        The minimum similarity score, from 0.0 to 1.0, for a name to match.
+        Defaults to 0.8.
+
+    Returns
+    -------
+    Comuna
+        The exactly matching comuna or, otherwise, the most similar one.
+
+    Raises
+    ------
+    UnknownComuna
+        If no comuna reaches `min_score`.
+    """
+
+
+def normalize_name(name: str) -> str:
+    """
+    Normalizes a territory name for accent and case-insensitive comparison.
+
+    Parameters
+    ----------
+    name : str
+        The name to normalize.
+
+    Returns
+    -------
+    str
+        The name in lowercase, without diacritics nor apostrophes, with
+        hyphens and other punctuation replaced by spaces, and whitespaces
+        collapsed.
+    """
+
+
+class PostalDirectory:
+    """
+    A directory of postal codes, used to look up the comuna of a postal
+    code.
+
+    The directory holds ranges of postal codes assigned to comunas. When
+    looking up a postal code, the narrowest matching range wins, so exact
+    codes take precedence over broader ranges. Among ranges of equal width,
+    the last loaded wins.
+    """
+
+    def __init__(self, embedded: bool = True) -> None:
+        """
+        Creates a postal code directory.
+
+        Parameters
+        ----------
+        embedded : bool, optional
+            Whether to start with the embedded dataset. Defaults to `True`.
+
+        Notes
+        -----
+        - The embedded dataset holds the general postal code of the main
+          comunas, each covering the 10,000 codes sharing its first three
+          digits. It is not exhaustive: load the full dataset of Correos de
+          Chile with :meth:`load_csv` or :meth:`load_file`.
+        """
+
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+    def load_csv(self, data: str) -> int:
+        """
+        Loads postal code records from CSV data.
+
+        Each line is either `postal_code,comuna` or `start,end,comuna`,
+        separated by ',' or ';'. The comuna can be given by CUT code or
+        name. Empty lines, lines starting with '#' and a header line are
+        skipped. Records are only added if the whole data is valid.
+
+        Parameters
+        ----------
+        data : str
+            The CSV records.
+
+        Returns
+        -------
+        int
+            The number of loaded records.
+
+        Raises
+        ------
+        InvalidPostalRecord
+            If a record has an invalid number of fields or an invalid
+            postal code.
+        UnknownPostalComuna
+            If the comuna of a record is not in the registry.
+        """
+
+    def load_file(self, path: str) -> int:
+        """
+        Loads postal code records from a CSV file. See :meth:`load_csv`.
+
+        Parameters
+        ----------
+        path : str
+            Path to the CSV file.
+
+        Returns
+        -------
+        int
+            The number of loaded records.
+
+        Raises
+        ------
+        PostalReadError
+            If the file cannot be read.
+        PostalException
+            Any error raised by :meth:`load_csv`.
+        """
+
+    def lookup(self, code: str) -> Comuna:
+        """
+        Looks up the comuna of a postal code.
+
+        Parameters
+        ----------
+        code : str
+            The postal code.
+
+        Returns
+        -------
+        Comuna
+            The comuna of the postal code. Its `code` attribute is the CUT
+            code, to cross-check addresses.
+
+        Raises
+        ------
+        InvalidPostalCode
+            If the postal code is invalid.
+        UnknownPostalCode
+            If the postal code is not in the directory.
+        """
+
+
+def normalize_postal_code(code: str) -> str:
+    """
+    Normalizes a Chilean postal code.
+
+    Parameters
+    ----------
+    code : str
+        The postal code, e.g. '832 0000'.
+
+    Returns
+    -------
+    str
+        The 7 digits of the postal code.
+
+    Raises
+    ------
+    InvalidPostalCode
+        If the code, without spaces nor hyphens, does not have exactly 7
+        digits or starts with '0'.
+    """
+
+
+def validate_postal_code(code: str) -> bool:
+    """
+    Validates a Chilean postal code.
+
+    Parameters
+    ----------
+    code : str
+        The postal code.
+
+    Returns
+    -------
+    bool
+        `True` if the code has 7 digits and does not start with '0',
+        `False` otherwise.
+    """
+
+
+def get_postal_comuna(code: str) -> Comuna:
+    """
+    Gets the comuna of a postal code from the embedded dataset.
+
+    Parameters
+    ----------
+    code : str
+        The postal code.
+
+    Returns
+    -------
+    Comuna
+        The comuna of the postal code.
+
+    Raises
+    ------
+    InvalidPostalCode
+        If the postal code is invalid.
+    UnknownPostalCode
+        If the postal code is not in the embedded dataset. See
+        :class:`PostalDirectory` to load the full dataset.
+    """
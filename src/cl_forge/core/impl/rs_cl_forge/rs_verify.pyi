@@ -1,116 +1,1253 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from datetime import date
+
 class PpuException(Exception):  # noqa: N818
     """Base class for all exceptions raised by the PPU."""
 
-class UnknownFormat(PpuException):
-    """Raised when the given PPU does not match any known format."""
+class UnknownFormat(PpuException):
+    """Raised when the given PPU does not match any known format."""
+
+class InvalidLength(PpuException):
+    """Raised when PPU letters or digraphs are of invalid length."""
+
+class UnknownLetter(PpuException):
+    """Raised when the given PPU has letters out of the mapping."""
+
+class EmptyLetter(PpuException):
+    """Raised when internal mapping functions encounter an empty letter."""
+
+class UnknownDigraph(PpuException):
+    """Raised when the given PPU has digraphs out of the mapping."""
+
+class EmptyDigraph(PpuException):
+    """Raised when internal mapping functions encounter an empty digraph."""
+
+class ParsingError(PpuException):
+    """Raised when failed to create the numeric representation of the given PPU."""
+
+class InvalidNumeric(PpuException):
+    """Raised when the given numeric representation does not match any PPU."""
+
+class VerifierMismatch(PpuException):
+    """Raised when the given PPU verifier does not match the computed one."""
+
+class MissingVerifier(PpuException):
+    """Raised when the given complete PPU has no '-' before its verifier."""
+
+
+class PlateException(Exception):  # noqa: N818
+    """Base class for all exceptions raised by the plate parser."""
+
+class UnknownPlateFormat(PlateException):
+    """Raised when the given plate does not match any known format."""
+
+class CountryMismatch(PlateException):
+    """Raised when the given plate does not match any format of the given country."""
+
+class UnknownCountry(PlateException):
+    """Raised when the given country is not supported."""
+
+
+class VinException(Exception):  # noqa: N818
+    """Base class for all exceptions raised by the VIN parser."""
+
+class EmptyVin(VinException):
+    """Raised when the given VIN is empty."""
+
+class InvalidVinLength(VinException):
+    """Raised when the given VIN does not have 17 characters."""
+
+class InvalidVinCharacter(VinException):
+    """Raised when the given VIN has characters out of the VIN alphabet."""
+
+
+class IdCardException(Exception):  # noqa: N818
+    """Base class for all exceptions raised by the identity card parser."""
+
+class InvalidMrzLength(IdCardException):
+    """Raised when the given MRZ has an invalid length."""
+
+class InvalidMrzCharacter(IdCardException):
+    """Raised when the given MRZ has characters out of the MRZ alphabet."""
+
+class UnsupportedDocument(IdCardException):
+    """Raised when the given MRZ is not from a Chilean identity card."""
+
+class InvalidCheckDigit(IdCardException):
+    """Raised when an ICAO check digit is invalid."""
+
+class InvalidDate(IdCardException):
+    """Raised when a date of the identity card is invalid."""
+
+class InvalidDocumentNumber(IdCardException):
+    """Raised when the identity card document number is invalid."""
+
+class InvalidRun(IdCardException):
+    """Raised when the RUN of the identity card does not match its verifier."""
+
+class InvalidQrUrl(IdCardException):
+    """Raised when the given QR code URL is invalid."""
+
+class MissingQrParameter(IdCardException):
+    """Raised when the given QR code URL lacks a required parameter."""
+
+class SerialMismatch(IdCardException):
+    """Raised when the QR code serial does not match its MRZ document number."""
+
+
+class PhoneException(Exception):  # noqa: N818
+    """Base class for all exceptions raised by the phone number parser."""
+
+class EmptyPhone(PhoneException):
+    """Raised when the given phone number is empty."""
+
+class InvalidPhoneCharacter(PhoneException):
+    """Raised when the given phone number has invalid characters."""
+
+class UnknownPhonePrefix(PhoneException):
+    """Raised when the given phone number has an unknown prefix."""
+
+class InvalidPhoneLength(PhoneException):
+    """Raised when the given phone number has an invalid length."""
+
+
+class BankException(Exception):  # noqa: N818
+    """Base class for all exceptions raised by the bank registry."""
+
+class UnknownBank(BankException):
+    """Raised when no bank matches the given query."""
+
+class EmptyAccount(BankException):
+    """Raised when the given account number is empty."""
+
+class InvalidAccountCharacter(BankException):
+    """Raised when the given account number has invalid characters."""
+
+class InvalidAccountRut(BankException):
+    """Raised when the RUT of a CuentaRUT check has an invalid verifier."""
+
+
+class RoleException(Exception):  # noqa: N818
+    """Base class for all exceptions raised by the case and property role parsers."""
+
+class EmptyRole(RoleException):
+    """Raised when the given role is empty."""
+
+class InvalidRoleFormat(RoleException):
+    """Raised when the given role does not match the expected format."""
+
+class UnknownCaseType(RoleException):
+    """Raised when the prefix of the given case role is not a known case type."""
+
+class InvalidRoleYear(RoleException):
+    """Raised when the year of the given case role is out of range."""
+
+class InvalidComunaCode(RoleException):
+    """Raised when the comuna code of a property role is out of range."""
+
+
+class VerifierException(Exception): # noqa: N818
+    """Base class for all exceptions raised by the verifier."""
+
+class EmptyVerifier(VerifierException):
+    """Raised when the given verifier is empty on RUT validation."""
+
+class InvalidVerifier(VerifierException):
+    """Raised when the given verifier is invalid on RUT validation."""
+
+class UnexpectedComputation(VerifierException):
+    """Raised when the verifier computation fails."""
+
+
+class GenerateException(Exception): # noqa: N818
+    """Base class for all exceptions raised by the RUT generator."""
+
+class InvalidRange(GenerateException):
+    """Raised when the generator given lower bound is greater than upper bound."""
+
+class InvalidInput(GenerateException):
+    """Raised when generator's given parameters are invalid."""
+
+class InsufficientRange(GenerateException):
+    """Raised when the requested amount of RUTs is greater than the available range."""
+
+class UnexpectedGeneration(GenerateException):
+    """Raised to support unidentified errors during RUT generation."""
+
+
+class Ppu:
+    """
+        Represents a Chilean PPU (vehicle license plate).
+
+        Attributes
+        ----------
+        raw : str
+            The input PPU.
+        numeric : int
+            The numeric representation of the PPU.
+        normalized: str
+            The normalized PPU.
+        verifier: str
+            The calculated verifier digit of the PPU.
+        format: str
+            The detected format of the PPU. Supported formats:
+
+            - `LLLNN`  -> 3 letters followed by 2 digits
+            - `LLLNNN` -> 4 letters followed by 3 digits
+            - `LLLLNN` -> 4 letters followed by 2 digits
+            - `LLNNNN` -> 2 letters followed by 4 digits
+        complete: str
+            The normalized PPU with the verifier digit, separated by '-'.
+        inspection_month: int
+            The month (1-12) of the periodic technical inspection.
+
+        Notes
+        -----
+        - Instances are immutable, hashable, ordered and picklable. Equality,
+          hashing and ordering are based on the normalized PPU, so
+          ``Ppu("BBC12") == Ppu("bbc012")``.
+        """
+
+    def __init__(self, ppu: str) -> None:
+        """Initializes a PPU instance with the given PPU string.
+
+        Parameters
+        ----------
+        ppu : str
+            Chilean PPU (vehicle license plate).
+        """
+
+    @staticmethod
+    def from_numeric(numeric: int) -> Ppu:
+        """
+        Creates a PPU from its numeric representation.
+
+        Only digraph-based PPUs (`LLNNNN` format) can be recovered, since
+        letter-by-letter mappings are not one-to-one.
+
+        Parameters
+        ----------
+        numeric : int
+            Numeric representation of the PPU. See :func:`ppu_to_numeric`.
+
+        Returns
+        -------
+        Ppu
+            The PPU in `LLNNNN` format.
+
+        Raises
+        ------
+        InvalidNumeric
+            If the leading digits do not match any digraph.
+        """
+
+    @staticmethod
+    def from_complete(complete: str) -> Ppu:
+        """
+        Creates a PPU from a PPU followed by its verifier digit, validating
+        the verifier.
+
+        Parameters
+        ----------
+        complete : str
+            PPU and verifier digit, separated by '-' (e.g. "PHZF55-K").
+            The separator is required, since e.g. "BBC123" may be either
+            "BBC12" with verifier 3 or "BBC123" without verifier.
+
+        Returns
+        -------
+        Ppu
+            The PPU, with `raw` set to the given PPU without its
+            verifier.
+
+        Raises
+        ------
+        UnknownFormat
+            If the PPU does not match any known format.
+        VerifierMismatch
+            If the verifier does not match the computed one.
+        MissingVerifier
+            If there is no '-' before the verifier.
+        """
+
+    def __repr__(self) -> str: ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+    def __hash__(self) -> int: ...
+
+    def __lt__(self, other: Ppu) -> bool: ...
+
+    def __le__(self, other: Ppu) -> bool: ...
+
+    def __gt__(self, other: Ppu) -> bool: ...
+
+    def __ge__(self, other: Ppu) -> bool: ...
+
+    def __reduce__(self) -> tuple[type[Ppu], tuple[str]]: ...
+
+    @property
+    def raw(self) -> str:
+        """The input PPU."""
+
+    @property
+    def numeric(self) -> int:
+        """The numeric representation of the PPU."""
+
+    @property
+    def normalized(self) -> str:
+        """The normalized PPU."""
+
+    @property
+    def verifier(self) -> str:
+        """The calculated verifier digit of the PPU."""
+
+    @property
+    def format(self) -> str:
+        """The detected format of the PPU."""
+
+    @property
+    def complete(self) -> str:
+        """The normalized PPU with the verifier digit, separated by '-'."""
+
+    @property
+    def inspection_month(self) -> int:
+        """The month (1-12) of the periodic technical inspection."""
+
+    def is_restricted(self, date: date) -> bool:
+        """
+        Checks whether the vehicle is under driving restriction on the given
+        date. See :func:`is_restricted`.
+
+        Parameters
+        ----------
+        date : date
+            The date to check.
+
+        Returns
+        -------
+        bool
+            `True` if the vehicle is restricted, `False` otherwise.
+        """
+
+
+class Plate:
+    """
+    Represents a vehicle license plate from Chile or a neighbouring country.
+
+    Attributes
+    ----------
+    raw : str
+        The input plate.
+    normalized : str
+        The normalized plate, uppercase and without separators. Chilean
+        plates are normalized as in :class:`Ppu`.
+    format : str
+        The detected format of the plate, where `L` is a letter, `N` a digit
+        and `X` either of them. Supported formats:
+
+        - `CL`: Chilean PPU formats, see :class:`Ppu`.
+        - `AR`: `LLLNNN` (legacy) and `LLNNNLL` (Mercosur).
+        - `BR`: `LLLNNNN` (legacy) and `LLLNLNN` (Mercosur).
+        - `PE`: `LXXNNN`.
+    country : str
+        The ISO 3166-1 alpha-2 code of the issuing country: `CL`, `AR`,
+        `BR` or `PE`.
+    """
+
+    def __init__(self, plate: str, country: str | None = None) -> None:
+        """
+        Initializes a Plate instance with the given plate string.
+
+        Parameters
+        ----------
+        plate : str
+            Vehicle license plate. Spaces, '-', '.' and '·' are ignored.
+        country : str | None
+            Optional country code to restrict the detection to. If `None`,
+            ambiguous plates are resolved in order: Chile, Argentina,
+            Brazil and Peru.
+
+        Raises
+        ------
+        UnknownPlateFormat
+            If the plate does not match any known format.
+        CountryMismatch
+            If the plate does not match any format of the given country.
+        UnknownCountry
+            If the given country is not supported.
+        """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def raw(self) -> str:
+        """The input plate."""
+
+    @property
+    def normalized(self) -> str:
+        """The normalized plate."""
+
+    @property
+    def format(self) -> str:
+        """The detected format of the plate."""
+
+    @property
+    def country(self) -> str:
+        """The ISO 3166-1 alpha-2 code of the issuing country."""
+
+
+class Vin:
+    """
+    Represents a VIN (Vehicle Identification Number) as defined by ISO 3779.
+
+    Attributes
+    ----------
+    raw : str
+        The input VIN.
+    normalized : str
+        The VIN trimmed and in uppercase.
+    wmi : str
+        The World Manufacturer Identifier (positions 1 to 3).
+    vds : str
+        The Vehicle Descriptor Section (positions 4 to 9).
+    vis : str
+        The Vehicle Identifier Section (positions 10 to 17).
+    check_digit : str
+        The check digit as written in the VIN (position 9).
+    is_valid : bool
+        Whether the check digit matches the computed one.
+    manufacturer : str | None
+        The manufacturer, if the WMI is known.
+    region : str | None
+        The region of manufacture.
+    model_year : int | None
+        The model year, if the model year character is valid.
+    """
+
+    def __init__(self, vin: str) -> None:
+        """
+        Initializes a VIN instance with the given VIN string.
+
+        The length and alphabet of the VIN are validated, but not its check
+        digit, which is only mandatory for vehicles built for North America.
+        See :attr:`is_valid`.
+
+        Parameters
+        ----------
+        vin : str
+            Vehicle Identification Number.
+
+        Raises
+        ------
+        EmptyVin
+            If the VIN is empty.
+        InvalidVinLength
+            If the VIN does not have 17 characters.
+        InvalidVinCharacter
+            If the VIN has characters other than digits and letters, or any
+            of the letters 'I', 'O' and 'Q'.
+        """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def raw(self) -> str:
+        """The input VIN."""
+
+    @property
+    def normalized(self) -> str:
+        """The VIN trimmed and in uppercase."""
+
+    @property
+    def wmi(self) -> str:
+        """The World Manufacturer Identifier (positions 1 to 3)."""
+
+    @property
+    def vds(self) -> str:
+        """The Vehicle Descriptor Section (positions 4 to 9)."""
+
+    @property
+    def vis(self) -> str:
+        """The Vehicle Identifier Section (positions 10 to 17)."""
+
+    @property
+    def check_digit(self) -> str:
+        """The check digit as written in the VIN (position 9)."""
+
+    @property
+    def is_valid(self) -> bool:
+        """Whether the check digit matches the computed one."""
+
+    @property
+    def manufacturer(self) -> str | None:
+        """The manufacturer, if the WMI is known."""
+
+    @property
+    def region(self) -> str | None:
+        """The region of manufacture."""
+
+    @property
+    def model_year(self) -> int | None:
+        """
+        The model year, if the model year character (position 10) is valid.
+
+        Model year characters repeat every 30 years. Following the North
+        American convention, a letter in position 7 means the 2010-2039
+        cycle, and a digit means the 1980-2009 cycle.
+        """
+
+
+def validate_vin(vin: str) -> bool:
+    """
+    Validates a VIN against its ISO 3779 check digit.
+
+    Parameters
+    ----------
+    vin : str
+        Vehicle Identification Number.
+
+    Returns
+    -------
+    bool
+        `True` if the check digit (position 9) matches the computed one,
+        `False` otherwise.
+
+    Raises
+    ------
+    VinException
+        If the VIN is empty, or has invalid length or characters.
+
+    Notes
+    -----
+    - The check digit is mandatory only for vehicles built for North
+      America. Many European and Asian manufacturers do not use it.
+    """
+
+
+def calculate_vin_check_digit(vin: str) -> str:
+    """
+    Calculates the ISO 3779 check digit of a VIN.
+
+    Parameters
+    ----------
+    vin : str
+        Vehicle Identification Number.
+
+    Returns
+    -------
+    str
+        The check digit: '0'..'9' or 'X'.
+
+    Raises
+    ------
+    VinException
+        If the VIN is empty, or has invalid length or characters.
+    """
+
+class IdCardMrz:
+    """
+    Represents the MRZ (Machine Readable Zone) of a Chilean identity card,
+    as defined by ICAO 9303 for TD1 documents.
+
+    Attributes
+    ----------
+    kind : str
+        The card generation: 'old' or 'new'.
+    document_code : str
+        The document code: 'IN' (new cards) or 'ID' (old cards).
+    document_number : str
+        The document number, including its overflow for old cards.
+    birth_date : date
+        The holder's birth date.
+    sex : str
+        The holder's sex: 'M', 'F' or '<' if unspecified.
+    expiry_date : date
+        The card's expiry date.
+    nationality : str
+        The holder's nationality (ISO 3166-1 alpha-3).
+    optional_data : str
+        The optional data of the first line, without fillers.
+    optional_data_2 : str
+        The optional data of the second line, without fillers.
+    surnames : str
+        The holder's surnames, separated by spaces.
+    given_names : str
+        The holder's given names, separated by spaces.
+    run : str | None
+        The holder's RUN (e.g. '12345678-5'), if found in the optional data.
+    """
+
+    def __init__(self, mrz: str) -> None:
+        """
+        Parses and validates the three lines of an identity card MRZ.
+
+        Whitespaces and line breaks are ignored, so the MRZ can be given
+        as read by an OCR.
+
+        Parameters
+        ----------
+        mrz : str
+            The three 30-character lines of the MRZ.
+
+        Raises
+        ------
+        InvalidMrzLength
+            If the MRZ does not have 90 characters.
+        InvalidMrzCharacter
+            If the MRZ has characters other than 'A'..'Z', '0'..'9' and '<'.
+        UnsupportedDocument
+            If the MRZ is not from a Chilean identity card.
+        InvalidCheckDigit
+            If any ICAO check digit is invalid.
+        InvalidDate
+            If the birth or expiry date is invalid.
+        InvalidDocumentNumber
+            If the document number does not match any card generation.
+        InvalidRun
+            If the RUN found in the optional data does not match its
+            verifier.
+        """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def kind(self) -> str:
+        """The card generation: 'old' or 'new'."""
+
+    @property
+    def document_code(self) -> str:
+        """The document code: 'IN' (new cards) or 'ID' (old cards)."""
+
+    @property
+    def document_number(self) -> str:
+        """The document number, including its overflow for old cards."""
+
+    @property
+    def birth_date(self) -> date:
+        """The holder's birth date."""
+
+    @property
+    def sex(self) -> str:
+        """The holder's sex: 'M', 'F' or '<' if unspecified."""
+
+    @property
+    def expiry_date(self) -> date:
+        """The card's expiry date."""
+
+    @property
+    def nationality(self) -> str:
+        """The holder's nationality (ISO 3166-1 alpha-3)."""
+
+    @property
+    def optional_data(self) -> str:
+        """The optional data of the first line, without fillers."""
+
+    @property
+    def optional_data_2(self) -> str:
+        """The optional data of the second line, without fillers."""
+
+    @property
+    def surnames(self) -> str:
+        """The holder's surnames, separated by spaces."""
+
+    @property
+    def given_names(self) -> str:
+        """The holder's given names, separated by spaces."""
+
+    @property
+    def run(self) -> str | None:
+        """
+        The holder's RUN (e.g. '12345678-5'), if found in the optional data.
+
+        The RUN is validated against its verifier with the same algorithm
+        as :func:`calculate_verifier`.
+        """
+
+
+class IdCardQr:
+    """
+    Represents the payload of the QR code printed on the back of Chilean
+    identity cards, a URL to the Civil Registry document status portal.
+
+    Attributes
+    ----------
+    url : str
+        The input URL.
+    run : str
+        The holder's RUN (e.g. '12345678-5').
+    document_type : str
+        The document type, e.g. 'CEDULA'.
+    serial : str
+        The document number (serial).
+    birth_date : date
+        The holder's birth date.
+    expiry_date : date
+        The card's expiry date.
+    """
+
+    def __init__(self, url: str) -> None:
+        """
+        Parses and validates the URL encoded in an identity card QR code.
+
+        Parameters
+        ----------
+        url : str
+            The URL read from the QR code.
+
+        Raises
+        ------
+        InvalidQrUrl
+            If the URL does not point to the Civil Registry portal, or its
+            `mrz` parameter is malformed.
+        MissingQrParameter
+            If any of the `RUN`, `type`, `serial` or `mrz` parameters is
+            missing.
+        InvalidRun
+            If the RUN does not match its verifier.
+        InvalidCheckDigit
+            If any ICAO check digit of the `mrz` parameter is invalid.
+        InvalidDate
+            If the birth or expiry date is invalid.
+        SerialMismatch
+            If `serial` does not match the document number of the `mrz`
+            parameter.
+        """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def url(self) -> str:
+        """The input URL."""
+
+    @property
+    def run(self) -> str:
+        """The holder's RUN (e.g. '12345678-5')."""
+
+    @property
+    def document_type(self) -> str:
+        """The document type, e.g. 'CEDULA'."""
+
+    @property
+    def serial(self) -> str:
+        """The document number (serial)."""
+
+    @property
+    def birth_date(self) -> date:
+        """The holder's birth date."""
+
+    @property
+    def expiry_date(self) -> date:
+        """The card's expiry date."""
+
+
+def validate_document_number(number: str) -> str:
+    """
+    Validates the document number (serial) of a Chilean identity card.
+
+    Parameters
+    ----------
+    number : str
+        Document number, e.g. '100123456' or 'A012345678'.
+
+    Returns
+    -------
+    str
+        The card generation that matches the number: 'new' for 9 digits
+        not starting with '0', or 'old' for a letter followed by 9 digits.
+
+    Raises
+    ------
+    InvalidDocumentNumber
+        If the number does not match any card generation.
+    """
+
+
+class Phone:
+    """
+    Represents a Chilean phone number.
+
+    Attributes
+    ----------
+    raw : str
+        The input phone number.
+    national : str
+        The national number: digits only, without country code nor trunk
+        prefix.
+    e164 : str
+        The number in E.164 format, e.g. '+56912345678'.
+    international : str
+        The number in international format, e.g. '+56 9 1234 5678'.
+    kind : str
+        The kind of the number: 'mobile', 'fixed' or 'special'.
+    prefix : str
+        The area code, '9' for mobile numbers or the service code.
+    description : str
+        The zone of fixed numbers, or the name of the service.
+    """
+
+    def __init__(self, phone: str) -> None:
+        """
+        Parses a Chilean phone number and classifies it using the
+        numbering plan.
+
+        Separators (spaces, '-', '.', '(' and ')') are ignored. The country
+        code ('+56', '56' or '0056') or, otherwise, the trunk prefix '0'
+        used before 2016 (e.g. '02 2345 6789') is removed.
+
+        Parameters
+        ----------
+        phone : str
+            Phone number, e.g. '9 1234 5678' or '+56 2 2345 6789'.
+
+        Raises
+        ------
+        EmptyPhone
+            If the number has no digits.
+        InvalidPhoneCharacter
+            If the number has characters other than digits, separators and
+            a leading '+'.
+        UnknownPhonePrefix
+            If the number is not Chilean, or does not start with any prefix
+            of the numbering plan.
+        InvalidPhoneLength
+            If the number does not have the length its prefix requires.
+        """
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def raw(self) -> str:
+        """The input phone number."""
+
+    @property
+    def national(self) -> str:
+        """
+        The national number: digits only, without country code nor trunk
+        prefix.
+        """
+
+    @property
+    def e164(self) -> str:
+        """The number in E.164 format, e.g. '+56912345678'."""
+
+    @property
+    def international(self) -> str:
+        """The number in international format, e.g. '+56 9 1234 5678'."""
+
+    @property
+    def kind(self) -> str:
+        """The kind of the number: 'mobile', 'fixed' or 'special'."""
+
+    @property
+    def prefix(self) -> str:
+        """The area code, '9' for mobile numbers or the service code."""
+
+    @property
+    def description(self) -> str:
+        """The zone of fixed numbers, or the name of the service."""
+
+
+def normalize_phone(phone: str) -> str:
+    """
+    Normalizes a Chilean phone number to E.164 format.
+
+    Parameters
+    ----------
+    phone : str
+        Phone number, e.g. '9 1234 5678' or '(2) 2345-6789'.
+
+    Returns
+    -------
+    str
+        The number in E.164 format, e.g. '+56912345678'.
+
+    Raises
+    ------
+    PhoneException
+        If the number is empty, has invalid characters, an unknown prefix
+        or an invalid length.
+    """
 
-class InvalidLength(PpuException):
-    """Raised when PPU letters or digraphs are of invalid length."""
 
-class UnknownLetter(PpuException):
-    """Raised when the given PPU has letters out of the mapping."""
+def validate_phone(phone: str) -> bool:
+    """
+    Validates a Chilean phone number.
 
-class EmptyLetter(PpuException):
-    """Raised when internal mapping functions encounter an empty letter."""
+    Parameters
+    ----------
+    phone : str
+        Phone number.
 
-class UnknownDigraph(PpuException):
-    """Raised when the given PPU has digraphs out of the mapping."""
+    Returns
+    -------
+    bool
+        `True` if the number is a valid Chilean phone number, `False`
+        otherwise.
+    """
 
-class EmptyDigraph(PpuException):
-    """Raised when internal mapping functions encounter an empty digraph."""
 
-class ParsingError(PpuException):
-    """Raised when failed to create the numeric representation of the given PPU."""
+class Bank:
+    """
+    Represents a bank operating in Chile.
 
+    Attributes
+    ----------
+    code : str
+        The institution code assigned by the CMF (formerly SBIF), e.g.
+        '012'.
+    name : str
+        The legal name of the bank.
+    short_name : str
+        The commercial name of the bank.
+    """
 
-class VerifierException(Exception): # noqa: N818
-    """Base class for all exceptions raised by the verifier."""
+    def __init__(self, query: str) -> None:
+        """
+        Gets a bank by institution code or name.
 
-class EmptyVerifier(VerifierException):
-    """Raised when the given verifier is empty on RUT validation."""
+        Parameters
+        ----------
+        query : str
+            The institution code (e.g. '1' or '001'), the legal name or the
+            commercial name of the bank. Names are compared
+            case-insensitively.
 
-class InvalidVerifier(VerifierException):
-    """Raised when the given verifier is invalid on RUT validation."""
+        Raises
+        ------
+        UnknownBank
+            If no bank matches.
+        """
 
-class UnexpectedComputation(VerifierException):
-    """Raised when the verifier computation fails."""
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
 
+    @property
+    def code(self) -> str:
+        """The institution code assigned by the CMF, e.g. '012'."""
 
-class GenerateException(Exception): # noqa: N818
-    """Base class for all exceptions raised by the RUT generator."""
+    @property
+    def name(self) -> str:
+        """The legal name of the bank."""
 
-class InvalidRange(GenerateException):
-    """Raised when the generator given lower bound is greater than upper bound."""
+    @property
+    def short_name(self) -> str:
+        """The commercial name of the bank."""
 
-class InvalidInput(GenerateException):
-    """Raised when generator's given parameters are invalid."""
 
-class InsufficientRange(GenerateException):
-    """Raised when the requested amount of RUTs is greater than the available range."""
+def get_banks() -> list[Bank]:
+    """
+    Gets all the banks, by institution code.
 
-class UnexpectedGeneration(GenerateException):
-    """Raised to support unidentified errors during RUT generation."""
+    Returns
+    -------
+    list[Bank]
+        The banks operating in Chile.
+    """
 
 
-class Ppu:
+def normalize_account_number(account: str) -> str:
     """
-        Represents a Chilean PPU (vehicle license plate).
+    Normalizes a bank account number.
 
-        Attributes
-        ----------
-        raw : str
-            The input PPU.
-        numeric : int
-            The numeric representation of the PPU.
-        normalized: str
-            The normalized PPU.
-        verifier: str
-            The calculated verifier digit of the PPU.
-        format: str
-            The detected format of the PPU. Supported formats:
+    Parameters
+    ----------
+    account : str
+        Account number, e.g. '00-123-45678-9'.
 
-            - `LLLNN`  -> 3 letters followed by 2 digits
-            - `LLLNNN` -> 4 letters followed by 3 digits
-            - `LLLLNN` -> 4 letters followed by 2 digits
-            - `LLNNNN` -> 2 letters followed by 4 digits
-        complete: str
-            The normalized PPU with the verifier digit, separated by '-'.
+    Returns
+    -------
+    str
+        The digits of the account number.
+
+    Raises
+    ------
+    EmptyAccount
+        If the account number has no digits.
+    InvalidAccountCharacter
+        If the account number has characters other than digits, spaces,
+        '-' and '.'.
+    """
+
+
+def validate_cuenta_rut(account: str, digits: int, verifier: str) -> bool:
+    """
+    Checks whether an account number is the CuentaRUT of a RUT.
+
+    CuentaRUT accounts, offered by BancoEstado, are numbered with the RUT
+    of the holder without verifier.
+
+    Parameters
+    ----------
+    account : str
+        Account number.
+    digits : int
+        Correlative number of the RUT of the account holder.
+    verifier : str
+        Verifier digit of the RUT of the account holder.
+
+    Returns
+    -------
+    bool
+        `True` if the account number, without leading zeros, is the RUT
+        without verifier, `False` otherwise.
+
+    Raises
+    ------
+    VerifierException
+        If the verifier is empty or has an invalid format.
+    InvalidAccountRut
+        If the verifier does not match the RUT.
+    BankException
+        If the account number is empty or has invalid characters.
+    """
+
+
+class CaseRole:
+    """
+    Represents the role (rol) of a case of the Poder Judicial.
+
+    Attributes
+    ----------
+    case_type : str | None
+        The prefix of the role, as written by the Poder Judicial: the
+        letter of the case type in first-instance courts ('C', 'V', 'E',
+        'O', 'T' or 'M'), or the book of the case in Cortes de Apelaciones
+        ('Civil', 'Penal', 'Familia', 'Laboral-Cobranza', 'Protección',
+        'Amparo', 'Contencioso Administrativo' or 'Policía Local'). Roles
+        of the Corte Suprema have no prefix.
+    tribunal : str
+        The tribunal of the case, told by the prefix of the role:
+        'juzgado', 'corte_apelaciones' or 'corte_suprema'.
+    number : int
+        The correlative number of the case in the year.
+    year : int
+        The year the case was filed.
+    normalized : str
+        The role as written by the Poder Judicial, e.g. 'C-1234-2023'.
+    """
+
+    def __init__(self, role: str) -> None:
         """
+        Parses the role of a case of the Poder Judicial.
 
-    def __init__(self, ppu: str) -> None:
-        """Initializes a PPU instance with the given PPU string.
+        The role is trimmed, and its parts may be separated by '-' or
+        whitespace. The prefix is matched ignoring case and accents, and
+        leading zeros of the number are removed.
 
         Parameters
         ----------
-        ppu : str
-            Chilean PPU (vehicle license plate).
+        role : str
+            Case role, e.g. 'C-1234-2023', 'Protección-1234-2023' or
+            '1234-2023'.
+
+        Raises
+        ------
+        EmptyRole
+            If the role is empty.
+        InvalidRoleFormat
+            If the role is not an optional prefix, a positive number and a
+            four-digit year.
+        UnknownCaseType
+            If the prefix is not a known case type.
+        InvalidRoleYear
+            If the year is before 1900 or after the current year.
         """
 
     def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
 
     @property
-    def raw(self) -> str:
-        """The input PPU."""
+    def case_type(self) -> str | None:
+        """The prefix of the role, if any, e.g. 'C' or 'Protección'."""
 
     @property
-    def numeric(self) -> int:
-        """The numeric representation of the PPU."""
+    def tribunal(self) -> str:
+        """The tribunal of the case: 'juzgado', 'corte_apelaciones' or 'corte_suprema'."""
+
+    @property
+    def number(self) -> int:
+        """The correlative number of the case in the year."""
+
+    @property
+    def year(self) -> int:
+        """The year the case was filed."""
 
     @property
     def normalized(self) -> str:
-        """The normalized PPU."""
+        """The role as written by the Poder Judicial, e.g. 'C-1234-2023'."""
+
+
+class PropertyRole:
+    """
+    Represents the role (rol de avalúo) of a property in the SII, which
+    identifies it within a comuna.
+
+    Attributes
+    ----------
+    comuna : int
+        The code of the comuna where the property is located.
+    block : int
+        The block (manzana) number.
+    lot : int
+        The lot (predio) number within the block.
+    normalized : str
+        The role as written by the SII, e.g. '1234-56'.
+    """
+
+    def __init__(self, role: str, comuna: int) -> None:
+        """
+        Parses the role of a property in the SII.
+
+        The block and lot may be separated by '-' or whitespace, and their
+        leading zeros are removed.
+
+        Parameters
+        ----------
+        role : str
+            Property role, e.g. '1234-56'.
+        comuna : int
+            The code of the comuna where the property is located. The SII
+            uses its own comuna codes, which differ from the CUT codes, so
+            the code is only checked to be in range.
+
+        Raises
+        ------
+        EmptyRole
+            If the role is empty.
+        InvalidRoleFormat
+            If the role is not a block and a lot number, both positive and
+            of at most five digits.
+        InvalidComunaCode
+            If the comuna code is zero or has more than five digits.
+        """
+
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
 
     @property
-    def verifier(self) -> str:
-        """The calculated verifier digit of the PPU."""
+    def comuna(self) -> int:
+        """The code of the comuna where the property is located."""
 
     @property
-    def format(self) -> str:
-        """The detected format of the PPU."""
+    def block(self) -> int:
+        """The block (manzana) number."""
 
     @property
-    def complete(self) -> str:
-        """The normalized PPU with the verifier digit, separated by '-'."""
+    def lot(self) -> int:
+        """The lot (predio) number within the block."""
+
+    @property
+    def normalized(self) -> str:
+        """The role as written by the SII, e.g. '1234-56'."""
+
+
+def normalize_case_role(role: str) -> str:
+    """
+    Normalizes the role of a case of the Poder Judicial.
+
+    Parameters
+    ----------
+    role : str
+        Case role, e.g. 'c 01234 2023'.
+
+    Returns
+    -------
+    str
+        The normalized role, e.g. 'C-1234-2023'.
+
+    Raises
+    ------
+    RoleException
+        If the role is empty, malformed or its year is out of range. See
+        :class:`CaseRole`.
+    """
+
+
+def validate_case_role(role: str) -> bool:
+    """
+    Validates the role of a case of the Poder Judicial.
+
+    Parameters
+    ----------
+    role : str
+        Case role.
+
+    Returns
+    -------
+    bool
+        `True` if the role can be parsed by :class:`CaseRole`, `False`
+        otherwise.
+    """
+
+
+def normalize_property_role(role: str) -> str:
+    """
+    Normalizes the role of a property in the SII.
+
+    Parameters
+    ----------
+    role : str
+        Property role, e.g. '01234-056'.
+
+    Returns
+    -------
+    str
+        The normalized role, e.g. '1234-56'.
+
+    Raises
+    ------
+    EmptyRole
+        If the role is empty.
+    InvalidRoleFormat
+        If the role is not a block and a lot number. See
+        :class:`PropertyRole`.
+    """
+
+
+def calculate_mrz_check_digit(field: str) -> str:
+    """
+    Calculates the ICAO 9303 check digit of an MRZ field.
+
+    Parameters
+    ----------
+    field : str
+        MRZ field, with characters 'A'..'Z', '0'..'9' and '<'.
+
+    Returns
+    -------
+    str
+        The check digit: '0'..'9'.
+
+    Raises
+    ------
+    InvalidMrzCharacter
+        If the field has characters out of the MRZ alphabet.
+    """
+
+
+def get_plate_formats(plate: str) -> list[tuple[str, str]]:
+    """
+    Detects every known format a vehicle license plate matches.
+
+    Parameters
+    ----------
+    plate : str
+        Vehicle license plate. Spaces, '-', '.' and '·' are ignored.
+
+    Returns
+    -------
+    list[tuple[str, str]]
+        `(country, format)` pairs in detection priority: Chile, Argentina,
+        Brazil and Peru. Empty if the plate does not match any format.
+    """
 
 
 def calculate_verifier(digits: int) -> str:
@@ -231,4 +1368,79 @@ def generate(
     InsufficientRange
         If the range between `min` and `max` is too small to generate `n`
         unique RUT/RUNs.
+    """
+
+
+def get_inspection_month(ppu: str) -> int:
+    """
+    Gets the month of the periodic technical inspection ("revisión técnica")
+    of a private vehicle, based on the last digit of its PPU.
+
+    Parameters
+    ----------
+    ppu : str
+        Chilean PPU (vehicle license plate).
+
+    Returns
+    -------
+    int
+        The inspection month, from 1 (January) to 12 (December).
+
+    Raises
+    ------
+    UnknownFormat
+        If the PPU does not match any known format.
+    """
+
+
+def get_restricted_digits(date: date) -> list[int]:
+    """
+    Gets the last PPU digits under driving restriction ("restricción
+    vehicular") in Santiago on the given date.
+
+    Parameters
+    ----------
+    date : date
+        The date to check.
+
+    Returns
+    -------
+    list[int]
+        The restricted digits in ascending order. Empty if no restriction
+        applies (e.g. weekends).
+
+    Notes
+    -----
+    - Uses the embedded permanent restriction table for vehicles without
+      green seal, which rotates monthly from Monday to Friday.
+    - Holidays are not taken into account.
+    """
+
+
+def is_restricted(ppu: str, date: date) -> bool:
+    """
+    Checks whether a vehicle is under driving restriction ("restricción
+    vehicular") in Santiago on the given date.
+
+    Parameters
+    ----------
+    ppu : str
+        Chilean PPU (vehicle license plate).
+    date : date
+        The date to check.
+
+    Returns
+    -------
+    bool
+        `True` if the last digit of the PPU is restricted on `date`,
+        `False` otherwise.
+
+    Raises
+    ------
+    UnknownFormat
+        If the PPU does not match any known format.
+
+    Notes
+    -----
+    - Holidays are not taken into account.
     """
\ No newline at end of file
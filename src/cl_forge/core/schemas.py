@@ -1,18 +1,21 @@
 from datetime import datetime
+from decimal import Decimal
 
 from pydantic import BaseModel, ConfigDict, Field, field_validator
 
+from cl_forge.core.impl.rs_cl_forge.rs_amounts import parse_number
+
 
 class BaseCmfRecord(BaseModel):
     model_config = ConfigDict(from_attributes=True)
 
-    value: float = Field(alias="Valor")
+    value: Decimal = Field(alias="Valor")
     date: datetime = Field(alias="Fecha")
 
     @field_validator('value', mode='before')
     @classmethod
     def convert_value(cls, v):
-        return float(v.replace('.', '').replace(',', '.'))
+        return parse_number(v)
 
     @field_validator('date', mode='before')
     @classmethod
@@ -24,7 +27,7 @@ class IpcRecord(BaseCmfRecord):
     @field_validator('value', mode='before')
     @classmethod
     def convert_value(cls, v):
-        return round(float(v.replace('.', '').replace(',', '.')) / 100, 5)
+        return parse_number(v) / 100
 
 
 class UsdRecord(BaseCmfRecord): ...
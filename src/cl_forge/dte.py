@@ -0,0 +1,21 @@
+from cl_forge.core.impl.rs_cl_forge.rs_dte import (
+    Caf,
+    Dte,
+    Emitter,
+    Item,
+    Receiver,
+    Stamp,
+    Totals,
+    parse_dtes,
+)
+
+__all__ = (
+    "Dte",
+    "Emitter",
+    "Receiver",
+    "Totals",
+    "Item",
+    "Stamp",
+    "Caf",
+    "parse_dtes",
+)
\ No newline at end of file
@@ -1,33 +1,133 @@
+from cl_forge.core.impl.rs_cl_forge.rs_amounts import (
+    AmountException,
+    EmptyNumber,
+    InvalidAmount,
+    InvalidGrouping,
+    InvalidNumberCharacter,
+    LocaleException,
+    NumberOutOfRange,
+    TooManyDecimals,
+    UnknownCurrency,
+    UnknownRounding,
+)
 from cl_forge.core.impl.rs_cl_forge.rs_base import (
     BadStatus,
+    CacheStorageError,
+    CassetteMiss,
+    CassetteStorageError,
     ClientException,
     EmptyApiKey,
     EmptyPath,
     HttpError,
+    InvalidCache,
     InvalidPath,
+    InvalidQuota,
+    InvalidRateLimit,
+    InvalidRetryPolicy,
+    InvalidSettings,
+    QuotaExceeded,
+    QuotaStorageError,
+    UnsupportedCacheMode,
+    UnsupportedCassetteMode,
     UnsupportedFormat,
 )
+from cl_forge.core.impl.rs_cl_forge.rs_calendar import (
+    CalendarException,
+    UnsupportedYear,
+)
+from cl_forge.core.impl.rs_cl_forge.rs_dte import (
+    DteException,
+    FolioOutOfRange,
+    InvalidCafKey,
+    InvalidDteRut,
+    InvalidElementValue,
+    InvalidXml,
+    MissingElement,
+    StampMismatch,
+    UnencodableCharacter,
+    UnknownDteType,
+)
+from cl_forge.core.impl.rs_cl_forge.rs_territory import (
+    InvalidPostalCode,
+    InvalidPostalRecord,
+    PostalException,
+    PostalReadError,
+    TerritoryException,
+    UnknownComuna,
+    UnknownPostalCode,
+    UnknownPostalComuna,
+    UnknownProvince,
+    UnknownRegion,
+)
 from cl_forge.core.impl.rs_cl_forge.rs_verify import (
+    BankException,
+    CountryMismatch,
+    EmptyAccount,
     EmptyDigraph,
     EmptyLetter,
+    EmptyPhone,
+    EmptyRole,
     EmptyVerifier,
+    EmptyVin,
     GenerateException,
+    IdCardException,
     InsufficientRange,
+    InvalidAccountCharacter,
+    InvalidAccountRut,
+    InvalidCheckDigit,
+    InvalidComunaCode,
+    InvalidDate,
+    InvalidDocumentNumber,
     InvalidInput,
     InvalidLength,
+    InvalidMrzCharacter,
+    InvalidMrzLength,
+    InvalidNumeric,
+    InvalidPhoneCharacter,
+    InvalidPhoneLength,
+    InvalidQrUrl,
     InvalidRange,
+    InvalidRoleFormat,
+    InvalidRoleYear,
+    InvalidRun,
     InvalidVerifier,
+    InvalidVinCharacter,
+    InvalidVinLength,
+    MissingQrParameter,
+    MissingVerifier,
     ParsingError,
+    PhoneException,
+    PlateException,
     PpuException,
+    RoleException,
+    SerialMismatch,
     UnexpectedComputation,
     UnexpectedGeneration,
+    UnknownBank,
+    UnknownCaseType,
+    UnknownCountry,
     UnknownDigraph,
     UnknownFormat,
     UnknownLetter,
+    UnknownPhonePrefix,
+    UnknownPlateFormat,
+    UnsupportedDocument,
     VerifierException,
+    VerifierMismatch,
+    VinException,
 )
 
 __all__ = (
+    "AmountException",
+    "InvalidAmount",
+    "TooManyDecimals",
+    "LocaleException",
+    "EmptyNumber",
+    "InvalidNumberCharacter",
+    "InvalidGrouping",
+    "NumberOutOfRange",
+    "UnknownCurrency",
+    "UnknownRounding",
     "BadStatus",
     "ClientException",
     "EmptyApiKey",
@@ -35,7 +135,44 @@ __all__ = (
     "HttpError",
     "InvalidPath",
     "UnsupportedFormat",
+    "InvalidRetryPolicy",
+    "InvalidRateLimit",
+    "InvalidQuota",
+    "QuotaExceeded",
+    "QuotaStorageError",
+    "InvalidCache",
+    "UnsupportedCacheMode",
+    "CacheStorageError",
+    "UnsupportedCassetteMode",
+    "CassetteMiss",
+    "CassetteStorageError",
+    "InvalidSettings",
+    "CalendarException",
+    "UnsupportedYear",
+    "DteException",
+    "InvalidXml",
+    "MissingElement",
+    "InvalidElementValue",
+    "UnknownDteType",
+    "InvalidDteRut",
+    "InvalidCafKey",
+    "UnencodableCharacter",
+    "StampMismatch",
+    "FolioOutOfRange",
+    "TerritoryException",
+    "UnknownRegion",
+    "UnknownProvince",
+    "UnknownComuna",
+    "PostalException",
+    "InvalidPostalCode",
+    "UnknownPostalCode",
+    "InvalidPostalRecord",
+    "UnknownPostalComuna",
+    "PostalReadError",
     "ParsingError",
+    "InvalidNumeric",
+    "VerifierMismatch",
+    "MissingVerifier",
     "PpuException",
     "UnknownFormat",
     "InvalidLength",
@@ -43,6 +180,41 @@ __all__ = (
     "EmptyLetter",
     "UnknownDigraph",
     "EmptyDigraph",
+    "PlateException",
+    "UnknownPlateFormat",
+    "CountryMismatch",
+    "UnknownCountry",
+    "VinException",
+    "EmptyVin",
+    "InvalidVinLength",
+    "InvalidVinCharacter",
+    "IdCardException",
+    "InvalidMrzLength",
+    "InvalidMrzCharacter",
+    "UnsupportedDocument",
+    "InvalidCheckDigit",
+    "InvalidDate",
+    "InvalidDocumentNumber",
+    "InvalidRun",
+    "InvalidQrUrl",
+    "MissingQrParameter",
+    "SerialMismatch",
+    "PhoneException",
+    "EmptyPhone",
+    "InvalidPhoneCharacter",
+    "UnknownPhonePrefix",
+    "InvalidPhoneLength",
+    "BankException",
+    "UnknownBank",
+    "EmptyAccount",
+    "InvalidAccountCharacter",
+    "InvalidAccountRut",
+    "RoleException",
+    "EmptyRole",
+    "InvalidRoleFormat",
+    "InvalidRoleYear",
+    "UnknownCaseType",
+    "InvalidComunaCode",
     "VerifierException",
     "EmptyVerifier",
     "InvalidVerifier",
@@ -1,3 +1,6 @@
-from cl_forge.core.impl.rs_cl_forge.rs_market import MarketClient
+from cl_forge.core.impl.rs_cl_forge.rs_market import AsyncMarketClient, MarketClient
 
-__all__ = ("MarketClient",)
\ No newline at end of file
+__all__ = (
+    "AsyncMarketClient",
+    "MarketClient",
+)
\ No newline at end of file
@@ -1,3 +1,21 @@
-from cl_forge.core.impl.rs_cl_forge.rs_base import Config, Token
+from cl_forge.core.impl.rs_cl_forge.rs_base import (
+    Cache,
+    Cassette,
+    ClientSettings,
+    Config,
+    Quota,
+    RateLimit,
+    RetryPolicy,
+    Token,
+)
 
-__all__ = ("Config", "Token")
\ No newline at end of file
+__all__ = (
+    "Cache",
+    "Cassette",
+    "ClientSettings",
+    "Config",
+    "Quota",
+    "RateLimit",
+    "RetryPolicy",
+    "Token",
+)
\ No newline at end of file
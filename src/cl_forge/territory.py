@@ -0,0 +1,27 @@
+from cl_forge.core.impl.rs_cl_forge.rs_territory import (
+    Comuna,
+    PostalDirectory,
+    Province,
+    Region,
+    get_postal_comuna,
+    get_regions,
+    match_comuna,
+    normalize_name,
+    normalize_postal_code,
+    search_comunas,
+    validate_postal_code,
+)
+
+__all__ = (
+    "Region",
+    "Province",
+    "Comuna",
+    "get_regions",
+    "search_comunas",
+    "match_comuna",
+    "normalize_name",
+    "PostalDirectory",
+    "normalize_postal_code",
+    "validate_postal_code",
+    "get_postal_comuna",
+)
\ No newline at end of file
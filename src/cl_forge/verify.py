@@ -1,17 +1,65 @@
 from cl_forge.core.impl.rs_cl_forge.rs_verify import (
+    Bank,
+    CaseRole,
+    IdCardMrz,
+    IdCardQr,
+    Phone,
+    Plate,
     Ppu,
+    PropertyRole,
+    Vin,
+    calculate_mrz_check_digit,
     calculate_verifier,
+    calculate_vin_check_digit,
     generate,
+    get_banks,
+    get_inspection_month,
+    get_plate_formats,
+    get_restricted_digits,
+    is_restricted,
+    normalize_account_number,
+    normalize_case_role,
+    normalize_phone,
     normalize_ppu,
+    normalize_property_role,
     ppu_to_numeric,
+    validate_case_role,
+    validate_cuenta_rut,
+    validate_document_number,
+    validate_phone,
     validate_rut,
+    validate_vin,
 )
 
 __all__ = (
     "Ppu",
+    "Plate",
     "calculate_verifier",
     "normalize_ppu",
     "ppu_to_numeric",
     "validate_rut",
     "generate",
+    "get_inspection_month",
+    "get_restricted_digits",
+    "is_restricted",
+    "get_plate_formats",
+    "Vin",
+    "validate_vin",
+    "calculate_vin_check_digit",
+    "IdCardMrz",
+    "IdCardQr",
+    "validate_document_number",
+    "calculate_mrz_check_digit",
+    "Phone",
+    "normalize_phone",
+    "validate_phone",
+    "Bank",
+    "get_banks",
+    "normalize_account_number",
+    "validate_cuenta_rut",
+    "CaseRole",
+    "normalize_case_role",
+    "validate_case_role",
+    "PropertyRole",
+    "normalize_property_role",
 )
\ No newline at end of file
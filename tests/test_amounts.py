@@ -0,0 +1,112 @@
+from decimal import Decimal
+
+import pytest
+
+from cl_forge import amounts, exceptions
+
+
+def test_number_to_words():
+    assert amounts.number_to_words(0) == "cero"
+    assert amounts.number_to_words(1) == "uno"
+    assert amounts.number_to_words(16) == "dieciséis"
+    assert amounts.number_to_words(100) == "cien"
+    assert amounts.number_to_words(101) == "ciento uno"
+    assert amounts.number_to_words(999) == "novecientos noventa y nueve"
+    assert amounts.number_to_words(1_001) == "mil uno"
+    assert amounts.number_to_words(10**12) == "un billón"
+
+def test_number_to_words_feminine():
+    assert amounts.number_to_words(1, feminine=True) == "una"
+    assert amounts.number_to_words(21, feminine=True) == "veintiuna"
+    assert amounts.number_to_words(500, feminine=True) == "quinientas"
+    assert amounts.number_to_words(200_000_000, feminine=True) == (
+        "doscientos millones"
+    )
+
+def test_pesos_to_words():
+    assert amounts.pesos_to_words(1) == "un peso"
+    assert amounts.pesos_to_words(21) == "veintiún pesos"
+    assert amounts.pesos_to_words(31_000) == "treinta y un mil pesos"
+    assert amounts.pesos_to_words(1_000_000) == "un millón de pesos"
+    assert amounts.pesos_to_words(2_000_000_000) == "dos mil millones de pesos"
+    assert amounts.pesos_to_words(1_500_000) == "un millón quinientos mil pesos"
+
+def test_amount_to_words():
+    assert amounts.amount_to_words(1, "cuota", "cuotas", feminine=True) == "una cuota"
+    assert amounts.amount_to_words(21, "dólar", "dólares") == "veintiún dólares"
+
+def test_uf_to_words():
+    assert amounts.uf_to_words("1") == "una Unidad de Fomento"
+    assert amounts.uf_to_words("1000000") == (
+        "un millón de Unidades de Fomento"
+    )
+    assert amounts.uf_to_words("21,05") == (
+        "veintiuna coma cero cinco Unidades de Fomento"
+    )
+    assert amounts.uf_to_words("300.5000") == (
+        "trescientas coma cinco Unidades de Fomento"
+    )
+
+def test_uf_to_words_invalid():
+    with pytest.raises(exceptions.InvalidAmount):
+        amounts.uf_to_words("1.234,56")
+    with pytest.raises(exceptions.InvalidAmount):
+        amounts.uf_to_words("-1")
+    with pytest.raises(exceptions.TooManyDecimals):
+        amounts.uf_to_words("1,23456")
+    with pytest.raises(exceptions.AmountException):
+        amounts.uf_to_words("")
+
+def test_parse_number():
+    assert amounts.parse_number("38.416,69") == Decimal("38416.69")
+    assert amounts.parse_number("1234,5678") == Decimal("1234.5678")
+    assert amounts.parse_number(" - 0,3 ") == Decimal("-0.3")
+    assert amounts.parse_number("1.000.000") == Decimal("1000000")
+    assert str(amounts.parse_number("0,10")) == "0.10"
+
+def test_parse_number_invalid():
+    with pytest.raises(exceptions.EmptyNumber):
+        amounts.parse_number("  ")
+    with pytest.raises(exceptions.InvalidNumberCharacter):
+        amounts.parse_number("12a")
+    with pytest.raises(exceptions.InvalidGrouping):
+        amounts.parse_number("1.23,4")
+    with pytest.raises(exceptions.InvalidGrouping):
+        amounts.parse_number("1,234.5")
+    with pytest.raises(exceptions.NumberOutOfRange):
+        amounts.parse_number("9" * 30)
+
+def test_parse_currency():
+    assert amounts.parse_currency("$1.234.567") == (Decimal("1234567"), "CLP")
+    assert amounts.parse_currency("UF 1.234,5678") == (Decimal("1234.5678"), "UF")
+    assert amounts.parse_currency("-US$ 10,5") == (Decimal("-10.5"), "USD")
+    assert amounts.parse_currency("utm -2") == (Decimal("-2"), "UTM")
+
+    value, currency = amounts.parse_currency("UF 1.234,5678")
+    assert amounts.format_currency(value, currency, decimals=4) == "UF 1.234,5678"
+    assert amounts.format_currency(*amounts.parse_currency("$1.234.567")) == "$1.234.567"
+
+    with pytest.raises(exceptions.UnknownCurrency):
+        amounts.parse_currency("€ 10")
+
+def test_round_decimal():
+    assert amounts.round_decimal(Decimal("1.25"), 1) == Decimal("1.3")
+    assert amounts.round_decimal(Decimal("1.25"), 1, "half_even") == Decimal("1.2")
+    assert amounts.round_decimal(Decimal("-1.25"), 0, "down") == Decimal("-1")
+    with pytest.raises(exceptions.UnknownRounding):
+        amounts.round_decimal(Decimal("1"), 0, "nearest")
+
+def test_format_number():
+    assert amounts.format_number(Decimal("38416.69"), 2) == "38.416,69"
+    assert amounts.format_number(1234567) == "1.234.567"
+    assert amounts.format_number(Decimal("-12345.675"), 2) == "-12.345,68"
+    assert amounts.format_number(Decimal("0.5"), 3) == "0,500"
+
+def test_format_currency():
+    value = Decimal("1234.5678")
+    assert amounts.format_currency(value) == "$1.235"
+    assert amounts.format_currency(value, "UF") == "UF 1.234,57"
+    assert amounts.format_currency(value, "UF", decimals=4) == "UF 1.234,5678"
+    assert amounts.format_currency(-value, "US$", rounding="down") == "-US$1.234,56"
+    with pytest.raises(exceptions.UnknownCurrency):
+        amounts.format_currency(value, "EUR")
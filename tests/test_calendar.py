@@ -0,0 +1,65 @@
+from datetime import date
+
+import pytest
+
+from cl_forge import calendar, exceptions
+
+
+def test_get_holidays():
+    holidays = calendar.get_holidays(2024)
+    assert len(holidays) == 17
+    assert holidays[0].date == date(2024, 1, 1)
+    assert holidays[0].name == "Año Nuevo"
+    assert holidays[0].irrenunciable
+    assert holidays[0].scope == "national"
+    assert [h.date for h in holidays] == sorted(h.date for h in holidays)
+
+def test_easter_holidays():
+    dates = {h.name: h.date for h in calendar.get_holidays(2025)}
+    assert dates["Viernes Santo"] == date(2025, 4, 18)
+    assert dates["Sábado Santo"] == date(2025, 4, 19)
+
+def test_moved_holidays():
+    dates = {h.name: h.date for h in calendar.get_holidays(2023)}
+    # Thursday, October 12 moves to Monday.
+    assert dates["Encuentro de Dos Mundos"] == date(2023, 10, 9)
+    # Thursday, June 29 moves to Monday.
+    assert dates["San Pedro y San Pablo"] == date(2023, 6, 26)
+    # Tuesday, October 31 moves to the previous Friday.
+    assert dates["Día de las Iglesias Evangélicas y Protestantes"] == date(2023, 10, 27)
+
+def test_solstice_holiday():
+    name = "Día Nacional de los Pueblos Indígenas"
+
+    def dates(year):
+        return {h.name: h.date for h in calendar.get_holidays(year)}
+
+    assert dates(2024)[name] == date(2024, 6, 20)
+    assert dates(2026)[name] == date(2026, 6, 21)
+    assert name not in {h.name for h in calendar.get_holidays(2020)}
+
+def test_regional_holidays():
+    assert not calendar.is_holiday(date(2025, 6, 7))
+    assert calendar.is_holiday(date(2025, 6, 7), comuna="Arica")
+    assert calendar.is_holiday(date(2025, 8, 20), comuna="16103")
+    assert not calendar.is_holiday(date(2025, 8, 20), comuna="San Carlos")
+    holidays = calendar.get_holidays(2025, comuna="Chillán")
+    assert any(h.scope == "comunal" for h in holidays)
+
+def test_business_days():
+    assert calendar.is_business_day(date(2025, 9, 17))
+    assert not calendar.is_business_day(date(2025, 9, 18))
+    assert not calendar.is_business_day(date(2025, 9, 20))
+    assert calendar.next_business_day(date(2025, 9, 17)) == date(2025, 9, 22)
+    assert calendar.add_business_days(date(2025, 12, 23), 2) == date(2025, 12, 26)
+    assert calendar.add_business_days(date(2025, 12, 23), -2) == date(2025, 12, 19)
+    assert calendar.business_days_between(date(2025, 9, 15), date(2025, 9, 22)) == 3
+    assert calendar.business_days_between(date(2025, 9, 22), date(2025, 9, 15)) == -3
+
+def test_calendar_errors():
+    with pytest.raises(exceptions.UnsupportedYear):
+        calendar.get_holidays(1999)
+    with pytest.raises(exceptions.CalendarException):
+        calendar.is_business_day(date(3000, 1, 1))
+    with pytest.raises(exceptions.UnknownComuna):
+        calendar.get_holidays(2025, comuna="Gotham")
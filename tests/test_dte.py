@@ -0,0 +1,82 @@
+from datetime import date
+from decimal import Decimal
+from pathlib import Path
+
+import pytest
+
+from cl_forge import dte, exceptions
+
+FIXTURES = Path(__file__).parent / "fixtures"
+
+
+@pytest.fixture
+def xml():
+    return (FIXTURES / "dte_33.xml").read_bytes()
+
+
+def test_parse_dte(xml):
+    document = dte.Dte(xml)
+    assert document.dte_type == 33
+    assert document.dte_type_name == "Factura Electrónica"
+    assert document.folio == 60
+    assert document.issue_date == date(2025, 10, 13)
+
+def test_parse_parties(xml):
+    document = dte.Dte(xml)
+    assert document.emitter.rut == "97975000-5"
+    assert document.emitter.business_name == "EMPRESA DE PRUEBA"
+    assert document.emitter.activity_codes == [474100]
+    assert document.receiver.rut == "77777777-7"
+    assert document.receiver.business_name == "Comercial Niño & Cía"
+
+def test_parse_totals_and_items(xml):
+    document = dte.Dte(xml)
+    assert document.totals.net == 51500
+    assert document.totals.vat_rate == Decimal("19")
+    assert document.totals.vat == 9785
+    assert document.totals.total == 61285
+    assert [i.line for i in document.items] == [1, 2]
+    assert document.items[1].name == "Cañería de cobre"
+    assert document.items[1].quantity == Decimal("1.5")
+    assert document.items[1].amount == 1500
+
+def test_parse_text(xml):
+    text = xml.decode("latin-1").replace('encoding="ISO-8859-1"', 'encoding="UTF-8"')
+    assert dte.Dte(text).receiver.business_name == "Comercial Niño & Cía"
+
+def test_parse_dtes(xml):
+    documents = dte.parse_dtes(xml)
+    assert len(documents) == 1
+    assert documents[0].folio == 60
+
+def test_stamp(xml):
+    stamp = dte.Dte(xml).stamp
+    assert stamp.folio == 60
+    assert stamp.total == 61285
+    assert stamp.caf.folio_from == 1
+    assert stamp.caf.folio_to == 200
+    assert stamp.verify_signature()
+
+def test_check_stamp(xml):
+    assert dte.Dte(xml).check_stamp() == "consistent"
+
+def test_check_altered_stamp(xml):
+    altered = xml.replace(b"<RSR>Comercial", b"<RSR>Comercia1")
+    assert dte.Dte(altered).check_stamp() == "invalid"
+
+def test_check_altered_total(xml):
+    altered = xml.replace(b"<MntTotal>61285", b"<MntTotal>61286")
+    with pytest.raises(exceptions.StampMismatch):
+        dte.Dte(altered).check_stamp()
+
+def test_invalid_rut(xml):
+    with pytest.raises(exceptions.InvalidDteRut):
+        dte.Dte(xml.replace(b"<RUTRecep>77777777-7", b"<RUTRecep>77777777-8"))
+
+def test_invalid_xml():
+    with pytest.raises(exceptions.InvalidXml):
+        dte.Dte("<DTE>")
+    with pytest.raises(exceptions.MissingElement):
+        dte.Dte("<DTE/>")
+    with pytest.raises(exceptions.DteException):
+        dte.Dte("<DTE><Documento/></DTE>")
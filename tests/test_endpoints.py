@@ -1,4 +1,5 @@
 from datetime import datetime
+from decimal import Decimal
 from unittest.mock import patch
 
 import pytest
@@ -49,7 +50,8 @@ MOCK_RESPONSES = {
     "/ipc/2025": {
         "IPCs": [
             {"Valor": "0,1", "Fecha": "2025-01-01"},
-            {"Valor": "0,2", "Fecha": "2025-02-01"}
+            {"Valor": "0,2", "Fecha": "2025-02-01"},
+            {"Valor": "- 0,3", "Fecha": "2025-03-01"}
         ]
     }
 }
@@ -71,56 +73,58 @@ def test_usd_endpoints(mock_cmf_client):
     
     # Test current
     current = usd.current()
-    assert current.value == 872.22
+    assert current.value == Decimal("872.22")
     assert current.date == datetime(2026, 1, 23)
     
     # Test year
     year_data = usd.year(2025)
     assert len(year_data) == 2
-    assert year_data[0].value == 996.46
+    assert year_data[0].value == Decimal("996.46")
     assert year_data[0].date == datetime(2025, 1, 2)
 
 def test_eur_endpoints(mock_cmf_client):
     eur = Eur(api_key="test")
     
     current = eur.current()
-    assert current.value == 910.15
+    assert current.value == Decimal("910.15")
     assert current.date == datetime(2026, 1, 23)
     
     year_data = eur.year(2025)
     assert len(year_data) == 2
-    assert year_data[0].value == 1035.50
+    assert year_data[0].value == Decimal("1035.50")
 
 def test_uf_endpoints(mock_cmf_client):
     uf = Uf(api_key="test")
     
     current = uf.current()
-    assert current.value == 38500.12
+    assert current.value == Decimal("38500.12")
+    assert str(current.value) == "38500.12"
     assert current.date == datetime(2026, 1, 23)
     
     year_data = uf.year(2025)
     assert len(year_data) == 2
-    assert year_data[0].value == 37100.0
+    assert year_data[0].value == Decimal("37100.0")
 
 def test_utm_endpoints(mock_cmf_client):
     utm = Utm(api_key="test")
     current = utm.current()
-    assert current.value == 67123.0
+    assert current.value == Decimal("67123.0")
     
     year_data = utm.year(2025)
     assert len(year_data) == 2
-    assert year_data[0].value == 66500.0
+    assert year_data[0].value == Decimal("66500.0")
 
 def test_ipc_endpoints(mock_cmf_client):
     ipc = Ipc(api_key="test")
     
     current = ipc.current()
     # IpcRecord divides by 100: 0,5 -> 0.005
-    assert current.value == 0.005
+    assert current.value == Decimal("0.005")
     
     year_data = ipc.year(2025)
-    assert len(year_data) == 2
-    assert year_data[0].value == 0.001
+    assert len(year_data) == 3
+    assert year_data[0].value == Decimal("0.001")
+    assert year_data[2].value == Decimal("-0.003")
 
 
 def test_endpoint_init():
@@ -0,0 +1,103 @@
+import pytest
+
+from cl_forge import exceptions, territory
+
+
+def test_region_lookup():
+    region = territory.Region("RM")
+    assert region.code == "13"
+    assert region.name == "Metropolitana de Santiago"
+    assert territory.Region("5").name == "Valparaíso"
+    assert territory.Region("los rios") == territory.Region("XIV")
+
+def test_region_ordering():
+    regions = territory.get_regions()
+    assert len(regions) == 16
+    assert regions[0].name == "Arica y Parinacota"
+    assert regions[-1].roman == "XII"
+    assert territory.Region("15") < territory.Region("01")
+    assert sorted(regions, key=lambda r: r.code)[0].code == "01"
+
+def test_region_children():
+    region = territory.Region("XVI")
+    assert [p.code for p in region.provinces()] == ["161", "162", "163"]
+    assert len(region.comunas()) == 21
+    assert sum(len(r.comunas()) for r in territory.get_regions()) == 346
+
+def test_province():
+    province = territory.Province("cordillera")
+    assert province.code == "132"
+    assert province.region == territory.Region("13")
+    assert [c.name for c in province.comunas()] == [
+        "Puente Alto",
+        "Pirque",
+        "San José de Maipo",
+    ]
+
+def test_comuna_lookup():
+    comuna = territory.Comuna("NUNOA")
+    assert comuna.code == "13120"
+    assert comuna.name == "Ñuñoa"
+    assert comuna.province.name == "Santiago"
+    assert comuna.region.roman == "RM"
+    assert territory.Comuna("1101").name == "Iquique"
+    assert len({territory.Comuna("Viña del Mar"), territory.Comuna("05109")}) == 1
+
+def test_fuzzy_matching():
+    results = territory.search_comunas("Pealolen", limit=3)
+    assert len(results) == 3
+    assert results[0][0].name == "Peñalolén"
+    assert results[0][1] > results[1][1]
+    assert territory.match_comuna("Consepcion").code == "08101"
+
+    with pytest.raises(exceptions.UnknownComuna):
+        territory.match_comuna("Buenos Aires")
+
+def test_normalize_name():
+    assert territory.normalize_name("  VIÑA  DEL MAR ") == "vina del mar"
+    assert territory.normalize_name("O'Higgins") == "ohiggins"
+
+def test_unknown():
+    with pytest.raises(exceptions.UnknownRegion):
+        territory.Region("17")
+
+    with pytest.raises(exceptions.UnknownProvince):
+        territory.Province("Ñuble")
+
+    with pytest.raises(exceptions.UnknownComuna):
+        territory.Comuna("Vina del Mal")
+
+def test_postal_code_validation():
+    assert territory.normalize_postal_code(" 832 0000 ") == "8320000"
+    assert territory.validate_postal_code("7500000") is True
+    assert territory.validate_postal_code("0320000") is False
+
+    with pytest.raises(exceptions.InvalidPostalCode):
+        territory.normalize_postal_code("832000")
+
+def test_postal_comuna():
+    comuna = territory.get_postal_comuna("2520000")
+    assert comuna.code == "05109"
+    assert comuna == territory.Comuna("Viña del Mar")
+
+    with pytest.raises(exceptions.UnknownPostalCode):
+        territory.get_postal_comuna("9990000")
+
+def test_postal_directory(tmp_path):
+    directory = territory.PostalDirectory()
+    assert directory.load_csv("postal_code;comuna\n8320123;Providencia") == 1
+    assert directory.lookup("8320123").name == "Providencia"
+    assert directory.lookup("8320124").name == "Santiago"
+
+    path = tmp_path / "postal.csv"
+    path.write_text("9990000,9990999,13101\n")
+    empty = territory.PostalDirectory(embedded=False)
+    assert len(empty) == 0
+    assert empty.load_file(str(path)) == 1
+    assert empty.lookup("9990500").code == "13101"
+
+    with pytest.raises(exceptions.UnknownPostalComuna):
+        directory.load_csv("8320123,Narnia")
+
+    with pytest.raises(exceptions.InvalidPostalRecord):
+        directory.load_csv("8320123")
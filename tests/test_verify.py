@@ -1,3 +1,5 @@
+from datetime import date
+
 import pytest
 
 from cl_forge import exceptions, verify
@@ -96,3 +98,301 @@ def test_generate_insufficient_range():
     # n > (max - min + 1)
     with pytest.raises(exceptions.InsufficientRange):
         verify.generate(12, 1000, 1010)
+
+def test_get_inspection_month():
+    assert verify.get_inspection_month("PHZF59") == 1
+    assert verify.get_inspection_month("PHZF50") == 2
+    assert verify.get_inspection_month("bbc12") == 5
+    assert verify.Ppu("BR1238").inspection_month == 11
+
+    with pytest.raises(exceptions.UnknownFormat):
+        verify.get_inspection_month("INVALID")
+
+def test_is_restricted():
+    # Friday, May 9th 2025: digits 9 and 0
+    friday = date(2025, 5, 9)
+    assert verify.get_restricted_digits(friday) == [0, 9]
+    assert verify.is_restricted("PHZF50", friday) is True
+    assert verify.is_restricted("PHZF55", friday) is False
+    assert verify.Ppu("BBC19").is_restricted(friday) is True
+
+    # Weekends are never restricted
+    assert verify.get_restricted_digits(date(2025, 5, 10)) == []
+
+def test_plate_class():
+    plate = verify.Plate("ab 123 cd")
+    assert plate.normalized == "AB123CD"
+    assert plate.format == "LLNNNLL"
+    assert plate.country == "AR"
+
+    plate = verify.Plate("ABC-1D23")
+    assert plate.normalized == "ABC1D23"
+    assert plate.country == "BR"
+
+def test_plate_class_chile():
+    # Chilean plates keep the PPU normalization
+    plate = verify.Plate("bbc12")
+    assert plate.normalized == verify.Ppu("bbc12").normalized
+    assert plate.country == "CL"
+
+def test_plate_class_country():
+    assert verify.Plate("ABC123").country == "CL"
+    assert verify.Plate("ABC123", country="pe").country == "PE"
+
+    with pytest.raises(exceptions.CountryMismatch):
+        verify.Plate("ABC1D23", country="CL")
+
+    with pytest.raises(exceptions.UnknownCountry):
+        verify.Plate("ABC123", country="UY")
+
+    with pytest.raises(exceptions.UnknownPlateFormat):
+        verify.Plate("INVALID")
+
+def test_get_plate_formats():
+    assert verify.get_plate_formats("ABC123") == [
+        ("CL", "LLLNNN"),
+        ("AR", "LLLNNN"),
+        ("PE", "LXXNNN"),
+    ]
+    assert verify.get_plate_formats("ABC1234") == [("BR", "LLLNNNN")]
+    assert verify.get_plate_formats("12345") == []
+
+def test_vin_class():
+    vin = verify.Vin("1m8gdm9axkp042788")
+    assert vin.normalized == "1M8GDM9AXKP042788"
+    assert vin.wmi == "1M8"
+    assert vin.vds == "GDM9AX"
+    assert vin.vis == "KP042788"
+    assert vin.check_digit == "X"
+    assert vin.is_valid is True
+    assert vin.region == "North America"
+    assert vin.model_year == 1989
+
+def test_vin_manufacturer():
+    vin = verify.Vin("9BWZZZ377VT004251")
+    assert vin.manufacturer == "Volkswagen"
+    assert vin.region == "South America"
+
+def test_validate_vin():
+    assert verify.validate_vin("1M8GDM9AXKP042788") is True
+    assert verify.validate_vin("1M8GDM9A1KP042788") is False
+    assert verify.calculate_vin_check_digit("11111111111111111") == "1"
+
+def test_vin_invalid():
+    with pytest.raises(exceptions.EmptyVin):
+        verify.Vin("  ")
+
+    with pytest.raises(exceptions.InvalidVinLength):
+        verify.Vin("1M8GDM9AXKP04278")
+
+    with pytest.raises(exceptions.InvalidVinCharacter):
+        verify.Vin("1M8GDM9AXKP04278O")
+
+def test_ppu_value_semantics():
+    assert verify.Ppu("BBC12") == verify.Ppu("bbc012")
+    assert verify.Ppu("PHZF55") != verify.Ppu("PHZF56")
+    assert len({verify.Ppu("phzf55"), verify.Ppu(" PHZF55 ")}) == 1
+    assert sorted([verify.Ppu("PHZF56"), verify.Ppu("BR1234")]) == [
+        verify.Ppu("BR1234"),
+        verify.Ppu("PHZF56"),
+    ]
+
+def test_ppu_pickle():
+    import pickle
+
+    ppu = verify.Ppu("bbc12")
+    restored = pickle.loads(pickle.dumps(ppu))
+    assert restored == ppu
+    assert restored.raw == "bbc12"
+    assert restored.format == "LLLNN"
+    assert type(ppu).__module__ == "cl_forge.core.impl.rs_cl_forge.rs_verify"
+
+def test_ppu_pickle_from_complete():
+    import pickle
+
+    ppu = verify.Ppu.from_complete(" phzf55-k ")
+    restored = pickle.loads(pickle.dumps(ppu))
+    assert restored == ppu
+    assert restored.raw == "phzf55"
+    assert restored.complete == "PHZF55-K"
+
+def test_ppu_from_numeric():
+    ppu = verify.Ppu.from_numeric(871234)
+    assert ppu.normalized == "BR1234"
+    assert ppu.numeric == 871234
+
+    with pytest.raises(exceptions.InvalidNumeric):
+        verify.Ppu.from_numeric(1234)
+
+def test_ppu_from_complete():
+    ppu = verify.Ppu.from_complete("PHZF55-K")
+    assert ppu == verify.Ppu("PHZF55")
+    assert ppu.raw == "PHZF55"
+    assert verify.Ppu.from_complete("phzf55 - k").complete == "PHZF55-K"
+
+    with pytest.raises(exceptions.VerifierMismatch):
+        verify.Ppu.from_complete("PHZF55-1")
+    # Without the separator, "BBC123" could be "BBC12" with verifier 3
+    with pytest.raises(exceptions.MissingVerifier):
+        verify.Ppu.from_complete("BBC123")
+    with pytest.raises(exceptions.MissingVerifier):
+        verify.Ppu.from_complete("PHZF55K")
+
+MRZ_NEW = (
+    "INCHL1001234562<<<<<<<<<<<<<<<\n"
+    "8501019M3001019CHL12345678<5<1\n"
+    "GONZALEZ<PEREZ<<JUAN<PABLO<<<<"
+)
+QR_URL = (
+    "https://portal.sidiv.registrocivil.cl/docstatus?RUN=12345678-5"
+    "&type=CEDULA&serial=100123456&mrz=100123456285010193001019"
+)
+
+def test_idcard_mrz():
+    mrz = verify.IdCardMrz(MRZ_NEW)
+    assert mrz.kind == "new"
+    assert mrz.document_number == "100123456"
+    assert mrz.birth_date == date(1985, 1, 1)
+    assert mrz.expiry_date == date(2030, 1, 1)
+    assert mrz.sex == "M"
+    assert mrz.nationality == "CHL"
+    assert mrz.run == "12345678-5"
+    assert mrz.run == f"12345678-{verify.calculate_verifier(12345678)}"
+
+def test_idcard_mrz_old():
+    line1 = "IDCHLA01234567<84<<<<<<<<<<<<<"
+    mrz = verify.IdCardMrz(line1 + MRZ_NEW[30:])
+    assert mrz.kind == "old"
+    assert mrz.document_number == "A012345678"
+
+def test_idcard_mrz_invalid():
+    with pytest.raises(exceptions.InvalidMrzLength):
+        verify.IdCardMrz(MRZ_NEW[:-1])
+
+    with pytest.raises(exceptions.InvalidCheckDigit):
+        verify.IdCardMrz(MRZ_NEW.replace("8501019M", "8501018M"))
+
+def test_idcard_qr():
+    qr = verify.IdCardQr(QR_URL)
+    assert qr.run == "12345678-5"
+    assert qr.serial == "100123456"
+    assert qr.document_type == "CEDULA"
+    assert qr.birth_date == date(1985, 1, 1)
+
+    with pytest.raises(exceptions.InvalidRun):
+        verify.IdCardQr(QR_URL.replace("12345678-5", "12345678-K"))
+
+    with pytest.raises(exceptions.InvalidQrUrl):
+        verify.IdCardQr(QR_URL.replace("registrocivil", "example"))
+
+def test_validate_document_number():
+    assert verify.validate_document_number("100123456") == "new"
+    assert verify.validate_document_number("A012345678") == "old"
+    assert verify.calculate_mrz_check_digit("100123456") == "2"
+
+    with pytest.raises(exceptions.InvalidDocumentNumber):
+        verify.validate_document_number("012345678")
+
+def test_phone_class():
+    phone = verify.Phone("9 1234 5678")
+    assert phone.national == "912345678"
+    assert phone.e164 == "+56912345678"
+    assert phone.international == "+56 9 1234 5678"
+    assert phone.kind == "mobile"
+
+    phone = verify.Phone("0221234567")
+    assert phone.kind == "fixed"
+    assert phone.prefix == "2"
+    assert phone.description == "Santiago"
+
+    assert verify.Phone("600 360 7777").kind == "special"
+
+def test_normalize_phone():
+    assert verify.normalize_phone("+56 2 2345 6789") == "+56223456789"
+    assert verify.normalize_phone("(32) 212-3456") == "+56322123456"
+    assert verify.validate_phone("+56912345678") is True
+    assert verify.validate_phone("+54 11 2345 6789") is False
+
+def test_phone_invalid():
+    with pytest.raises(exceptions.EmptyPhone):
+        verify.Phone(" - ")
+
+    with pytest.raises(exceptions.InvalidPhoneCharacter):
+        verify.Phone("9 1234 567a")
+
+    with pytest.raises(exceptions.UnknownPhonePrefix):
+        verify.Phone("+56 1 2345 6789")
+
+    with pytest.raises(exceptions.InvalidPhoneLength):
+        verify.Phone("9 1234 567")
+
+def test_bank_lookup():
+    bank = verify.Bank("12")
+    assert bank.code == "012"
+    assert bank.short_name == "BancoEstado"
+    assert verify.Bank("bci") == verify.Bank("016")
+    assert [b.code for b in verify.get_banks()][:2] == ["001", "009"]
+
+    with pytest.raises(exceptions.UnknownBank):
+        verify.Bank("999")
+
+def test_normalize_account_number():
+    assert verify.normalize_account_number(" 00-123-45678-9 ") == "00123456789"
+
+    with pytest.raises(exceptions.InvalidAccountCharacter):
+        verify.normalize_account_number("123A")
+    with pytest.raises(exceptions.EmptyAccount):
+        verify.normalize_account_number("--")
+
+def test_validate_cuenta_rut():
+    assert verify.validate_cuenta_rut("12.345.678", 12345678, "5") is True
+    assert verify.validate_cuenta_rut("0012345678", 12345678, "5") is True
+    assert verify.validate_cuenta_rut("12345679", 12345678, "5") is False
+
+    with pytest.raises(exceptions.InvalidAccountRut):
+        verify.validate_cuenta_rut("12345678", 12345678, "K")
+
+def test_case_role():
+    role = verify.CaseRole(" c-01234-2023 ")
+    assert role.case_type == "C"
+    assert role.number == 1234
+    assert role.year == 2023
+    assert role.normalized == "C-1234-2023"
+    assert role.tribunal == "juzgado"
+    assert role == verify.CaseRole("C 1234 2023")
+
+    role = verify.CaseRole("proteccion-567-2024")
+    assert role.case_type == "Protección"
+    assert role.tribunal == "corte_apelaciones"
+    assert role.normalized == "Protección-567-2024"
+
+    role = verify.CaseRole("1234-2023")
+    assert role.case_type is None
+    assert role.tribunal == "corte_suprema"
+
+def test_normalize_case_role():
+    assert verify.normalize_case_role("v 87 2019") == "V-87-2019"
+    assert verify.validate_case_role("T-15-2024") is True
+    assert verify.validate_case_role("C-1234-23") is False
+
+    with pytest.raises(exceptions.EmptyRole):
+        verify.normalize_case_role("  ")
+    with pytest.raises(exceptions.InvalidRoleFormat):
+        verify.normalize_case_role("C-1234")
+    with pytest.raises(exceptions.UnknownCaseType):
+        verify.normalize_case_role("CA-1234-2023")
+    with pytest.raises(exceptions.InvalidRoleYear):
+        verify.normalize_case_role("C-1234-1899")
+
+def test_property_role():
+    role = verify.PropertyRole("01234-056", 13101)
+    assert role.comuna == 13101
+    assert role.block == 1234
+    assert role.lot == 56
+    assert role.normalized == "1234-56"
+    assert verify.normalize_property_role("123 45") == "123-45"
+
+    with pytest.raises(exceptions.InvalidRoleFormat):
+        verify.PropertyRole("1234", 13101)
+    with pytest.raises(exceptions.InvalidComunaCode):
+        verify.PropertyRole("1234-56", 0)